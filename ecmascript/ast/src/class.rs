@@ -57,6 +57,9 @@ pub enum ClassMember {
     PrivateProp(PrivateProp),
     #[tag("TsIndexSignature")]
     TsIndexSignature(TsIndexSignature),
+    /// `es2022`
+    #[tag("StaticBlock")]
+    StaticBlock(StaticBlock),
 }
 
 macro_rules! property {
@@ -158,6 +161,14 @@ pub struct Constructor {
     pub is_optional: bool,
 }
 
+/// `static { ... }`
+#[ast_node("StaticBlock")]
+pub struct StaticBlock {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
 #[ast_node("Decorator")]
 pub struct Decorator {
     pub span: Span,
@@ -147,6 +147,14 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    /// `a |> f`
+    #[tag("PipelineExpression")]
+    Pipeline(PipelineExpr),
+
+    /// `obj::func` and `::obj.func`
+    #[tag("BindExpression")]
+    Bind(BindExpr),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -522,6 +530,7 @@ impl From<f64> for Expr {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value,
+            raw: None,
         }))
     }
 }
@@ -550,6 +559,28 @@ pub struct OptChainExpr {
     pub expr: Box<Expr>,
 }
 
+#[ast_node("PipelineExpression")]
+pub struct PipelineExpr {
+    pub span: Span,
+
+    /// Topic of the pipeline.
+    pub left: Box<Expr>,
+
+    /// Function called with `left`.
+    pub right: Box<Expr>,
+}
+
+#[ast_node("BindExpression")]
+pub struct BindExpr {
+    pub span: Span,
+
+    /// `None` for `::obj.func`, which binds `obj`.
+    #[serde(default)]
+    pub object: Option<Box<Expr>>,
+
+    pub callee: Box<Expr>,
+}
+
 test_de!(
     jsx_element,
     JSXElement,
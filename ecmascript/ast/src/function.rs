@@ -14,6 +14,10 @@ pub struct Function {
     #[serde(default)]
     pub decorators: Vec<Decorator>,
 
+    /// Decorators of parameters. Only methods of classes can have them.
+    #[serde(default, rename = "paramDecorators")]
+    pub param_decorators: Vec<ParamDecorator>,
+
     pub span: Span,
 
     #[serde(default)]
@@ -34,10 +38,29 @@ pub struct Function {
     pub return_type: Option<TsTypeAnn>,
 }
 
+/// A constructor parameter with decorators, e.g. `@Inject() a`.
+#[ast_node("Parameter")]
+pub struct Param {
+    pub span: Span,
+    pub decorators: Vec<Decorator>,
+    pub pat: Pat,
+}
+
+/// A decorator of a method parameter, e.g. `@Body()` in `m(@Body() b) {}`.
+#[ast_node("ParameterDecorator")]
+pub struct ParamDecorator {
+    pub span: Span,
+    /// Index of the decorated parameter.
+    pub index: usize,
+    pub decorator: Decorator,
+}
+
 #[ast_node]
 pub enum PatOrTsParamProp {
     #[tag("TsParameterProperty")]
     TsParamProp(TsParamProp),
+    #[tag("Parameter")]
+    Param(Param),
     #[tag("*")]
     Pat(Pat),
 }
@@ -9,16 +9,17 @@
 pub use self::{
     class::{
         Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind,
-        PrivateMethod, PrivateProp,
+        PrivateMethod, PrivateProp, StaticBlock,
     },
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
-        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
-        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
-        ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super,
-        TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BindExpr, BlockStmtOrExpr, CallExpr,
+        ClassExpr, CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr,
+        NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PipelineExpr, PropOrSpread,
+        SeqExpr, SpreadElement, Super, TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr,
+        YieldExpr,
     },
-    function::{Function, PatOrTsParamProp},
+    function::{Function, Param, ParamDecorator, PatOrTsParamProp},
     ident::{Ident, IdentExt, PrivateName},
     jsx::{
         JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXClosingElement, JSXClosingFragment,
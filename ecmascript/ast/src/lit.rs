@@ -44,6 +44,12 @@ pub struct Str {
     /// This includes line escape.
     #[serde(default)]
     pub has_escape: bool,
+
+    /// Source text of the literal, including quotes.
+    ///
+    /// `None` if the node was not created by the parser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
 }
 impl Str {
     #[inline]
@@ -77,10 +83,15 @@ pub struct Regex {
 }
 
 #[ast_node("NumericLiteral")]
-#[derive(Copy)]
 pub struct Number {
     pub span: Span,
     pub value: f64,
+
+    /// Source text of the literal, e.g. `0xff` or `1_000`.
+    ///
+    /// `None` if the node was not created by the parser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsWord>,
 }
 
 impl Display for Number {
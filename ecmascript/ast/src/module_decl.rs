@@ -1,6 +1,6 @@
 use crate::{
     decl::Decl,
-    expr::{ClassExpr, Expr, FnExpr},
+    expr::{ClassExpr, Expr, FnExpr, ObjectLit},
     ident::Ident,
     lit::Str,
     typescript::{TsExportAssignment, TsImportEqualsDecl, TsInterfaceDecl, TsNamespaceExportDecl},
@@ -62,6 +62,14 @@ pub struct ImportDecl {
 
     #[serde(rename = "source")]
     pub src: Str,
+
+    /// `{ type: "json" }` in `assert { type: "json" }`
+    #[serde(default)]
+    pub asserts: Option<ObjectLit>,
+
+    /// `import type` and `import typeof` of flow.
+    #[serde(default)]
+    pub type_only: bool,
 }
 
 /// `export * from 'mod'`
@@ -71,6 +79,9 @@ pub struct ExportAll {
 
     #[serde(rename = "source")]
     pub src: Str,
+
+    #[serde(default)]
+    pub asserts: Option<ObjectLit>,
 }
 
 /// `export { foo } from 'mod'`
@@ -83,6 +94,9 @@ pub struct NamedExport {
 
     #[serde(rename = "source")]
     pub src: Option<Str>,
+
+    #[serde(default)]
+    pub asserts: Option<ObjectLit>,
 }
 
 #[ast_node("ExportDefaultDeclaration")]
@@ -139,6 +153,10 @@ pub struct ImportSpecific {
 
     #[serde(default)]
     pub imported: Option<Ident>,
+
+    /// `type A` and `typeof A` in `import { type A } from 'mod'` of flow.
+    #[serde(default)]
+    pub type_only: bool,
 }
 
 #[ast_node]
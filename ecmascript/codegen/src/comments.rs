@@ -59,6 +59,12 @@ impl<'a> Emitter<'a> {
             return Ok(());
         }
 
+        self.emit_leading_comments(pos)?;
+        // Keywords like `return` are written without a span.
+        self.wr.set_next_src_pos(pos)
+    }
+
+    fn emit_leading_comments(&mut self, pos: BytePos) -> Result {
         let comments = match self.comments {
             Some(ref comments) => comments,
             None => return Ok(()),
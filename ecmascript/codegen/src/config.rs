@@ -1,4 +1,63 @@
-#[derive(Debug, Default, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+use swc_ecma_parser::JscTarget;
+
+#[derive(Debug, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// Number of spaces used for a level of indentation.
+    ///
+    /// Defaults to 4.
+    pub indent_width: usize,
+
+    /// Quote used for string literals which contain neither or both kinds of
+    /// quotes.
+    ///
+    /// Literals which are printed from their source text keep their quotes.
+    pub quote_style: QuoteStyle,
+
+    /// Add a trailing comma to array and object literals which are printed on
+    /// multiple lines.
+    pub trailing_comma: bool,
+
+    /// Break minified output after `;`, `,` or `{` once a line gets longer
+    /// than this.
+    pub max_line_len: Option<usize>,
+
+    /// Escape non-ascii characters in strings, templates, regular expressions
+    /// and identifiers.
+    pub ascii_only: bool,
+
+    /// Source text of a literal is reused only if its syntax is supported by
+    /// this version.
+    ///
+    /// Defaults to the latest version, as the emitter does not lower syntax.
+    pub target: JscTarget,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            minify: false,
+            indent_width: 4,
+            quote_style: Default::default(),
+            trailing_comma: false,
+            max_line_len: None,
+            ascii_only: false,
+            target: JscTarget::Es2020,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    Single,
+    Double,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Single
+    }
 }
@@ -1,4 +1,4 @@
-use super::{list::ListFormat, Emitter, Result};
+use super::{escape_ident, list::ListFormat, Emitter, Result};
 use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
@@ -28,6 +28,17 @@ impl<'a> Emitter<'a> {
         for dec in &node.class.decorators {
             emit!(dec);
         }
+
+        if node.declare {
+            keyword!("declare");
+            space!();
+        }
+
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         keyword!("class");
         space!();
         emit!(node.ident);
@@ -39,6 +50,11 @@ impl<'a> Emitter<'a> {
     pub fn emit_fn_decl(&mut self, node: &FnDecl) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        if node.declare {
+            keyword!("declare");
+            space!();
+        }
+
         if node.function.is_async {
             keyword!("async");
             space!();
@@ -59,6 +75,11 @@ impl<'a> Emitter<'a> {
     pub fn emit_var_decl(&mut self, node: &VarDecl) -> Result {
         self.emit_leading_comments_of_pos(node.span.lo())?;
 
+        if node.declare {
+            keyword!("declare");
+            space!();
+        }
+
         keyword!(node.kind.as_str());
         space!();
 
@@ -73,7 +94,21 @@ impl<'a> Emitter<'a> {
     pub fn emit_var_declator(&mut self, node: &VarDeclarator) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.name);
+        match node.name {
+            // `let a!: number;`
+            Pat::Ident(ref i) if node.definite => {
+                self.wr
+                    .write_symbol(i.span, &escape_ident(&i.sym, self.cfg.ascii_only))?;
+                punct!("!");
+
+                if let Some(ref ty) = i.type_ann {
+                    punct!(":");
+                    formatting_space!();
+                    emit!(ty);
+                }
+            }
+            _ => emit!(node.name),
+        }
 
         if let Some(ref init) = node.init {
             formatting_space!();
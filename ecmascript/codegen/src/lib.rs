@@ -1,13 +1,13 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{Config, QuoteStyle};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
     util::{SourceMapperExt, SpanExt, StartsWithAlphaNum},
 };
-use std::{io, sync::Arc};
+use std::{borrow::Cow, io, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -21,6 +21,7 @@ mod decl;
 mod expr;
 mod jsx;
 pub mod list;
+mod lit;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -172,6 +173,10 @@ impl<'a> Emitter<'a> {
 
         keyword!("import");
         space!();
+        if node.type_only {
+            keyword!("type");
+            space!();
+        }
 
         let mut specifiers = vec![];
         let mut emitted_default = false;
@@ -228,11 +233,17 @@ impl<'a> Emitter<'a> {
 
         formatting_space!();
         emit!(node.src);
+        self.emit_import_asserts(&node.asserts)?;
         semi!();
     }
 
     #[emitter]
     pub fn emit_import_specific(&mut self, node: &ImportSpecific) -> Result {
+        if node.type_only {
+            keyword!("type");
+            space!();
+        }
+
         if let Some(ref imported) = node.imported {
             emit!(imported);
             space!();
@@ -246,11 +257,13 @@ impl<'a> Emitter<'a> {
     #[emitter]
     pub fn emit_export_specifier(&mut self, node: &ExportSpecifier) -> Result {
         match node {
-            ExportSpecifier::Default(ref node) => {
-                unimplemented!("codegen of `export default from 'foo';`")
-            }
+            ExportSpecifier::Default(ref node) => emit!(node.exported),
             ExportSpecifier::Namespace(ref node) => {
-                unimplemented!("codegen of `export foo as Foo from 'foo';`")
+                punct!("*");
+                space!();
+                keyword!("as");
+                space!();
+                emit!(node.name);
             }
             ExportSpecifier::Named(ref node) => emit!(node),
         }
@@ -276,21 +289,51 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         keyword!("export");
-        formatting_space!();
-        punct!("{");
-        self.emit_list(
-            node.span,
-            Some(&node.specifiers),
-            ListFormat::NamedImportsOrExportsElements,
-        )?;
-        // TODO:
-        punct!("}");
+
+        // `export v from 'mod';` and `export * as ns from 'mod';` are printed in front
+        // of the braced specifiers.
+        let mut specifiers = vec![];
+        let mut emitted_head = false;
+        for specifier in &node.specifiers {
+            match specifier {
+                ExportSpecifier::Named(ref s) => {
+                    specifiers.push(s);
+                }
+                _ => {
+                    if emitted_head {
+                        punct!(",");
+                        formatting_space!();
+                    } else {
+                        space!();
+                    }
+                    emitted_head = true;
+                    emit!(specifier);
+                }
+            }
+        }
+
+        if !specifiers.is_empty() || !emitted_head {
+            if emitted_head {
+                punct!(",");
+            }
+            formatting_space!();
+            punct!("{");
+            self.emit_list(
+                node.span,
+                Some(&specifiers),
+                ListFormat::NamedImportsOrExportsElements,
+            )?;
+            punct!("}");
+        }
+
         if let Some(ref src) = node.src {
             space!();
             keyword!("from");
+            space!();
             emit!(src);
-            semi!();
+            self.emit_import_asserts(&node.asserts)?;
         }
+        semi!();
     }
 
     #[emitter]
@@ -304,9 +347,22 @@ impl<'a> Emitter<'a> {
         keyword!("from");
         space!();
         emit!(node.src);
+        self.emit_import_asserts(&node.asserts)?;
         semi!();
     }
 
+    /// Emits ` assert { type: "json" }`.
+    fn emit_import_asserts(&mut self, asserts: &Option<ObjectLit>) -> Result {
+        if let Some(ref asserts) = *asserts {
+            formatting_space!(self);
+            keyword!(self, "assert");
+            formatting_space!(self);
+            emit!(self, asserts);
+        }
+
+        Ok(())
+    }
+
     #[emitter]
     pub fn emit_lit(&mut self, node: &Lit) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
@@ -325,7 +381,8 @@ impl<'a> Emitter<'a> {
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.wr.write_str(&n.exp)?;
+                self.wr
+                    .write_str(&escape_non_ascii(&n.exp, self.cfg.ascii_only))?;
                 punct!("/");
                 self.wr.write_str(&n.flags)?;
             }
@@ -343,10 +400,11 @@ impl<'a> Emitter<'a> {
     pub fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        // if let Some(s) = get_text_of_node(&self.cm, node, false) {
-        //     self.wr.write_str_lit(node.span, &s)?;
-        //     return Ok(());
-        // }
+        if let Some(raw) = lit::str_lit_raw(node, self.cfg.target) {
+            self.wr.write_str_lit(node.span, &escape_non_ascii(raw, self.cfg.ascii_only))?;
+            return Ok(());
+        }
+
         let value = node
             .value
             .replace("\\", "\\\\")
@@ -369,20 +427,23 @@ impl<'a> Emitter<'a> {
             .replace("\0", "\\0");
         // let value = node.value.replace("\n", "\\n");
 
-        if !node.value.contains('\'') {
-            punct!("'");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
-        } else if !node.value.contains('\"') {
-            punct!("\"");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("\"");
+        let has_single = node.value.contains('\'');
+        let use_single = if has_single != node.value.contains('"') {
+            !has_single
         } else {
-            punct!("'");
-            self.wr
-                .write_str_lit(node.span, &value.replace("'", "\\'"))?;
-            punct!("'");
-        }
+            self.cfg.quote_style == QuoteStyle::Single
+        };
+        let (quote, escaped_quote) = if use_single {
+            ("'", "\\'")
+        } else {
+            ("\"", "\\\"")
+        };
+
+        punct!(quote);
+        let value = value.replace(quote, escaped_quote);
+        self.wr
+            .write_str_lit(node.span, &escape_non_ascii(&value, self.cfg.ascii_only))?;
+        punct!(quote);
     }
 
     #[emitter]
@@ -396,11 +457,7 @@ impl<'a> Emitter<'a> {
             }
             self.wr.write_str_lit(num.span, "Infinity")?;
         } else {
-            if num.value.is_sign_negative() && num.value == 0.0 {
-                self.wr.write_str_lit(num.span, "-0.0")?;
-            } else {
-                self.wr.write_str_lit(num.span, &format!("{}", num.value))?;
-            }
+            self.wr.write_str_lit(num.span, &lit::num_lit_text(num, self.cfg.target))?;
         }
     }
 
@@ -488,6 +545,8 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::Pipeline(ref n) => emit!(n),
+            Expr::Bind(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
     }
@@ -521,6 +580,28 @@ impl<'a> Emitter<'a> {
         }
     }
 
+    #[emitter]
+    pub fn emit_pipeline_expr(&mut self, n: &PipelineExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.left);
+        formatting_space!();
+        operator!("|>");
+        formatting_space!();
+        emit!(n.right);
+    }
+
+    #[emitter]
+    pub fn emit_bind_expr(&mut self, n: &BindExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if let Some(ref object) = n.object {
+            emit!(object);
+        }
+        punct!("::");
+        emit!(n.callee);
+    }
+
     #[emitter]
     pub fn emit_invalid(&mut self, n: &Invalid) -> Result {
         self.emit_leading_comments_of_pos(n.span.lo())?;
@@ -532,7 +613,15 @@ impl<'a> Emitter<'a> {
     pub fn emit_call_expr(&mut self, node: &CallExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.callee);
+        if is_type_assertion(&node.callee) {
+            punct!("(");
+            emit!(node.callee);
+            punct!(")");
+        } else {
+            emit!(node.callee);
+        }
+
+        emit!(node.type_args);
 
         punct!("(");
         self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
@@ -547,6 +636,8 @@ impl<'a> Emitter<'a> {
         space!();
         emit!(node.callee);
 
+        emit!(node.type_args);
+
         if let Some(ref args) = node.args {
             punct!("(");
             self.emit_expr_or_spreads(node.span(), args, ListFormat::NewExpressionArguments)?;
@@ -558,7 +649,13 @@ impl<'a> Emitter<'a> {
     pub fn emit_member_expr(&mut self, node: &MemberExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.obj);
+        if is_type_assertion(&node.obj) {
+            punct!("(");
+            emit!(node.obj);
+            punct!(")");
+        } else {
+            emit!(node.obj);
+        }
 
         if node.computed {
             punct!("[");
@@ -582,26 +679,12 @@ impl<'a> Emitter<'a> {
     /// `1..toString` is a valid property access, emit a dot after the literal
     pub fn needs_2dots_for_property_access(&self, expr: &ExprOrSuper) -> bool {
         match *expr {
-            ExprOrSuper::Expr(ref expr) => {
-                match **expr {
-                    Expr::Lit(Lit::Num(Number { span, value })) => {
-                        if value.fract() == 0.0 {
-                            return true;
-                        }
-                        // check if numeric literal is a decimal literal that was originally written
-                        // with a dot
-                        if let Ok(text) = self.cm.span_to_snippet(span) {
-                            if text.contains('.') {
-                                return false;
-                            }
-                            text.starts_with('0') || text.ends_with(' ')
-                        } else {
-                            true
-                        }
-                    }
-                    _ => false,
+            ExprOrSuper::Expr(ref expr) => match **expr {
+                Expr::Lit(Lit::Num(ref num)) => {
+                    lit::is_decimal_int_lit(&lit::num_lit_text(num, self.cfg.target))
                 }
-            }
+                _ => false,
+            },
             _ => false,
         }
     }
@@ -617,10 +700,19 @@ impl<'a> Emitter<'a> {
         if node.is_generator {
             punct!("*")
         }
+
+        emit!(node.type_params);
+
         punct!("(");
         self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
         punct!(")");
 
+        if let Some(ref ty) = node.return_type {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
         punct!("=>");
         emit!(node.body);
     }
@@ -718,6 +810,11 @@ impl<'a> Emitter<'a> {
             emit!(dec);
         }
 
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         keyword!("class");
 
         if let Some(ref i) = node.ident {
@@ -730,11 +827,26 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_class_trailing(&mut self, node: &Class) -> Result {
+        emit!(node.type_params);
+
         if node.super_class.is_some() {
             space!();
             keyword!("extends");
             space!();
             emit!(node.super_class);
+            emit!(node.super_type_params);
+        }
+
+        if !node.implements.is_empty() {
+            space!();
+            keyword!("implements");
+            space!();
+
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
         }
 
         punct!("{");
@@ -750,18 +862,39 @@ impl<'a> Emitter<'a> {
             ClassMember::Method(ref n) => emit!(n),
             ClassMember::PrivateMethod(ref n) => emit!(n),
             ClassMember::PrivateProp(ref n) => emit!(n),
-            ClassMember::TsIndexSignature(ref n) => emit!(n),
+            ClassMember::TsIndexSignature(ref n) => {
+                emit!(n);
+                semi!();
+            }
+            ClassMember::StaticBlock(ref n) => emit!(n),
         }
     }
 
+    #[emitter]
+    pub fn emit_static_block(&mut self, n: &StaticBlock) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("static");
+        formatting_space!();
+        emit!(n.body);
+    }
+
     #[emitter]
     pub fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        self.emit_accesibility(n.accessibility)?;
+
         if n.is_static {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -788,6 +921,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         self.emit_fn_trailing(&n.function)?;
     }
 
@@ -795,10 +932,18 @@ impl<'a> Emitter<'a> {
     pub fn emit_class_method(&mut self, n: &ClassMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        self.emit_accesibility(n.accessibility)?;
+
         if n.is_static {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -825,6 +970,10 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
         self.emit_fn_trailing(&n.function)?;
     }
 
@@ -832,26 +981,120 @@ impl<'a> Emitter<'a> {
     pub fn emit_private_prop(&mut self, n: &PrivateProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_private_prop")
+        for dec in &n.decorators {
+            emit!(dec);
+        }
+
+        self.emit_accesibility(n.accessibility)?;
+
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
+        emit!(n.key);
+
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
+        if let Some(ref ty) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
+        if let Some(ref value) = n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(value);
+        }
+
+        semi!();
     }
 
     #[emitter]
-    pub fn emit_class_prop(&mut self, node: &ClassProp) -> Result {
-        self.emit_leading_comments_of_pos(node.span().lo())?;
+    pub fn emit_class_prop(&mut self, n: &ClassProp) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_class_prop")
+        for dec in &n.decorators {
+            emit!(dec);
+        }
+
+        self.emit_accesibility(n.accessibility)?;
+
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
+        if n.computed {
+            punct!("[");
+            emit!(n.key);
+            punct!("]");
+        } else {
+            emit!(n.key);
+        }
+
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
+        if let Some(ref ty) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
+        if let Some(ref value) = n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(value);
+        }
+
+        semi!();
     }
 
     #[emitter]
     pub fn emit_class_constructor(&mut self, n: &Constructor) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        self.emit_accesibility(n.accessibility)?;
+
         keyword!("constructor");
         punct!("(");
-        self.emit_list(n.span(), Some(&n.params), ListFormat::Parameters)?;
+        self.emit_list(n.span(), Some(&n.params), ListFormat::CommaListElements)?;
         punct!(")");
 
-        emit!(n.body);
+        match n.body {
+            Some(ref body) => emit!(body),
+            None => semi!(),
+        }
     }
 
     #[emitter]
@@ -910,12 +1153,39 @@ impl<'a> Emitter<'a> {
     /// prints `(b){}` from `function a(b){}`
     #[emitter]
     pub fn emit_fn_trailing(&mut self, node: &Function) -> Result {
+        emit!(node.type_params);
+
         punct!("(");
-        self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
+        if node.param_decorators.is_empty() {
+            self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
+        } else {
+            for (i, param) in node.params.iter().enumerate() {
+                if i != 0 {
+                    punct!(",");
+                    formatting_space!();
+                }
+                for dec in node.param_decorators.iter().filter(|dec| dec.index == i) {
+                    emit!(dec.decorator);
+                }
+                emit!(param);
+            }
+        }
         punct!(")");
 
-        formatting_space!();
-        emit!(node.body);
+        if let Some(ref ty) = node.return_type {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
+        match node.body {
+            Some(ref body) => {
+                formatting_space!();
+                emit!(body);
+            }
+            // Overload signatures and ambient functions.
+            None => semi!(),
+        }
     }
 
     #[emitter]
@@ -986,7 +1256,10 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_quasi(&mut self, node: &TplElement) -> Result {
-        self.wr.write_str_lit(node.span, &node.raw.value)?;
+        self.wr.write_str_lit(
+            node.span,
+            &escape_non_ascii(&node.raw.value, self.cfg.ascii_only),
+        )?;
         return Ok(());
     }
 
@@ -1151,6 +1424,11 @@ impl<'a> Emitter<'a> {
         space!();
         punct!("(");
         punct!(")");
+        if let Some(ref ty) = node.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
         formatting_space!();
         emit!(node.body);
     }
@@ -1194,6 +1472,12 @@ impl<'a> Emitter<'a> {
     pub fn emit_paren_expr(&mut self, node: &ParenExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        // Type casts print their own parens.
+        if let Expr::TsTypeCast(..) = *node.expr {
+            emit!(node.expr);
+            return Ok(());
+        }
+
         punct!("(");
         emit!(node.expr);
         punct!(")");
@@ -1221,13 +1505,24 @@ impl<'a> Emitter<'a> {
             unimplemented!()
         } else {
             // TODO: span
-            self.wr.write_symbol(ident.span, &ident.sym)?
+            self.wr
+                .write_symbol(ident.span, &escape_ident(&ident.sym, self.cfg.ascii_only))?;
 
             // self.wr
             //     .write(get_text_of_node(&self.cm, &ident, /* includeTrivia */
             // false).as_bytes())?;
         }
 
+        if ident.optional {
+            punct!("?");
+        }
+
+        if let Some(ref ty) = ident.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
         // Call emitList directly since it could be an array of
         // TypeParameterDeclarations _or_ type arguments
 
@@ -1405,9 +1700,18 @@ impl<'a> Emitter<'a> {
                     _ => false,
                 }
             };
+            let closing_line_terminator = self
+                .cm
+                .should_write_closing_line_terminator(parent_node, children, format);
             if format.contains(ListFormat::CommaDelimited) && has_trailing_comma {
                 self.wr.write_punct(",")?;
                 formatting_space!(self);
+            } else if format.contains(ListFormat::CommaDelimited)
+                && self.cfg.trailing_comma
+                && !self.cfg.minify
+                && closing_line_terminator
+            {
+                self.wr.write_punct(",")?;
             }
 
             {
@@ -1442,10 +1746,7 @@ impl<'a> Emitter<'a> {
             }
 
             // Write the closing line terminator or closing whitespace.
-            if self
-                .cm
-                .should_write_closing_line_terminator(parent_node, children, format)
-            {
+            if closing_line_terminator {
                 if !self.cfg.minify {
                     self.wr.write_line()?;
                 }
@@ -1482,8 +1783,19 @@ impl<'a> Emitter<'a> {
             Pat::Ident(ref n) => emit!(n),
             Pat::Object(ref n) => emit!(n),
             Pat::Rest(ref n) => emit!(n),
-            Pat::Invalid(..) => unimplemented!("emit Pat::Invalid"),
+            Pat::Invalid(ref n) => emit!(n),
+        }
+    }
+
+    #[emitter]
+    pub fn emit_param(&mut self, node: &Param) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        for dec in &node.decorators {
+            emit!(dec);
         }
+
+        emit!(node.pat);
     }
 
     #[emitter]
@@ -1492,6 +1804,12 @@ impl<'a> Emitter<'a> {
 
         punct!("...");
         emit!(node.arg);
+
+        if let Some(ref ty) = node.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
     }
 
     #[emitter]
@@ -1529,6 +1847,12 @@ impl<'a> Emitter<'a> {
             ListFormat::ArrayBindingPatternElements,
         )?;
         punct!("]");
+
+        if let Some(ref ty) = node.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
     }
 
     #[emitter]
@@ -1553,6 +1877,12 @@ impl<'a> Emitter<'a> {
             ListFormat::ObjectBindingPatternElements,
         )?;
         punct!("}");
+
+        if let Some(ref ty) = node.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
     }
 
     #[emitter]
@@ -2051,6 +2381,86 @@ fn should_emit_whitespace_before_operand(node: &UnaryExpr) -> bool {
     }
 }
 
+/// Returns true if `e` is `<T>a`, which binds looser than a member access or a
+/// call.
+fn is_type_assertion(e: &ExprOrSuper) -> bool {
+    match *e {
+        ExprOrSuper::Expr(ref e) => match **e {
+            Expr::TsTypeAssertion(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Escapes characters of a string, template or regular expression literal
+/// which cannot be written as-is.
+///
+/// Lone surrogates are always escaped because they cannot be encoded in utf8,
+/// and other non-ascii characters are escaped if `ascii_only` is true. Astral
+/// characters are written as surrogate pairs because `\u{...}` is invalid in
+/// regular expressions without the `u` flag.
+fn escape_non_ascii(s: &str, ascii_only: bool) -> Cow<'_, str> {
+    let needs_escape = |c: char| {
+        let code = c as u32;
+        (ascii_only && !c.is_ascii()) || (code >= 0xd800 && code <= 0xdfff)
+    };
+    if !s.chars().any(needs_escape) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 16);
+    // `\é` is an identity escape, so the backslash can be reused.
+    let mut escaped = false;
+    for c in s.chars() {
+        if !needs_escape(c) {
+            escaped = c == '\\' && !escaped;
+            buf.push(c);
+            continue;
+        }
+
+        if !escaped {
+            buf.push('\\');
+        }
+        escaped = false;
+
+        let code = c as u32;
+        if code < 0x10000 {
+            buf.push_str(&format!("u{:04X}", code));
+        } else {
+            let code = code - 0x10000;
+            buf.push_str(&format!(
+                "u{:04X}\\u{:04X}",
+                0xd800 + (code >> 10),
+                0xdc00 + (code & 0x3ff)
+            ));
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+/// Escapes non-ascii characters of an identifier if `ascii_only` is true.
+fn escape_ident(sym: &str, ascii_only: bool) -> Cow<'_, str> {
+    if !ascii_only || sym.is_ascii() {
+        return Cow::Borrowed(sym);
+    }
+
+    let mut buf = String::with_capacity(sym.len() + 16);
+    for c in sym.chars() {
+        let code = c as u32;
+        if c.is_ascii() {
+            buf.push(c);
+        } else if code < 0x10000 {
+            buf.push_str(&format!("\\u{:04X}", code));
+        } else {
+            buf.push_str(&format!("\\u{{{:X}}}", code));
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
 impl<N> Node for Option<N>
 where
     N: Node,
@@ -0,0 +1,283 @@
+//! Helpers for printing numeric and string literals.
+use std::{borrow::Cow, char};
+use swc_ecma_ast::{Number, Str};
+use swc_ecma_parser::JscTarget;
+
+/// Returns the text of a numeric literal.
+///
+/// The source text is reused if it still evaluates to the value of the node
+/// and is valid for `target`.
+pub(crate) fn num_lit_text(num: &Number, target: JscTarget) -> Cow<'_, str> {
+    match num.raw {
+        Some(ref raw)
+            if is_num_lit_valid_for(raw, target)
+                && parse_num(raw).map(f64::to_bits) == Some(num.value.to_bits()) =>
+        {
+            Cow::Borrowed(&**raw)
+        }
+        _ => Cow::Owned(num_to_str(num.value)),
+    }
+}
+
+/// Returns the source text of a string literal if it still evaluates to the
+/// value of the node and is valid for `target`.
+pub(crate) fn str_lit_raw(s: &Str, target: JscTarget) -> Option<&str> {
+    let raw = s.raw.as_ref()?;
+    let quote = match raw.chars().next() {
+        Some(c @ '\'') | Some(c @ '"') => c,
+        _ => return None,
+    };
+    if raw.len() < 2 || !raw.ends_with(quote) {
+        return None;
+    }
+
+    let body = &raw[1..raw.len() - 1];
+    if !is_str_lit_body_valid_for(body, target) {
+        return None;
+    }
+
+    if unescape(body)? == &*s.value {
+        Some(raw)
+    } else {
+        None
+    }
+}
+
+/// Returns true if `text` is a decimal integer literal which would swallow a
+/// following dot, as in `1..toString()`.
+pub(crate) fn is_decimal_int_lit(text: &str) -> bool {
+    let text = text.trim_start_matches('-');
+    !text.is_empty()
+        && text.bytes().all(|b| b.is_ascii_digit() || b == b'_')
+        && !is_legacy_octal(text)
+}
+
+/// Returns true if the numeric literal `raw` is valid for `target`.
+///
+/// Literals with a leading zero like `010` or `08` are never valid, as they are
+/// errors in strict mode code.
+fn is_num_lit_valid_for(raw: &str, target: JscTarget) -> bool {
+    // Numeric separators are not supported by any target yet.
+    if raw.contains('_') {
+        return false;
+    }
+
+    match raw.get(..2) {
+        Some("0x") | Some("0X") => true,
+        Some("0o") | Some("0O") | Some("0b") | Some("0B") => target >= JscTarget::Es2015,
+        _ => !(raw.starts_with('0') && raw[1..].starts_with(|c: char| c.is_ascii_digit())),
+    }
+}
+
+/// Returns true if the body of a string literal is valid for `target`.
+///
+/// Legacy octal escapes like `\07`, `\8` and `\9` are never valid, as they
+/// are errors in strict mode code. Neither are unescaped line terminators,
+/// which the raw text of jsx attributes may contain.
+fn is_str_lit_body_valid_for(body: &str, target: JscTarget) -> bool {
+    let mut chars = body.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' | '\r' => return false,
+            // Allowed in string literals since ES2019.
+            '\u{2028}' | '\u{2029}' if target < JscTarget::Es2019 => return false,
+            '\\' => match chars.next() {
+                // Line continuation
+                Some('\r') => {
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                }
+                Some('0') if chars.peek().map_or(false, char::is_ascii_digit) => return false,
+                Some('1'..='9') => return false,
+                Some('u') if chars.peek() == Some(&'{') && target < JscTarget::Es2015 => {
+                    return false
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    true
+}
+
+/// `010`
+fn is_legacy_octal(text: &str) -> bool {
+    text.len() > 1 && text.starts_with('0') && text.bytes().all(|b| b'0' <= b && b <= b'7')
+}
+
+/// Parses the text of a numeric literal.
+fn parse_num(raw: &str) -> Option<f64> {
+    let s = raw.replace('_', "");
+    let radix = match s.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ if is_legacy_octal(&s) => return parse_int(&s[1..], 8),
+        _ => return s.parse().ok(),
+    };
+
+    parse_int(&s[2..], radix)
+}
+
+fn parse_int(digits: &str, radix: u32) -> Option<f64> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits
+        .chars()
+        .try_fold(0f64, |v, c| Some(v * f64::from(radix) + f64::from(c.to_digit(radix)?)))
+}
+
+/// Converts a number to a string as `Number.prototype.toString()` does.
+///
+/// See https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+pub(crate) fn num_to_str(v: f64) -> String {
+    if v.is_nan() {
+        return "NaN".into();
+    }
+    if v == 0.0 {
+        // `-0` is not `0`.
+        return if v.is_sign_negative() { "-0" } else { "0" }.into();
+    }
+    if v.is_infinite() {
+        return if v < 0.0 { "-Infinity" } else { "Infinity" }.into();
+    }
+    if v < 0.0 {
+        return format!("-{}", num_to_str(-v));
+    }
+
+    // `{:e}` prints the shortest digits which round-trip.
+    let s = format!("{:e}", v);
+    let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exp[1..].parse::<i32>().unwrap() + 1;
+
+    if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, fract) = digits.split_at(n as usize);
+        format!("{}.{}", int, fract)
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let exp = n - 1;
+        let sign = if exp < 0 { '-' } else { '+' };
+        if rest.is_empty() {
+            format!("{}e{}{}", first, sign, exp.abs())
+        } else {
+            format!("{}.{}e{}{}", first, rest, sign, exp.abs())
+        }
+    }
+}
+
+/// Evaluates escape sequences in the body of a string literal.
+///
+/// Returns `None` for invalid escapes and lone surrogates.
+fn unescape(s: &str) -> Option<String> {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            buf.push(c);
+            continue;
+        }
+
+        let c = chars.next()?;
+        match c {
+            'n' => buf.push('\n'),
+            'r' => buf.push('\r'),
+            't' => buf.push('\t'),
+            'b' => buf.push('\u{0008}'),
+            'f' => buf.push('\u{000C}'),
+            'v' => buf.push('\u{000B}'),
+            // Line continuations
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+
+            '0'..='7' => {
+                // `\0` or a legacy octal escape.
+                let max_len = if c <= '3' { 3 } else { 2 };
+                let mut code = c.to_digit(8).unwrap();
+                for _ in 1..max_len {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            code = code * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                buf.push(char::from_u32(code)?);
+            }
+
+            'x' => {
+                let hi = chars.next()?.to_digit(16)?;
+                let lo = chars.next()?.to_digit(16)?;
+                buf.push(char::from_u32(hi * 16 + lo)?);
+            }
+
+            'u' => {
+                let code = read_unicode_escape(&mut chars)?;
+                if 0xd800 <= code && code < 0xdc00 {
+                    // Surrogate pair
+                    if chars.next()? != '\\' || chars.next()? != 'u' {
+                        return None;
+                    }
+                    let lo = read_unicode_escape(&mut chars)?;
+                    if lo < 0xdc00 || 0xdfff < lo {
+                        return None;
+                    }
+                    let code = 0x10000 + ((code - 0xd800) << 10) + (lo - 0xdc00);
+                    buf.push(char::from_u32(code)?);
+                } else {
+                    buf.push(char::from_u32(code)?);
+                }
+            }
+
+            _ => buf.push(c),
+        }
+    }
+
+    Some(buf)
+}
+
+/// Reads `XXXX` or `{X..}` after `\u`.
+fn read_unicode_escape<I>(chars: &mut I) -> Option<u32>
+where
+    I: Iterator<Item = char>,
+{
+    let first = chars.next()?;
+    if first == '{' {
+        let mut code = 0u32;
+        let mut len = 0;
+        loop {
+            let c = chars.next()?;
+            if c == '}' {
+                break;
+            }
+            code = code.checked_mul(16)?.checked_add(c.to_digit(16)?)?;
+            len += 1;
+        }
+        if len == 0 || code > 0x10_FFFF {
+            return None;
+        }
+        return Some(code);
+    }
+
+    let mut code = first.to_digit(16)?;
+    for _ in 0..3 {
+        code = code * 16 + chars.next()?.to_digit(16)?;
+    }
+    Some(code)
+}
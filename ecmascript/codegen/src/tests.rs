@@ -1,4 +1,4 @@
-use self::swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+use self::swc_ecma_parser::{JscTarget, Parser, Session, SourceFileInput, Syntax};
 use super::*;
 use crate::config::Config;
 use std::{
@@ -26,7 +26,9 @@ impl Builder {
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
-            wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            wr: Box::new(
+                text_writer::JsWriter::new(self.cm.clone(), "\n", s, None).with_config(&self.cfg),
+            ),
             comments: Some(&self.comments),
             handlers: Box::new(Noop),
         };
@@ -48,7 +50,7 @@ impl Builder {
     }
 }
 
-fn parse_then_emit(from: &str, cfg: Config) -> String {
+fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -60,7 +62,7 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
         let res = {
             let mut parser = Parser::new(
                 Session { handler: &handler },
-                Syntax::default(),
+                syntax,
                 SourceFileInput::from(&*src),
                 Some(&comments),
             );
@@ -75,20 +77,59 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
     .unwrap()
 }
 
+/// Emits a literal which was not created by the parser.
+fn emit_lit(cfg: Config, lit: Lit) -> String {
+    let cm = Arc::new(SourceMap::default());
+    Builder {
+        cfg,
+        cm,
+        comments: Default::default(),
+    }
+    .text("", |e| e.emit_lit(&lit).unwrap())
+}
+
+fn str_lit(value: &str) -> Lit {
+    Lit::Str(Str {
+        span: DUMMY_SP,
+        value: value.into(),
+        has_escape: false,
+        raw: None,
+    })
+}
+
+fn num_lit(value: f64) -> Lit {
+    Lit::Num(Number {
+        span: DUMMY_SP,
+        value,
+        raw: None,
+    })
+}
+
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true });
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false });
+    let out = parse_then_emit(from, Default::default(), Syntax::default());
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
 
 fn test_from_to(from: &str, to: &str) {
-    let out = parse_then_emit(from, Default::default());
+    test_from_to_custom_syntax(from, to, Syntax::default())
+}
+
+fn test_from_to_custom_syntax(from: &str, to: &str, syntax: Syntax) {
+    let out = parse_then_emit(from, Default::default(), syntax);
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()),);
 }
@@ -153,16 +194,12 @@ a;",
 
 #[test]
 fn no_octal_escape() {
-    test_from_to(
-        r#"'\x00a';
-'\x000';
-'\x001';
-'\x009'"#,
-        r#"'\0a';
-'\x000';
-'\x001';
-'\x009';"#,
-    );
+    let cfg = Config::default();
+
+    assert_eq!(emit_lit(cfg, str_lit("\0a")), r#"'\0a'"#);
+    assert_eq!(emit_lit(cfg, str_lit("\00")), r#"'\x000'"#);
+    assert_eq!(emit_lit(cfg, str_lit("\01")), r#"'\x001'"#);
+    assert_eq!(emit_lit(cfg, str_lit("\09")), r#"'\x009'"#);
 }
 
 #[test]
@@ -189,6 +226,318 @@ fn issue_546() {
     );
 }
 
+#[test]
+fn import_assertions() {
+    let syntax = Syntax::Es(swc_ecma_parser::EsConfig {
+        import_assertions: true,
+        ..Default::default()
+    });
+
+    test_from_to_custom_syntax(
+        "import data from './data.json' assert { type: 'json' };",
+        "import data from './data.json' assert {
+    type: 'json'
+};",
+        syntax,
+    );
+    test_from_to_custom_syntax(
+        "export * from './data.json' assert { type: 'json' };",
+        "export * from './data.json' assert {
+    type: 'json'
+};",
+        syntax,
+    );
+}
+
+#[test]
+fn export_specifiers() {
+    let syntax = Syntax::Es(swc_ecma_parser::EsConfig {
+        export_default_from: true,
+        export_namespace_from: true,
+        ..Default::default()
+    });
+
+    test_from_to_custom_syntax("export * as ns from 'a';", "export * as ns from 'a';", syntax);
+    test_from_to_custom_syntax("export v from 'a';", "export v from 'a';", syntax);
+    test_from_to_custom_syntax(
+        "export v, { a, b as c } from 'a';",
+        "export v, { a, b as c } from 'a';",
+        syntax,
+    );
+    test_from_to_custom_syntax(
+        "export * as ns, { a } from 'a';",
+        "export * as ns, { a } from 'a';",
+        syntax,
+    );
+    test_from_to_custom_syntax(
+        "export { a as b } from 'a';",
+        "export { a as b } from 'a';",
+        syntax,
+    );
+    test_from_to_custom_syntax("export { a, b };", "export { a, b };", syntax);
+    test_from_to_custom_syntax("export {};", "export {};", syntax);
+
+    assert_eq!(
+        parse_then_emit(
+            "export v from 'a';",
+            Config {
+                minify: true,
+                ..Default::default()
+            },
+            syntax,
+        ),
+        "export v from 'a';"
+    );
+}
+
+#[test]
+fn output_config() {
+    let syntax = Syntax::default();
+
+    let double = Config {
+        quote_style: QuoteStyle::Double,
+        ..Default::default()
+    };
+    assert_eq!(emit_lit(double, str_lit("a")), "\"a\"");
+    assert_eq!(emit_lit(double, str_lit("b\"")), "'b\"'");
+    assert_eq!(
+        parse_then_emit(
+            "var a = {b: 1, c: 2};",
+            Config {
+                indent_width: 2,
+                trailing_comma: true,
+                ..Default::default()
+            },
+            syntax,
+        )
+        .trim(),
+        "var a = {\n  b: 1,\n  c: 2,\n};"
+    );
+    assert_eq!(
+        parse_then_emit(
+            "var foo = 10, bar = 20;",
+            Config {
+                minify: true,
+                max_line_len: Some(8),
+                ..Default::default()
+            },
+            syntax,
+        )
+        .trim(),
+        "var foo=10,\nbar=20;"
+    );
+}
+
+#[test]
+fn ascii_only() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+    let syntax = Syntax::default();
+
+    assert_eq!(
+        parse_then_emit("var a = 'é😀';", cfg, syntax).trim(),
+        "var a = '\\u00E9\\uD83D\\uDE00';"
+    );
+    assert_eq!(
+        parse_then_emit("var é = `é${a}\\é`;", cfg, syntax).trim(),
+        "var \\u00E9 = `\\u00E9${a}\\u00E9`;"
+    );
+    assert_eq!(parse_then_emit("var 𐊧;", cfg, syntax).trim(), "var \\u{102A7};");
+    assert_eq!(parse_then_emit("/[é]/u;", cfg, syntax).trim(), "/[\\u00E9]/u;");
+}
+
+#[test]
+fn lone_surrogate() {
+    assert_eq!(
+        parse_then_emit("'\\uD800';", Default::default(), Syntax::default()).trim(),
+        "'\\uD800';"
+    );
+}
+
+#[test]
+fn raw_num_lit() {
+    test_from_to("0xFF;", "0xFF;");
+    test_from_to("1_000;", "1000;");
+    test_from_to("1e21;", "1e21;");
+    test_from_to(".5;", ".5;");
+    test_from_to("1.0.toString();", "1.0.toString();");
+    test_from_to("1..toString();", "1..toString();");
+    test_from_to("0x1.toString();", "0x1.toString();");
+}
+
+#[test]
+fn raw_str_lit() {
+    test_from_to(r#""a";"#, r#""a";"#);
+    test_from_to(r#"'\x41\u{42}';"#, r#"'\x41\u{42}';"#);
+    test_from_to("'a\\\nb';", "'a\\\nb';");
+}
+
+#[test]
+fn raw_lit_es5() {
+    let es5 = Config {
+        target: JscTarget::Es5,
+        ..Default::default()
+    };
+    let emit = |src| parse_then_emit(src, es5, Syntax::default());
+
+    assert_eq!(emit("0b101;").trim(), "5;");
+    assert_eq!(emit("0o17;").trim(), "15;");
+    assert_eq!(emit("0xFF;").trim(), "0xFF;");
+    assert_eq!(emit("a('\\u{1F600}');").trim(), "a('\u{1F600}');");
+    assert_eq!(emit("a('\\u0041');").trim(), "a('\\u0041');");
+}
+
+#[test]
+fn raw_legacy_octal_lit() {
+    let cfg = Config::default();
+
+    let lit = Lit::Num(Number {
+        span: DUMMY_SP,
+        value: 8.0,
+        raw: Some("010".into()),
+    });
+    assert_eq!(emit_lit(cfg, lit), "8");
+
+    let lit = Lit::Num(Number {
+        span: DUMMY_SP,
+        value: 8.0,
+        raw: Some("08".into()),
+    });
+    assert_eq!(emit_lit(cfg, lit), "8");
+
+    let lit = Lit::Str(Str {
+        span: DUMMY_SP,
+        value: "\u{7}".into(),
+        has_escape: true,
+        raw: Some("'\\07'".into()),
+    });
+    assert_eq!(emit_lit(cfg, lit), "'\u{7}'");
+}
+
+#[test]
+fn raw_str_lit_line_terminator() {
+    // Raw text of a jsx attribute
+    let lit = Lit::Str(Str {
+        span: DUMMY_SP,
+        value: "a\nb".into(),
+        has_escape: false,
+        raw: Some("\"a\nb\"".into()),
+    });
+    assert_eq!(emit_lit(Default::default(), lit), "'a\\nb'");
+
+    test_from_to("'a\\\r\nb';", "'a\\\r\nb';");
+}
+
+#[test]
+fn synthesized_num_lit() {
+    let cfg = Config::default();
+
+    assert_eq!(emit_lit(cfg, num_lit(255.0)), "255");
+    assert_eq!(emit_lit(cfg, num_lit(0.1)), "0.1");
+    assert_eq!(emit_lit(cfg, num_lit(-0.0)), "-0");
+    assert_eq!(emit_lit(cfg, num_lit(-1.5)), "-1.5");
+    assert_eq!(emit_lit(cfg, num_lit(1e21)), "1e+21");
+    assert_eq!(emit_lit(cfg, num_lit(1.5e300)), "1.5e+300");
+    assert_eq!(emit_lit(cfg, num_lit(123e18)), "123000000000000000000");
+    assert_eq!(emit_lit(cfg, num_lit(1e-7)), "1e-7");
+    assert_eq!(emit_lit(cfg, num_lit(0.000_001)), "0.000001");
+    assert_eq!(emit_lit(cfg, num_lit(::std::f64::NAN)), "NaN");
+}
+
+#[test]
+fn modified_lit() {
+    let lit = Lit::Num(Number {
+        span: DUMMY_SP,
+        value: 2.0,
+        raw: Some("0x1".into()),
+    });
+    assert_eq!(emit_lit(Default::default(), lit), "2");
+
+    let lit = Lit::Str(Str {
+        span: DUMMY_SP,
+        value: "b".into(),
+        has_escape: false,
+        raw: Some("\"a\"".into()),
+    });
+    assert_eq!(emit_lit(Default::default(), lit), "'b'");
+}
+
+#[test]
+fn pipeline() {
+    test_from_to_custom_syntax(
+        "a |> f |> g",
+        "a |> f |> g;",
+        Syntax::Es(swc_ecma_parser::EsConfig {
+            pipeline_operator: Some(swc_ecma_parser::PipelineProposal::Minimal),
+            ..Default::default()
+        }),
+    );
+}
+
+fn ts_type_ref(name: &str) -> Box<TsType> {
+    Box::new(TsType::TsTypeRef(TsTypeRef {
+        span: DUMMY_SP,
+        type_name: TsEntityName::Ident(Ident::new(name.into(), DUMMY_SP)),
+        type_params: None,
+    }))
+}
+
+#[test]
+fn type_assertion_precedence() {
+    test_from_to_custom_syntax(
+        "(<T>a).b; (<T>a)();",
+        "(<T>a).b;\n(<T>a)();",
+        Syntax::Typescript(Default::default()),
+    );
+
+    // Parens are added even if the parser did not create them.
+    let obj = Expr::TsTypeAssertion(TsTypeAssertion {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Ident(Ident::new("a".into(), DUMMY_SP))),
+        type_ann: ts_type_ref("T"),
+    });
+    let member = Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: ExprOrSuper::Expr(Box::new(obj)),
+        prop: Box::new(Expr::Ident(Ident::new("b".into(), DUMMY_SP))),
+        computed: false,
+    });
+    let out = Builder {
+        cfg: Default::default(),
+        cm: Default::default(),
+        comments: Default::default(),
+    }
+    .text("", |e| e.emit_expr(&member).unwrap());
+    assert_eq!(out, "(<T>a).b");
+}
+
+#[test]
+fn flow_type_cast() {
+    test_from_to_custom_syntax(
+        "(a: T); f((b: U));",
+        "(a: T);\nf((b: U));",
+        Syntax::Flow(Default::default()),
+    );
+
+    let cast = Expr::TsTypeCast(TsTypeCastExpr {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Ident(Ident::new("a".into(), DUMMY_SP))),
+        type_ann: TsTypeAnn {
+            span: DUMMY_SP,
+            type_ann: ts_type_ref("T"),
+        },
+    });
+    let out = Builder {
+        cfg: Default::default(),
+        cm: Default::default(),
+        comments: Default::default(),
+    }
+    .text("", |e| e.emit_expr(&cast).unwrap());
+    assert_eq!(out, "(a: T)");
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
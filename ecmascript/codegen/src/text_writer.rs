@@ -1,6 +1,6 @@
 pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
 use super::*;
-use swc_common::Span;
+use swc_common::{BytePos, Span};
 
 mod basic_impl;
 mod semicolon;
@@ -35,6 +35,10 @@ pub trait WriteJs {
     fn write_symbol(&mut self, span: Span, s: &str) -> Result;
 
     fn write_punct(&mut self, s: &'static str) -> Result;
+
+    /// Sets the original position of the next token which is written without
+    /// a span.
+    fn set_next_src_pos(&mut self, pos: BytePos) -> Result;
 }
 
 impl<W> WriteJs for Box<W>
@@ -93,4 +97,8 @@ where
     fn write_punct(&mut self, s: &'static str) -> Result {
         (**self).write_punct(s)
     }
+
+    fn set_next_src_pos(&mut self, pos: BytePos) -> Result {
+        (**self).set_next_src_pos(pos)
+    }
 }
@@ -1,10 +1,11 @@
 use super::{Result, WriteJs};
+use crate::config::Config;
 use sourcemap::SourceMapBuilder;
 use std::{
     io::{self, Write},
     sync::Arc,
 };
-use swc_common::{FileName, SourceMap, Span};
+use swc_common::{BytePos, FileName, SourceMap, Span};
 
 ///
 /// -----
@@ -15,11 +16,18 @@ use swc_common::{FileName, SourceMap, Span};
 pub struct JsWriter<'a, W: Write> {
     cm: Arc<SourceMap>,
     indent: usize,
+    indent_str: String,
+    /// Only set for minified output.
+    max_line_len: Option<usize>,
     line_start: bool,
     line_count: usize,
     line_pos: usize,
     new_line: &'a str,
     srcmap: Option<&'a mut SourceMapBuilder>,
+    /// Original position of the next token without a span.
+    next_src_pos: Option<BytePos>,
+    /// End of the last token which has a span.
+    last_src_pos: Option<BytePos>,
     wr: W,
     written_bytes: usize,
 }
@@ -34,27 +42,55 @@ impl<'a, W: Write> JsWriter<'a, W> {
         JsWriter {
             cm,
             indent: Default::default(),
+            indent_str: " ".repeat(Config::default().indent_width),
+            max_line_len: None,
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
             new_line,
             srcmap,
+            next_src_pos: None,
+            last_src_pos: None,
             wr,
             written_bytes: 0,
         }
     }
 
-    fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+    /// Applies the options of `cfg` which affect the writer.
+    pub fn with_config(mut self, cfg: &Config) -> Self {
+        self.indent_str = " ".repeat(cfg.indent_width);
+        self.max_line_len = if cfg.minify { cfg.max_line_len } else { None };
+        self
+    }
 
+    fn write_indent_string(&mut self) -> io::Result<usize> {
         let mut cnt = 0;
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            let written = self.wr.write(self.indent_str.as_bytes())?;
+            self.written_bytes += written;
+            self.line_pos += written;
+            cnt += written;
         }
 
         Ok(cnt)
     }
 
+    /// Breaks the line if it's longer than `max_line_len`.
+    ///
+    /// This must be called only after a token which cannot be followed by a
+    /// restricted production, like `,`.
+    fn wrap_line(&mut self) -> io::Result<()> {
+        if let Some(max_line_len) = self.max_line_len {
+            if self.line_pos >= max_line_len {
+                self.raw_write(self.new_line.as_bytes())?;
+                self.line_count += 1;
+                self.line_pos = 0;
+            }
+        }
+
+        Ok(())
+    }
+
     fn raw_write(&mut self, data: &[u8]) -> io::Result<usize> {
         let written = self.wr.write(data)?;
         self.written_bytes += written;
@@ -62,46 +98,98 @@ impl<'a, W: Write> JsWriter<'a, W> {
         Ok(written)
     }
 
-    fn write(&mut self, span: Option<Span>, data: &str) -> io::Result<usize> {
-        let mut cnt = 0;
+    /// Adds a mapping from the current position to `pos`.
+    fn add_srcmap(&mut self, pos: BytePos, name: Option<&str>) {
+        if let Some(ref mut srcmap) = self.srcmap {
+            let loc = self.cm.lookup_char_pos(pos);
+
+            let src = match loc.file.name {
+                FileName::Real(ref p) => Some(p.display().to_string()),
+                _ => None,
+            };
+            srcmap.add(
+                self.line_count as _,
+                self.line_pos as _,
+                (loc.line - 1) as _,
+                loc.col.0 as _,
+                src.as_ref().map(|s| &**s),
+                name,
+            );
+        }
+    }
 
-        macro_rules! srcmap {
-            ($byte_pos:expr) => {{
-                if let Some(ref mut srcmap) = self.srcmap {
-                    let loc = self.cm.lookup_char_pos($byte_pos);
-
-                    let src = match loc.file.name {
-                        FileName::Real(ref p) => Some(p.display().to_string()),
-                        _ => None,
-                    };
-                    srcmap.add(
-                        self.line_count as _,
-                        self.line_pos as _,
-                        (loc.line - 1) as _,
-                        loc.col.0 as _,
-                        src.as_ref().map(|s| &**s),
-                        None,
-                    );
-                }
-            }};
+    /// Returns the name of an identifier in the original source if it's
+    /// renamed.
+    ///
+    /// Identifiers synthesized from other nodes, like `_ref` for `getObj()`,
+    /// have no original name.
+    fn orig_name(&self, span: Span, s: &str) -> Option<String> {
+        fn is_ident_char(c: char) -> bool {
+            c == '$' || c == '_' || c.is_alphanumeric()
         }
 
-        if !data.is_empty() {
-            if let Some(span) = span {
-                if !span.is_dummy() {
-                    srcmap!(span.lo())
-                }
-            }
+        if self.srcmap.is_none() || span.is_dummy() || !s.chars().all(is_ident_char) {
+            return None;
+        }
+
+        let snippet = self.cm.span_to_snippet(span).ok()?;
+        if snippet.is_empty()
+            || snippet.starts_with(|c: char| c.is_ascii_digit())
+            || !snippet.chars().all(is_ident_char)
+            || snippet == s
+        {
+            return None;
+        }
+
+        Some(snippet)
+    }
 
+    fn write(&mut self, span: Option<Span>, data: &str) -> io::Result<usize> {
+        self.write_with_name(span, None, data)
+    }
+
+    /// `name` is recorded in the source map as the original name of `data`.
+    fn write_with_name(
+        &mut self,
+        span: Option<Span>,
+        name: Option<&str>,
+        data: &str,
+    ) -> io::Result<usize> {
+        let mut cnt = 0;
+
+        if !data.is_empty() {
+            // Mappings should point to the token, not to the indentation.
             if self.line_start {
                 cnt += self.write_indent_string()?;
                 self.line_start = false;
             }
+
+            match span {
+                Some(span) if !span.is_dummy() => {
+                    self.next_src_pos = None;
+                    self.add_srcmap(span.lo(), name);
+                }
+                _ if data.trim().is_empty() => {}
+                // Tokens without a span, like punctuators, are mapped to the start of
+                // the node or to the end of the previous token.
+                _ => {
+                    if let Some(pos) = self.next_src_pos.take().or(self.last_src_pos) {
+                        self.add_srcmap(pos, None);
+                    }
+                }
+            }
+
             cnt += self.raw_write(data.as_bytes())?;
+            // Template literals and comments may contain line breaks.
+            if let Some(idx) = data.rfind('\n') {
+                self.line_count += data.matches('\n').count();
+                self.line_pos = data.len() - idx - 1;
+            }
 
             if let Some(span) = span {
                 if !span.is_dummy() {
-                    srcmap!(span.hi())
+                    self.add_srcmap(span.hi(), None);
+                    self.last_src_pos = Some(span.hi());
                 }
             }
         }
@@ -122,6 +210,7 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
 
     fn write_semi(&mut self) -> Result {
         self.write(None, ";")?;
+        self.wrap_line()?;
         Ok(())
     }
     fn write_space(&mut self) -> Result {
@@ -161,17 +250,7 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
-        if !s.is_empty() {
-            self.write(Some(span), s)?;
-
-            let line_start_of_s = compute_line_starts(s);
-            if line_start_of_s.len() > 1 {
-                self.line_count = self.line_count + line_start_of_s.len() - 1;
-                self.line_pos =
-                    self.written_bytes - s.len() + line_start_of_s.last().cloned().unwrap_or(0);
-            }
-        }
-
+        self.write(Some(span), s)?;
         Ok(())
     }
 
@@ -191,48 +270,21 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
 
     fn write_symbol(&mut self, span: Span, s: &str) -> Result {
-        self.write(Some(span), s)?;
+        let name = self.orig_name(span, s);
+        self.write_with_name(Some(span), name.as_ref().map(|n| &**n), s)?;
         Ok(())
     }
 
     fn write_punct(&mut self, s: &'static str) -> Result {
         self.write(None, s)?;
+        if s == ";" || s == "," || s == "{" {
+            self.wrap_line()?;
+        }
         Ok(())
     }
-}
-
-fn compute_line_starts(s: &str) -> Vec<usize> {
-    let mut res = vec![];
-
-    let mut line_start = 0;
-
-    let mut chars = s.char_indices().peekable();
-
-    while let Some((pos, c)) = chars.next() {
-        match c {
-            '\r' => {
-                if let Some(&(_, '\n')) = chars.peek() {
-                    let _ = chars.next();
-                }
-            }
 
-            '\n' => {
-                res.push(line_start);
-                line_start = pos;
-                break;
-            }
-
-            _ => {
-                // if c > MAX_ASCII_CHAR && is_line_break(c) {
-                //     res.push(line_start);
-                //     line_start = pos;
-                // }
-                unimplemented!("compute_line_starts(char = {:?})", c)
-            }
-        }
+    fn set_next_src_pos(&mut self, pos: BytePos) -> Result {
+        self.next_src_pos = Some(pos);
+        Ok(())
     }
-
-    // Last line.
-    res.push(line_start);
-    res
 }
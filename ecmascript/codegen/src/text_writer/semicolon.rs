@@ -1,10 +1,11 @@
 use super::{Result, WriteJs};
-use swc_common::Span;
+use swc_common::{BytePos, Span};
 
 pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
     OmitTrailingSemi {
         inner: w,
         pending_semi: false,
+        pending_src_pos: None,
     }
 }
 
@@ -12,6 +13,8 @@ pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
 struct OmitTrailingSemi<W: WriteJs> {
     inner: W,
     pending_semi: bool,
+    /// Position passed to `set_next_src_pos` while a semicolon is pending.
+    pending_src_pos: Option<BytePos>,
 }
 
 macro_rules! with_semi {
@@ -54,6 +57,16 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_str(s: &str));
     with_semi!(write_symbol(span: Span, s: &str));
     with_semi!(write_punct(s: &'static str));
+
+    fn set_next_src_pos(&mut self, pos: BytePos) -> Result {
+        // A pending semicolon would take the position if it's written later.
+        if self.pending_semi {
+            self.pending_src_pos = Some(pos);
+            return Ok(());
+        }
+
+        self.inner.set_next_src_pos(pos)
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
@@ -62,6 +75,9 @@ impl<W: WriteJs> OmitTrailingSemi<W> {
             self.inner.write_punct(";")?;
             self.pending_semi = false;
         }
+        if let Some(pos) = self.pending_src_pos.take() {
+            self.inner.set_next_src_pos(pos)?;
+        }
         Ok(())
     }
 }
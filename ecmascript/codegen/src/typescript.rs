@@ -1,4 +1,5 @@
-use super::{Emitter, Result};
+use super::{list::ListFormat, Emitter, Result};
+use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
 
@@ -7,327 +8,978 @@ impl<'a> Emitter<'a> {
     pub fn emit_pat_or_ts_param_prop(&mut self, n: &PatOrTsParamProp) -> Result {
         match *n {
             PatOrTsParamProp::Pat(ref n) => emit!(n),
+            PatOrTsParamProp::Param(ref n) => emit!(n),
             PatOrTsParamProp::TsParamProp(ref n) => emit!(n),
         }
     }
 
     #[emitter]
     pub fn emit_ts_array_type(&mut self, n: &TsArrayType) -> Result {
-        unimplemented!("emit_ts_array_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.elem_type);
+        punct!("[");
+        punct!("]");
     }
 
     #[emitter]
     pub fn emit_ts_as_expr(&mut self, n: &TsAsExpr) -> Result {
-        unimplemented!("emit_ts_as_expr")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.expr);
+
+        space!();
+        keyword!("as");
+        space!();
+
+        emit!(n.type_ann);
     }
 
     #[emitter]
     pub fn emit_ts_call_signature_decl(&mut self, n: &TsCallSignatureDecl) -> Result {
-        unimplemented!("emit_ts_call_signature_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!(")");
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_cond_type(&mut self, n: &TsConditionalType) -> Result {
-        unimplemented!("emit_ts_cond_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.check_type);
+        space!();
+
+        keyword!("extends");
+        space!();
+
+        emit!(n.extends_type);
+        formatting_space!();
+        punct!("?");
+
+        formatting_space!();
+        emit!(n.true_type);
+        formatting_space!();
+
+        punct!(":");
+
+        formatting_space!();
+        emit!(n.false_type);
     }
 
     #[emitter]
     pub fn emit_ts_constructor_signature_decl(&mut self, n: &TsConstructSignatureDecl) -> Result {
-        unimplemented!("emit_ts_constructor_signature_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("new");
+        formatting_space!();
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!(")");
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_constructor_type(&mut self, n: &TsConstructorType) -> Result {
-        unimplemented!("emit_ts_constructor_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("new");
+        formatting_space!();
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!(")");
+
+        formatting_space!();
+        punct!("=>");
+        formatting_space!();
+
+        emit!(n.type_ann)
     }
 
     #[emitter]
     pub fn emit_ts_entity_name(&mut self, n: &TsEntityName) -> Result {
-        unimplemented!("emit_ts_entity_name")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsEntityName::TsQualifiedName(ref n) => emit!(n),
+            TsEntityName::Ident(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_enum_decl(&mut self, n: &TsEnumDecl) -> Result {
-        unimplemented!("emit_ts_enum_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.declare {
+            keyword!("declare");
+            space!();
+        }
+
+        if n.is_const {
+            keyword!("const");
+            space!();
+        }
+
+        keyword!("enum");
+        space!();
+
+        emit!(n.id);
+        formatting_space!();
+
+        punct!("{");
+
+        self.emit_list(n.span, Some(&n.members), ListFormat::EnumMembers)?;
+
+        punct!("}");
     }
 
     #[emitter]
     pub fn emit_ts_enum_member(&mut self, n: &TsEnumMember) -> Result {
-        unimplemented!("emit_ts_enum_member")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.id);
+
+        if let Some(ref init) = n.init {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(init);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_enum_member_id(&mut self, n: &TsEnumMemberId) -> Result {
-        unimplemented!("emit_ts_enum_member_id")
+        match *n {
+            TsEnumMemberId::Ident(ref n) => emit!(n),
+            TsEnumMemberId::Str(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_export_assignment(&mut self, n: &TsExportAssignment) -> Result {
-        unimplemented!("emit_ts_export_assignment")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("export");
+        formatting_space!();
+        punct!("=");
+        formatting_space!();
+
+        emit!(n.expr);
+        semi!();
     }
 
     #[emitter]
     pub fn emit_ts_expr_with_type_args(&mut self, n: &TsExprWithTypeArgs) -> Result {
-        unimplemented!("emit_ts_expr_with_type_args")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.expr);
+
+        emit!(n.type_args);
     }
 
     #[emitter]
     pub fn emit_ts_external_module_ref(&mut self, n: &TsExternalModuleRef) -> Result {
-        unimplemented!("emit_ts_external_module_ref")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("require");
+        punct!("(");
+        emit!(n.expr);
+        punct!(")");
     }
 
     #[emitter]
     pub fn emit_ts_fn_or_constructor_type(&mut self, n: &TsFnOrConstructorType) -> Result {
-        unimplemented!("emit_ts_fn_or_constructor_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsFnOrConstructorType::TsFnType(ref n) => emit!(n),
+            TsFnOrConstructorType::TsConstructorType(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_fn_param(&mut self, n: &TsFnParam) -> Result {
-        unimplemented!("emit_ts_fn_param")
+        match *n {
+            TsFnParam::Ident(ref n) => emit!(n),
+            TsFnParam::Array(ref n) => emit!(n),
+            TsFnParam::Rest(ref n) => emit!(n),
+            TsFnParam::Object(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_fn_type(&mut self, n: &TsFnType) -> Result {
-        unimplemented!("emit_ts_fn_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!(")");
+
+        formatting_space!();
+        punct!("=>");
+        formatting_space!();
+
+        emit!(n.type_ann);
     }
 
     #[emitter]
     pub fn emit_ts_import_equals_decl(&mut self, n: &TsImportEqualsDecl) -> Result {
-        unimplemented!("emit_ts_import_equals_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.is_export {
+            keyword!("export");
+            space!();
+        }
+
+        keyword!("import");
+        space!();
+
+        emit!(n.id);
+
+        formatting_space!();
+        punct!("=");
+        formatting_space!();
+
+        emit!(n.module_ref);
+        semi!();
     }
 
     #[emitter]
     pub fn emit_ts_index_signature(&mut self, n: &TsIndexSignature) -> Result {
-        unimplemented!("emit_ts_index_signature")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.readonly {
+            keyword!("readonly");
+            formatting_space!();
+        }
+
+        punct!("[");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!("]");
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_index_accessed_type(&mut self, n: &TsIndexedAccessType) -> Result {
-        unimplemented!("emit_ts_index_accessed_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.obj_type);
+
+        punct!("[");
+        emit!(n.index_type);
+        punct!("]");
     }
 
     #[emitter]
     pub fn emit_ts_infer_type(&mut self, n: &TsInferType) -> Result {
-        unimplemented!("emit_ts_infer_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("infer");
+        space!();
+        emit!(n.type_param);
     }
 
     #[emitter]
     pub fn emit_ts_interface_body(&mut self, n: &TsInterfaceBody) -> Result {
-        unimplemented!("emit_ts_interface_body")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("{");
+
+        self.emit_list(n.span, Some(&n.body), ListFormat::InterfaceMembers)?;
+
+        punct!("}");
     }
 
     #[emitter]
     pub fn emit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) -> Result {
-        unimplemented!("emit_ts_interface_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.declare {
+            keyword!("declare");
+            space!();
+        }
+
+        keyword!("interface");
+        space!();
+
+        emit!(n.id);
+
+        emit!(n.type_params);
+
+        if !n.extends.is_empty() {
+            space!();
+
+            keyword!("extends");
+
+            space!();
+
+            self.emit_list(n.span, Some(&n.extends), ListFormat::HeritageClauseTypes)?;
+        }
+
+        formatting_space!();
+
+        emit!(n.body);
     }
 
     #[emitter]
     pub fn emit_ts_intersection_type(&mut self, n: &TsIntersectionType) -> Result {
-        unimplemented!("emit_ts_intersection_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        self.emit_list(
+            n.span,
+            Some(&n.types),
+            ListFormat::IntersectionTypeConstituents,
+        )?;
     }
 
     #[emitter]
     pub fn emit_ts_keyword_type(&mut self, n: &TsKeywordType) -> Result {
-        unimplemented!("emit_ts_keyword_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match n.kind {
+            TsKeywordTypeKind::TsAnyKeyword => keyword!(n.span, "any"),
+            TsKeywordTypeKind::TsUnknownKeyword => keyword!(n.span, "unknown"),
+            TsKeywordTypeKind::TsNumberKeyword => keyword!(n.span, "number"),
+            TsKeywordTypeKind::TsObjectKeyword => keyword!(n.span, "object"),
+            TsKeywordTypeKind::TsBooleanKeyword => keyword!(n.span, "boolean"),
+            TsKeywordTypeKind::TsBigIntKeyword => keyword!(n.span, "bigint"),
+            TsKeywordTypeKind::TsStringKeyword => keyword!(n.span, "string"),
+            TsKeywordTypeKind::TsSymbolKeyword => keyword!(n.span, "symbol"),
+            TsKeywordTypeKind::TsVoidKeyword => keyword!(n.span, "void"),
+            TsKeywordTypeKind::TsUndefinedKeyword => keyword!(n.span, "undefined"),
+            TsKeywordTypeKind::TsNullKeyword => keyword!(n.span, "null"),
+            TsKeywordTypeKind::TsNeverKeyword => keyword!(n.span, "never"),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_lit(&mut self, n: &TsLit) -> Result {
-        unimplemented!("emit_ts_lit")
+        match *n {
+            TsLit::Number(ref n) => emit!(n),
+            TsLit::Str(ref n) => emit!(n),
+            TsLit::Bool(Bool { span, value }) => {
+                if value {
+                    keyword!(span, "true")
+                } else {
+                    keyword!(span, "false")
+                }
+            }
+        }
     }
 
     #[emitter]
     pub fn emit_ts_lit_type(&mut self, n: &TsLitType) -> Result {
-        unimplemented!("emit_ts_lit_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.lit);
     }
 
     #[emitter]
     pub fn emit_ts_mapped_type(&mut self, n: &TsMappedType) -> Result {
-        unimplemented!("emit_ts_mapped_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("{");
+        formatting_space!();
+
+        match n.readonly {
+            None => {}
+            Some(tpm) => {
+                match tpm {
+                    TruePlusMinus::True => {}
+                    TruePlusMinus::Plus => punct!("+"),
+                    TruePlusMinus::Minus => punct!("-"),
+                }
+                keyword!("readonly");
+                space!();
+            }
+        }
+
+        punct!("[");
+        emit!(n.type_param.name);
+
+        if let Some(ref constraint) = n.type_param.constraint {
+            space!();
+            keyword!("in");
+            space!();
+            emit!(constraint);
+        }
+        punct!("]");
+
+        match n.optional {
+            None => {}
+            Some(tpm) => match tpm {
+                TruePlusMinus::True => punct!("?"),
+                TruePlusMinus::Plus => {
+                    punct!("+");
+                    punct!("?");
+                }
+                TruePlusMinus::Minus => {
+                    punct!("-");
+                    punct!("?");
+                }
+            },
+        }
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
+
+        formatting_space!();
+        punct!("}");
     }
 
     #[emitter]
     pub fn emit_ts_method_signature(&mut self, n: &TsMethodSignature) -> Result {
-        unimplemented!("emit_ts_method_signature")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
+        if n.computed {
+            punct!("[");
+            emit!(n.key);
+            punct!("]");
+        } else {
+            emit!(n.key)
+        }
+
+        if n.optional {
+            punct!("?");
+        }
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::CommaListElements)?;
+        punct!(")");
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_module_block(&mut self, n: &TsModuleBlock) -> Result {
-        unimplemented!("emit_ts_module_block")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("{");
+        self.emit_list(n.span, Some(&n.body), ListFormat::MultiLineBlockStatements)?;
+        punct!("}");
     }
 
     #[emitter]
     pub fn emit_ts_module_decl(&mut self, n: &TsModuleDecl) -> Result {
-        unimplemented!("emit_ts_module_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.declare {
+            keyword!("declare");
+            space!();
+        }
+
+        if n.global {
+            // `declare global { }`
+            keyword!("global");
+        } else {
+            match n.id {
+                TsModuleName::Ident(..) => keyword!("namespace"),
+                TsModuleName::Str(..) => keyword!("module"),
+            }
+            space!();
+            emit!(n.id);
+        }
+
+        match n.body {
+            Some(ref body) => emit!(body),
+            None => semi!(),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_module_name(&mut self, n: &TsModuleName) -> Result {
-        unimplemented!("emit_ts_module_name")
+        match *n {
+            TsModuleName::Ident(ref n) => emit!(n),
+            TsModuleName::Str(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_module_ref(&mut self, n: &TsModuleRef) -> Result {
-        unimplemented!("emit_ts_module_ref")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsModuleRef::TsEntityName(ref n) => emit!(n),
+            TsModuleRef::TsExternalModuleRef(ref n) => emit!(n),
+        }
     }
 
+    /// `namespace A.B { }` is printed as `A` followed by `.B { }`.
     #[emitter]
     pub fn emit_ts_ns_body(&mut self, n: &TsNamespaceBody) -> Result {
-        unimplemented!("emit_ts_ns_body")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsNamespaceBody::TsModuleBlock(ref n) => {
+                formatting_space!();
+                emit!(n)
+            }
+            TsNamespaceBody::TsNamespaceDecl(ref n) => {
+                punct!(".");
+                emit!(n)
+            }
+        }
     }
 
     #[emitter]
     pub fn emit_ts_ns_decl(&mut self, n: &TsNamespaceDecl) -> Result {
-        unimplemented!("emit_ts_ns_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.id);
+        emit!(n.body);
     }
 
     #[emitter]
     pub fn emit_ts_ns_export_decl(&mut self, n: &TsNamespaceExportDecl) -> Result {
-        unimplemented!("emit_ts_ns_export_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("export");
+        space!();
+        keyword!("as");
+        space!();
+        keyword!("namespace");
+        space!();
+
+        emit!(n.id);
+        semi!();
     }
 
     #[emitter]
     pub fn emit_ts_non_null_expr(&mut self, n: &TsNonNullExpr) -> Result {
-        unimplemented!("emit_ts_non_null_expr")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.expr);
+        punct!("!")
     }
 
     #[emitter]
     pub fn emit_ts_optional_type(&mut self, n: &TsOptionalType) -> Result {
-        unimplemented!("emit_ts_optional_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.type_ann);
+        punct!("?");
     }
 
     #[emitter]
     pub fn emit_ts_param_prop(&mut self, n: &TsParamProp) -> Result {
-        unimplemented!("emit_ts_param_prop")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        for dec in &n.decorators {
+            emit!(dec);
+        }
+
+        self.emit_accesibility(n.accessibility)?;
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
+        emit!(n.param);
     }
 
     #[emitter]
     pub fn emit_ts_param_prop_param(&mut self, n: &TsParamPropParam) -> Result {
-        unimplemented!("emit_ts_param_prop_param")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsParamPropParam::Ident(ref n) => emit!(n),
+            TsParamPropParam::Assign(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_paren_type(&mut self, n: &TsParenthesizedType) -> Result {
-        unimplemented!("emit_ts_paren_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("(");
+        emit!(n.type_ann);
+        punct!(")");
     }
 
     #[emitter]
     pub fn emit_ts_property_signature(&mut self, n: &TsPropertySignature) -> Result {
-        unimplemented!("emit_ts_property_signature")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
+        if n.computed {
+            punct!("[");
+            emit!(n.key);
+            punct!("]");
+        } else {
+            emit!(n.key);
+        }
+
+        if n.optional {
+            punct!("?");
+        }
+
+        emit!(n.type_params);
+
+        if let Some(ref type_ann) = n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
+
+        if let Some(ref init) = n.init {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(init);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_qualified_name(&mut self, n: &TsQualifiedName) -> Result {
-        unimplemented!("emit_ts_qualified_name")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.left);
+        punct!(".");
+        emit!(n.right);
     }
 
     #[emitter]
     pub fn emit_ts_rest_type(&mut self, n: &TsRestType) -> Result {
-        unimplemented!("emit_ts_rest_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("...");
+        emit!(n.type_ann);
     }
 
     #[emitter]
     pub fn emit_ts_signature_decl(&mut self, n: &TsSignatureDecl) -> Result {
-        unimplemented!("emit_ts_signature_decl")
+        match *n {
+            TsSignatureDecl::TsCallSignatureDecl(ref n) => emit!(n),
+            TsSignatureDecl::TsConstructSignatureDecl(ref n) => emit!(n),
+            TsSignatureDecl::TsMethodSignature(ref n) => emit!(n),
+            TsSignatureDecl::TsFnType(ref n) => emit!(n),
+            TsSignatureDecl::TsConstructorType(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_this_type(&mut self, n: &TsThisType) -> Result {
-        unimplemented!("emit_ts_this_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!(n.span, "this");
     }
 
     #[emitter]
     pub fn emit_ts_this_type_or_ident(&mut self, n: &TsThisTypeOrIdent) -> Result {
-        unimplemented!("emit_ts_this_type_or_ident")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        match *n {
+            TsThisTypeOrIdent::TsThisType(ref n) => emit!(n),
+            TsThisTypeOrIdent::Ident(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_tuple_type(&mut self, n: &TsTupleType) -> Result {
-        unimplemented!("emit_ts_tuple_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("[");
+        self.emit_list(n.span, Some(&n.elem_types), ListFormat::TupleTypeElements)?;
+        punct!("]");
     }
 
     #[emitter]
     pub fn emit_ts_type(&mut self, n: &TsType) -> Result {
-        unimplemented!("emit_ts_type")
+        match *n {
+            TsType::TsKeywordType(ref n) => emit!(n),
+            TsType::TsThisType(ref n) => emit!(n),
+            TsType::TsFnOrConstructorType(ref n) => emit!(n),
+            TsType::TsTypeRef(ref n) => emit!(n),
+            TsType::TsTypeQuery(ref n) => emit!(n),
+            TsType::TsTypeLit(ref n) => emit!(n),
+            TsType::TsArrayType(ref n) => emit!(n),
+            TsType::TsTupleType(ref n) => emit!(n),
+            TsType::TsOptionalType(ref n) => emit!(n),
+            TsType::TsRestType(ref n) => emit!(n),
+            TsType::TsUnionOrIntersectionType(ref n) => emit!(n),
+            TsType::TsConditionalType(ref n) => emit!(n),
+            TsType::TsInferType(ref n) => emit!(n),
+            TsType::TsParenthesizedType(ref n) => emit!(n),
+            TsType::TsTypeOperator(ref n) => emit!(n),
+            TsType::TsIndexedAccessType(ref n) => emit!(n),
+            TsType::TsMappedType(ref n) => emit!(n),
+            TsType::TsLitType(ref n) => emit!(n),
+            TsType::TsTypePredicate(ref n) => emit!(n),
+            TsType::TsImportType(ref n) => emit!(n),
+        }
+    }
+
+    #[emitter]
+    pub fn emit_ts_import_type(&mut self, n: &TsImportType) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("import");
+        punct!("(");
+        emit!(n.arg);
+        punct!(")");
+
+        if let Some(ref qualifier) = n.qualifier {
+            punct!(".");
+            emit!(qualifier);
+        }
+
+        emit!(n.type_args);
     }
 
     #[emitter]
     pub fn emit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) -> Result {
-        unimplemented!("emit_ts_type_alias_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.declare {
+            keyword!("declare");
+            space!();
+        }
+
+        keyword!("type");
+        space!();
+
+        emit!(n.id);
+        emit!(n.type_params);
+        formatting_space!();
+
+        punct!("=");
+
+        formatting_space!();
+
+        emit!(n.type_ann);
+
+        semi!();
     }
 
+    /// Emits only the type. The caller is responsible for the leading colon.
     #[emitter]
     pub fn emit_ts_type_ann(&mut self, n: &TsTypeAnn) -> Result {
-        unimplemented!("emit_ts_type_ann")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.type_ann)
     }
 
     #[emitter]
     pub fn emit_ts_type_assertion(&mut self, n: &TsTypeAssertion) -> Result {
-        unimplemented!("emit_ts_type_assertion")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("<");
+        emit!(n.type_ann);
+        punct!(">");
+        emit!(n.expr);
     }
 
     #[emitter]
     pub fn emit_ts_const_assertion(&mut self, n: &TsConstAssertion) -> Result {
-        unimplemented!("emit_ts_const_assertion")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.expr);
+
+        space!();
+        keyword!("as");
+        space!();
+        keyword!("const");
     }
 
     #[emitter]
     pub fn emit_ts_type_cast_expr(&mut self, n: &TsTypeCastExpr) -> Result {
-        unimplemented!("emit_ts_type_cast_expr")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        // `(a: T)` is only valid with parens.
+        punct!("(");
+        emit!(n.expr);
+        punct!(":");
+        formatting_space!();
+        emit!(n.type_ann);
+        punct!(")");
     }
 
+    /// Members of interfaces and type literals are always terminated by a
+    /// semicolon.
     #[emitter]
     pub fn emit_ts_type_element(&mut self, n: &TsTypeElement) -> Result {
-        unimplemented!("emit_ts_type_element")
+        match *n {
+            TsTypeElement::TsCallSignatureDecl(ref n) => emit!(n),
+            TsTypeElement::TsConstructSignatureDecl(ref n) => emit!(n),
+            TsTypeElement::TsPropertySignature(ref n) => emit!(n),
+            TsTypeElement::TsMethodSignature(ref n) => emit!(n),
+            TsTypeElement::TsIndexSignature(ref n) => emit!(n),
+        }
+        semi!();
     }
 
     #[emitter]
     pub fn emit_ts_type_lit(&mut self, n: &TsTypeLit) -> Result {
-        unimplemented!("emit_ts_type_lit")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("{");
+        self.emit_list(
+            n.span,
+            Some(&n.members),
+            ListFormat::MultiLineTypeLiteralMembers,
+        )?;
+        punct!("}");
     }
 
     #[emitter]
     pub fn emit_ts_type_operator(&mut self, n: &TsTypeOperator) -> Result {
-        unimplemented!("emit_ts_type_operator")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!(n.op.as_str());
+        space!();
+        emit!(n.type_ann);
     }
 
     #[emitter]
     pub fn emit_ts_type_param(&mut self, n: &TsTypeParam) -> Result {
-        unimplemented!("emit_ts_type_param")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.name);
+
+        if let Some(ref constraint) = n.constraint {
+            space!();
+            keyword!("extends");
+            space!();
+            emit!(constraint);
+        }
+
+        if let Some(ref default) = n.default {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+            emit!(default);
+        }
     }
 
     #[emitter]
     pub fn emit_ts_type_param_decl(&mut self, n: &TsTypeParamDecl) -> Result {
-        unimplemented!("emit_ts_type_param_decl")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        self.emit_list(n.span, Some(&n.params), ListFormat::TypeParameters)?;
     }
 
     #[emitter]
     pub fn emit_ts_type_param_instantiation(&mut self, n: &TsTypeParamInstantiation) -> Result {
-        unimplemented!("emit_ts_type_param_instantiation")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        self.emit_list(n.span, Some(&n.params), ListFormat::TypeArguments)?;
     }
 
     #[emitter]
     pub fn emit_ts_type_predicate(&mut self, n: &TsTypePredicate) -> Result {
-        unimplemented!("emit_ts_type_predicate")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if n.asserts {
+            keyword!("asserts");
+            space!();
+        }
+
+        emit!(n.param_name);
+
+        space!();
+        keyword!("is");
+        space!();
+        emit!(n.type_ann);
     }
 
     #[emitter]
     pub fn emit_ts_type_query(&mut self, n: &TsTypeQuery) -> Result {
-        unimplemented!("emit_ts_type_query")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("typeof");
+        space!();
+        emit!(n.expr_name);
+    }
+
+    #[emitter]
+    pub fn emit_ts_type_query_expr(&mut self, n: &TsTypeQueryExpr) -> Result {
+        match *n {
+            TsTypeQueryExpr::TsEntityName(ref n) => emit!(n),
+            TsTypeQueryExpr::Import(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_type_ref(&mut self, n: &TsTypeRef) -> Result {
-        unimplemented!("emit_ts_type_ref")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.type_name);
+
+        emit!(n.type_params);
     }
 
     #[emitter]
     pub fn emit_ts_union_or_intersection_type(&mut self, n: &TsUnionOrIntersectionType) -> Result {
-        unimplemented!("emit_ts_union_or_intersection_type")
+        match *n {
+            TsUnionOrIntersectionType::TsUnionType(ref n) => emit!(n),
+            TsUnionOrIntersectionType::TsIntersectionType(ref n) => emit!(n),
+        }
     }
 
     #[emitter]
     pub fn emit_ts_union_type(&mut self, n: &TsUnionType) -> Result {
-        unimplemented!("emit_ts_union_type")
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        self.emit_list(n.span, Some(&n.types), ListFormat::UnionTypeConstituents)?;
+    }
+
+    /// Prints `public `, `protected ` or `private `.
+    pub fn emit_accesibility(&mut self, n: Option<Accessibility>) -> Result {
+        if let Some(a) = n {
+            match a {
+                Accessibility::Public => keyword!(self, "public"),
+                Accessibility::Protected => keyword!(self, "protected"),
+                Accessibility::Private => keyword!(self, "private"),
+            }
+            space!(self);
+        }
+
+        Ok(())
     }
 }
@@ -211,6 +211,12 @@ impl StartsWithAlphaNum for Expr {
             // TODO
             Expr::TsTypeCast(..) => true,
             Expr::OptChain(ref e) => e.expr.starts_with_alpha_num(),
+            Expr::Pipeline(ref e) => e.left.starts_with_alpha_num(),
+            Expr::Bind(BindExpr {
+                object: Some(ref object),
+                ..
+            }) => object.starts_with_alpha_num(),
+            Expr::Bind(..) => false,
 
             Expr::Invalid(..) => true,
         }
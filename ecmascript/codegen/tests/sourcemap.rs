@@ -0,0 +1,176 @@
+#![feature(box_syntax)]
+#![feature(specialization)]
+
+use sourcemap::{SourceMap as RawSourceMap, SourceMapBuilder};
+use swc_common::{FileName, Fold, FoldWith};
+use swc_ecma_ast::{CallExpr, Expr, ExprOrSuper, Ident, Module};
+use swc_ecma_codegen::{
+    self,
+    text_writer::{omit_trailing_semi, JsWriter},
+    Emitter,
+};
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+struct MyHandlers;
+
+impl swc_ecma_codegen::Handlers for MyHandlers {}
+
+/// Renames identifiers like the hygiene pass does.
+struct Renamer(&'static [(&'static str, &'static str)]);
+
+impl Fold<Ident> for Renamer {
+    fn fold(&mut self, i: Ident) -> Ident {
+        match self.0.iter().find(|&&(from, _)| *i.sym == *from) {
+            Some(&(_, to)) => Ident { sym: to.into(), ..i },
+            None => i,
+        }
+    }
+}
+
+/// Replaces calls like `getObj()` with an alias like `_ref`, spanning the call.
+struct Aliaser;
+
+impl Fold<Expr> for Aliaser {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(..),
+                ..
+            }) => Expr::Ident(Ident::new("_ref".into(), span)),
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+/// Prints `src` after renaming identifiers and returns the code with the
+/// decoded source map.
+fn print_with_map(src: &str, renamer: Renamer, minify: bool) -> (String, RawSourceMap) {
+    print_folded_with_map(src, renamer, minify)
+}
+
+fn print_folded_with_map<F>(src: &str, mut folder: F, minify: bool) -> (String, RawSourceMap)
+where
+    F: Fold<Module>,
+{
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Real("input.js".into()), src.to_string());
+        let module: Module = Parser::new(
+            Session { handler },
+            Syntax::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        )
+        .parse_module()
+        .map_err(|mut e| {
+            e.emit();
+        })?
+        .fold_with(&mut folder);
+
+        let mut buf = vec![];
+        let mut builder = SourceMapBuilder::new(None);
+        {
+            let cfg = swc_ecma_codegen::Config {
+                minify,
+                ..Default::default()
+            };
+            let wr = JsWriter::new(cm.clone(), "\n", &mut buf, Some(&mut builder));
+            let mut emitter = Emitter {
+                cfg,
+                cm: cm.clone(),
+                wr: if minify {
+                    box omit_trailing_semi(wr)
+                } else {
+                    box wr
+                },
+                comments: None,
+                handlers: box MyHandlers,
+            };
+
+            emitter.emit_module(&module).unwrap();
+        }
+
+        // Round-trip through the json representation.
+        let mut map = vec![];
+        builder.into_sourcemap().to_writer(&mut map).unwrap();
+        let map = RawSourceMap::from_slice(&map).unwrap();
+
+        Ok((String::from_utf8(buf).unwrap(), map))
+    })
+    .unwrap()
+}
+
+/// Asserts that the token at `dst` of the generated code is mapped to `src`.
+fn assert_mapping(map: &RawSourceMap, dst: (u32, u32), src: (u32, u32), name: Option<&str>) {
+    let token = map
+        .lookup_token(dst.0, dst.1)
+        .unwrap_or_else(|| panic!("no mapping for {:?}", dst));
+
+    assert_eq!(token.get_dst(), dst, "no mapping starts at {:?}", dst);
+    assert_eq!(token.get_src(), src, "wrong mapping for {:?}", dst);
+    assert_eq!(token.get_source(), Some("input.js"));
+    assert_eq!(token.get_name(), name, "wrong name for {:?}", dst);
+}
+
+#[test]
+fn renamed_idents() {
+    let (code, map) = print_with_map(
+        "function foo(a) {\n    return a + 1;\n}\nfoo(2);\n",
+        Renamer(&[("a", "a1")]),
+        false,
+    );
+    assert_eq!(code.trim(), "function foo(a1) {\n    return a1 + 1;\n}\nfoo(2);");
+
+    // `foo`
+    assert_mapping(&map, (0, 9), (0, 9), None);
+    // `a1`
+    assert_mapping(&map, (0, 13), (0, 13), Some("a"));
+    assert_mapping(&map, (1, 11), (1, 11), Some("a"));
+    // `foo` of the call
+    assert_mapping(&map, (3, 0), (3, 0), None);
+
+    assert_eq!(map.names().collect::<Vec<_>>(), vec!["a"]);
+}
+
+#[test]
+fn synthesized_alias() {
+    let (code, map) = print_folded_with_map("getObj().a;\n", Aliaser, false);
+    assert_eq!(code.trim(), "_ref.a;");
+
+    // `_ref` is not an original name of `getObj()`.
+    assert_mapping(&map, (0, 0), (0, 0), None);
+    assert_eq!(map.names().count(), 0);
+}
+
+#[test]
+fn tokens_without_span() {
+    let (code, map) = print_with_map("if (a) {\n    return b;\n}\n", Renamer(&[]), false);
+    assert_eq!(code.trim(), "if (a) {\n    return b;\n}");
+
+    // `if`
+    assert_mapping(&map, (0, 0), (0, 0), None);
+    // `return` is mapped to the statement, not to the indentation.
+    assert_mapping(&map, (1, 4), (1, 4), None);
+    // `;` is mapped to the end of `b`.
+    assert_mapping(&map, (1, 12), (1, 12), None);
+}
+
+#[test]
+fn multi_line_template() {
+    let (code, map) = print_with_map("a = `x\ny`;\nb;\n", Renamer(&[]), false);
+    assert_eq!(code.trim(), "a = `x\ny`;\nb;");
+
+    // Lines of the template are counted.
+    assert_mapping(&map, (2, 0), (2, 0), None);
+}
+
+#[test]
+fn omitted_trailing_semi() {
+    let (code, map) = print_with_map("a;\n[b];\n", Renamer(&[]), true);
+    assert_eq!(code.trim(), "a;[b]");
+
+    // `;` is mapped to the end of `a`, not to the next statement.
+    assert_mapping(&map, (0, 1), (0, 1), None);
+    // `[`
+    assert_mapping(&map, (0, 2), (1, 0), None);
+}
@@ -0,0 +1,174 @@
+#![feature(box_syntax)]
+#![feature(specialization)]
+#![feature(test)]
+
+extern crate test;
+
+use std::{
+    env,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+use swc_common::{FileName, SourceMap};
+use swc_ecma_ast::Module;
+use swc_ecma_codegen::{self, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, Session, SourceFileInput, Syntax, TsConfig};
+use test::{
+    test_main, DynTestFn, Options, ShouldPanic::No, TestDesc, TestDescAndFn, TestName, TestType,
+};
+use testing::drop_span;
+use walkdir::WalkDir;
+
+fn add_test<F: FnOnce() + Send + 'static>(
+    tests: &mut Vec<TestDescAndFn>,
+    name: String,
+    ignore: bool,
+    f: F,
+) {
+    tests.push(TestDescAndFn {
+        desc: TestDesc {
+            test_type: TestType::UnitTest,
+            name: TestName::DynTestName(name),
+            ignore,
+            should_panic: No,
+            allow_fail: false,
+        },
+        testfn: DynTestFn(box f),
+    });
+}
+
+struct MyHandlers;
+
+impl swc_ecma_codegen::Handlers for MyHandlers {}
+
+fn parse(
+    cm: &Arc<SourceMap>,
+    handler: &swc_common::errors::Handler,
+    tsx: bool,
+    name: FileName,
+    src: String,
+) -> Result<Module, ()> {
+    let fm = cm.new_source_file(name, src);
+
+    let lexer = Lexer::new(
+        Session { handler },
+        Syntax::Typescript(TsConfig {
+            tsx,
+            dynamic_import: true,
+            decorators: true,
+            ..Default::default()
+        }),
+        Default::default(),
+        SourceFileInput::from(&*fm),
+        None,
+    );
+    let mut parser: Parser<'_, Lexer<'_, SourceFileInput<'_>>> =
+        Parser::new_from(Session { handler }, lexer);
+
+    parser.parse_module().map_err(|mut e| {
+        e.emit();
+    })
+}
+
+fn print(cm: &Arc<SourceMap>, m: &Module) -> String {
+    let wr = Buf(Arc::new(RwLock::new(vec![])));
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            wr: box swc_ecma_codegen::text_writer::JsWriter::new(
+                cm.clone(),
+                "\n",
+                wr.clone(),
+                None,
+            ),
+            comments: None,
+            handlers: box MyHandlers,
+        };
+
+        emitter.emit_module(m).unwrap();
+    }
+
+    let code = wr.0.read().unwrap();
+    String::from_utf8_lossy(&code).into_owned()
+}
+
+/// Parses each typescript fixture of the parser, prints it, and checks that
+/// the printed code is parsed to the same module and printed the same way.
+fn identity_tests(tests: &mut Vec<TestDescAndFn>) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("parser")
+        .join("tests")
+        .join("typescript");
+
+    eprintln!("Loading tests from {}", dir.display());
+
+    for entry in WalkDir::new(&dir).into_iter() {
+        let entry = entry.expect("failed to read directory");
+        let is_ts = entry.file_name().to_string_lossy().ends_with(".ts");
+        let is_tsx = entry.file_name().to_string_lossy().ends_with(".tsx");
+        if entry.file_type().is_dir() || !(is_ts || is_tsx) {
+            continue;
+        }
+
+        let file_name = entry
+            .path()
+            .strip_prefix(&dir)
+            .expect("failed to strip prefix")
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let name = format!("typescript::identity::{}", file_name);
+        let path = entry.path().to_path_buf();
+
+        add_test(tests, name, false, move || {
+            ::testing::run_test(false, |cm, handler| {
+                let src = std::fs::read_to_string(&path).expect("failed to read file");
+                eprintln!(
+                    "\n\n========== Running codegen test {}\nSource:\n{}\n",
+                    file_name, src
+                );
+
+                let expected = parse(&cm, &handler, is_tsx, FileName::Real(path.clone()), src)?;
+                let first = print(&cm, &expected);
+
+                let actual = parse(&cm, &handler, is_tsx, FileName::Anon, first.clone())?;
+                let second = print(&cm, &actual);
+
+                assert_eq!(
+                    drop_span(actual),
+                    drop_span(expected),
+                    "printed code should have the same meaning:\n{}",
+                    first
+                );
+                assert_eq!(first, second, "printed code should be stable");
+
+                Ok(())
+            })
+            .expect("failed to run test");
+        });
+    }
+}
+
+#[test]
+fn identity() {
+    let args: Vec<_> = env::args().collect();
+    let mut tests = Vec::new();
+    identity_tests(&mut tests);
+    test_main(&args, tests, Some(Options::new()));
+}
+
+#[derive(Debug, Clone)]
+struct Buf(Arc<RwLock<Vec<u8>>>);
+impl std::io::Write for Buf {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.0.write().unwrap().write(data)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.write().unwrap().flush()
+    }
+}
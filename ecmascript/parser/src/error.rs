@@ -61,6 +61,28 @@ pub enum SyntaxError {
         word: JsWord,
     },
     UnterminatedRegxp,
+    UnknownRegexpFlag {
+        c: char,
+    },
+    DuplicateRegexpFlag {
+        c: char,
+    },
+    RegexpNothingToRepeat,
+    RegexpLoneQuantifierBrackets,
+    RegexpQuantifierOutOfOrder,
+    RegexpUnterminatedGroup,
+    RegexpUnmatchedParen,
+    RegexpInvalidGroup,
+    RegexpUnterminatedClass,
+    RegexpRangeOutOfOrder,
+    RegexpInvalidClass,
+    RegexpInvalidEscape,
+    RegexpInvalidUnicodeEscape,
+    RegexpInvalidPropertyName,
+    RegexpInvalidReference,
+    RegexpInvalidGroupName,
+    RegexpDuplicateGroupName,
+    RegexpInvalidNamedReference,
     UnterminatedTpl,
     IdentAfterNum,
     UnexpectedChar {
@@ -105,6 +127,12 @@ pub enum SyntaxError {
 
     NullishCoalescingWithLogicalOp,
     NullishCoalescingNotEnabled,
+    LogicalAssignmentNotEnabled,
+    StaticBlocksNotEnabled,
+    DecoratorOnStaticBlock,
+    ImportAssertionsNotEnabled,
+    InvalidBindCallee,
+    InvalidImportAssertion,
 
     MultipleDefault {
         /// Span of the previous default case
@@ -143,6 +171,7 @@ pub enum SyntaxError {
     },
     InvalidLeadingDecorator,
     DecoratorOnExport,
+    UnsupportedParamDecorator,
 
     TsNonLastRest,
     TsRequiredAfterOptional,
@@ -247,6 +276,44 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
                 format!("Unexpected escape sequence in reserved word: {}", word).into()
             }
             UnterminatedRegxp => "Unterminated regexp literal".into(),
+            UnknownRegexpFlag { c } => format!("Invalid regular expression flag {:?}", c).into(),
+            DuplicateRegexpFlag { c } => {
+                format!("Duplicate regular expression flag {:?}", c).into()
+            }
+            RegexpNothingToRepeat => "Invalid regular expression: nothing to repeat".into(),
+            RegexpLoneQuantifierBrackets => {
+                "Invalid regular expression: lone quantifier brackets".into()
+            }
+            RegexpQuantifierOutOfOrder => {
+                "Invalid regular expression: numbers out of order in {} quantifier".into()
+            }
+            RegexpUnterminatedGroup => "Invalid regular expression: unterminated group".into(),
+            RegexpUnmatchedParen => "Invalid regular expression: unmatched ')'".into(),
+            RegexpInvalidGroup => "Invalid regular expression: invalid group".into(),
+            RegexpUnterminatedClass => {
+                "Invalid regular expression: unterminated character class".into()
+            }
+            RegexpRangeOutOfOrder => {
+                "Invalid regular expression: range out of order in character class".into()
+            }
+            RegexpInvalidClass => "Invalid regular expression: invalid character class".into(),
+            RegexpInvalidEscape => "Invalid regular expression: invalid escape".into(),
+            RegexpInvalidUnicodeEscape => {
+                "Invalid regular expression: invalid unicode escape".into()
+            }
+            RegexpInvalidPropertyName => {
+                "Invalid regular expression: invalid property name".into()
+            }
+            RegexpInvalidReference => "Invalid regular expression: invalid back reference".into(),
+            RegexpInvalidGroupName => {
+                "Invalid regular expression: invalid capture group name".into()
+            }
+            RegexpDuplicateGroupName => {
+                "Invalid regular expression: duplicate capture group name".into()
+            }
+            RegexpInvalidNamedReference => {
+                "Invalid regular expression: invalid named reference".into()
+            }
             UnterminatedTpl => "Unterminated template".into(),
             IdentAfterNum => "Identifier cannot follow number".into(),
             UnexpectedChar { c } => format!("Unexpected character {:?}", c).into(),
@@ -325,6 +392,9 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DecoratorOnExport => "Using the export keyword between a decorator and a class is not \
                                   allowed. Please use `export @dec class` instead."
                 .into(),
+            UnsupportedParamDecorator => {
+                "Parameter decorators are only supported in constructors and methods".into()
+            }
             TsNonLastRest => "A rest element must be last in a tuple type.".into(),
             TsRequiredAfterOptional => {
                 "A required element cannot follow an optional element.".into()
@@ -368,6 +438,16 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             NullishCoalescingNotEnabled => {
                 "Nullish coalescing operator(??) requires jsc.parser.Coalescing".into()
             }
+            StaticBlocksNotEnabled => "Class static blocks require jsc.parser.staticBlocks".into(),
+            DecoratorOnStaticBlock => "Decorators are not valid on static blocks".into(),
+            ImportAssertionsNotEnabled => {
+                "Import assertions require jsc.parser.importAssertions".into()
+            }
+            InvalidImportAssertion => "Import assertion values must be string literals".into(),
+            InvalidBindCallee => "Binding should be performed on object property".into(),
+            LogicalAssignmentNotEnabled => "Logical assignment operators(&&=, ||=, ??=) require \
+                                            jsc.parser.logicalAssignment"
+                .into(),
 
             TS1056 => "jsc.taraget should be es5 or upper to use getter / setter".into(),
             TS1141 => "literal in an import type should be string literal".into(),
@@ -11,15 +11,16 @@ pub use self::{
 use self::{state::State, util::*};
 use crate::{
     error::{Error, SyntaxError},
+    regexp,
     token::*,
     Context, JscTarget, Session, Syntax,
 };
-use either::Either::{Left, Right};
 use smallvec::{smallvec, SmallVec};
-use std::{char, iter::FusedIterator};
+use std::{cell::RefCell, char, iter::FusedIterator, rc::Rc};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{
     comments::{Comment, Comments},
+    errors::Diagnostic,
     BytePos, Span,
 };
 
@@ -98,6 +99,8 @@ pub struct Lexer<'a, I: Input> {
     session: Session<'a>,
     comments: Option<&'a Comments>,
     leading_comments_buffer: Option<Vec<Comment>>,
+    /// [Some] if errors should be stored instead of being emitted.
+    error_buffer: Option<Rc<RefCell<Vec<Diagnostic>>>>,
     pub(crate) ctx: Context,
     input: I,
     state: State,
@@ -123,6 +126,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 None
             },
             comments,
+            error_buffer: None,
             input,
             state: State::new(syntax),
             ctx: Default::default(),
@@ -160,10 +164,7 @@ impl<'a, I: Input> Lexer<'a, I> {
                 if '0' <= next && next <= '9' {
                     return self
                         .read_number(true)
-                        .map(|v| match v {
-                            Left(v) => Num(v),
-                            Right(v) => BigInt(v),
-                        })
+                        .map(|v| self.num_token(start, v))
                         .map(Some);
                 }
 
@@ -201,6 +202,13 @@ impl<'a, I: Input> Lexer<'a, I> {
                 Some('?') => {
                     self.input.bump();
                     self.input.bump();
+
+                    // '??='
+                    if self.input.cur() == Some('=') {
+                        self.input.bump();
+                        return Ok(Some(AssignOp(NullishAssign)));
+                    }
+
                     return Ok(Some(tok!("??")));
                 }
                 _ => {
@@ -235,29 +243,20 @@ impl<'a, I: Input> Lexer<'a, I> {
                     _ => {
                         return self
                             .read_number(false)
-                            .map(|v| match v {
-                                Left(v) => Num(v),
-                                Right(v) => BigInt(v),
-                            })
+                            .map(|v| self.num_token(start, v))
                             .map(Some)
                     }
                 };
 
                 return self
                     .read_radix_number(radix)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
+                    .map(|v| self.num_token(start, v))
                     .map(Some);
             }
             '1'..='9' => {
                 return self
                     .read_number(false)
-                    .map(|v| match v {
-                        Left(v) => Num(v),
-                        Right(v) => BigInt(v),
-                    })
+                    .map(|v| self.num_token(start, v))
                     .map(Some)
             }
 
@@ -304,9 +303,29 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|'
+                    && self.input.cur() == Some('>')
+                    && self.syntax.pipeline_operator().is_some()
+                {
+                    self.input.bump();
+                    return Ok(Some(tok!("|>")));
+                }
+
                 // '||', '&&'
                 if self.input.cur() == Some(c) {
                     self.input.bump();
+
+                    // '||=', '&&='
+                    if self.input.cur() == Some('=') {
+                        self.input.bump();
+                        return Ok(Some(AssignOp(match token {
+                            BitAnd => AndAssign,
+                            BitOr => OrAssign,
+                            _ => unreachable!(),
+                        })));
+                    }
+
                     return Ok(Some(BinOp(match token {
                         BitAnd => LogicalAnd,
                         BitOr => LogicalOr,
@@ -800,8 +819,11 @@ impl<'a, I: Input> Lexer<'a, I> {
             match c {
                 c if c == quote => {
                     self.bump();
+                    let end = self.cur_pos();
+                    let raw = self.input.slice(start, end).into();
                     return Ok(Token::Str {
                         value: out.into(),
+                        raw,
                         has_escape,
                     });
                 }
@@ -823,6 +845,11 @@ impl<'a, I: Input> Lexer<'a, I> {
     }
 
     /// Expects current char to be '/'
+    ///
+    /// The pattern is parsed only to validate it. The parsed
+    /// [regexp::Pattern] is dropped and the token keeps the source of the
+    /// pattern, so passes which need the structure should call
+    /// [regexp::parse_pattern] themselves.
     fn read_regexp(&mut self) -> LexResult<Token> {
         debug_assert_eq!(self.cur(), Some('/'));
         let start = self.cur_pos();
@@ -854,7 +881,6 @@ impl<'a, I: Input> Lexer<'a, I> {
             self.bump();
             content.push(c);
         }
-        let content_span = Span::new(content_start, self.cur_pos(), Default::default());
 
         // input is terminated without following `/`
         if !self.is('/') {
@@ -874,6 +900,14 @@ impl<'a, I: Input> Lexer<'a, I> {
             .map(|(value, _)| value)
             .unwrap_or(js_word!(""));
 
+        let parsed_flags = match regexp::parse_flags(&flags, flags_start) {
+            Ok(flags) => flags,
+            Err(err) => return self.error_span(err.span, err.error),
+        };
+        if let Err(err) = regexp::parse_pattern(&content, parsed_flags, content_start) {
+            return self.error_span(err.span, err.error);
+        }
+
         Ok(Regex(content.into(), flags))
     }
 
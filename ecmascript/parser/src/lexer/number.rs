@@ -167,6 +167,19 @@ impl<'a, I: Input> Lexer<'a, I> {
         Ok(Either::Left(val))
     }
 
+    /// Creates a numeric token from the result of [read_number] or
+    /// [read_radix_number], attaching the source text read since `start`.
+    pub(super) fn num_token(&mut self, start: BytePos, v: Either<f64, BigIntValue>) -> Token {
+        match v {
+            Either::Left(value) => {
+                let end = self.cur_pos();
+                let raw = self.input.slice(start, end).into();
+                Token::Num { value, raw }
+            }
+            Either::Right(v) => Token::BigInt(v),
+        }
+    }
+
     /// This can read long integers like
     /// "13612536612375123612312312312312312312312".
     fn read_number_no_dot(&mut self, radix: u8) -> LexResult<f64> {
@@ -591,9 +604,21 @@ mod tests {
                 };
                 assert_eq!(vec.len(), 1);
                 let token = vec.into_iter().next().unwrap();
-                assert_eq!(Num(expected), token);
+                assert_eq!(
+                    Num {
+                        value: expected,
+                        raw: (*case).into(),
+                    },
+                    token
+                );
             } else if let Ok(vec) = vec {
-                assert_ne!(vec![Num(expected)], vec)
+                assert_ne!(
+                    vec![Num {
+                        value: expected,
+                        raw: (*case).into(),
+                    }],
+                    vec
+                )
             }
         }
     }
@@ -3,8 +3,8 @@ use crate::{input::Tokens, lexer::util::CharExt, token::*, JscTarget, Syntax};
 use enum_kind::Kind;
 use log::trace;
 use smallvec::{smallvec, SmallVec};
-use std::mem;
-use swc_common::BytePos;
+use std::{cell::RefCell, mem, rc::Rc};
+use swc_common::{errors::Diagnostic, BytePos};
 
 /// State of lexer.
 ///
@@ -84,7 +84,7 @@ impl<'a> From<&'a Token> for TokenType {
             _ => TokenType::Other {
                 before_expr: t.before_expr(),
                 can_have_trailing_comment: match *t {
-                    Token::Num(..)
+                    Token::Num { .. }
                     | Token::Str { .. }
                     | Token::Word(Word::Ident(..))
                     | Token::DollarLBrace
@@ -130,6 +130,10 @@ impl<I: Input> Tokens for Lexer<'_, I> {
     fn set_token_context(&mut self, c: TokenContexts) {
         self.state.context = c;
     }
+
+    fn set_error_buffer(&mut self, buffer: Option<Rc<RefCell<Vec<Diagnostic>>>>) {
+        self.error_buffer = buffer;
+    }
 }
 
 impl<'a, I: Input> Iterator for Lexer<'a, I> {
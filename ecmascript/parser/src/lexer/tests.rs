@@ -68,12 +68,18 @@ impl WithSpan for Token {
 }
 impl WithSpan for usize {
     fn into_token(self) -> Token {
-        Num(self as f64)
+        Num {
+            value: self as f64,
+            raw: self.to_string().into(),
+        }
     }
 }
 impl WithSpan for f64 {
     fn into_token(self) -> Token {
-        Num(self)
+        Num {
+            value: self,
+            raw: self.to_string().into(),
+        }
     }
 }
 impl<'a> WithSpan for &'a str {
@@ -159,7 +165,12 @@ fn test262_lexer_error_0001() {
     assert_eq!(
         lex(Syntax::default(), "123..a(1)"),
         vec![
-            123f64.span(0..4).lb(),
+            Num {
+                value: 123.0,
+                raw: "123.".into(),
+            }
+            .span(0..4)
+            .lb(),
             Dot.span(4..5),
             "a".span(5..6),
             LParen.span(6..7),
@@ -176,6 +187,7 @@ fn test262_lexer_error_0002() {
         vec![
             Token::Str {
                 value: "use strict".into(),
+                raw: r#"'use\x20strict'"#.into(),
                 has_escape: true,
             }
             .span(0..15)
@@ -262,6 +274,7 @@ fn str_escape() {
         lex_tokens(Syntax::default(), r#"'\n'"#),
         vec![Token::Str {
             value: "\n".into(),
+            raw: r#"'\n'"#.into(),
             has_escape: true
         }]
     );
@@ -273,6 +286,7 @@ fn str_escape_2() {
         lex_tokens(Syntax::default(), r#"'\\n'"#),
         vec![Token::Str {
             value: "\\n".into(),
+            raw: r#"'\\n'"#.into(),
             has_escape: true
         }]
     );
@@ -284,6 +298,7 @@ fn str_escape_hex() {
         lex(Syntax::default(), r#"'\x61'"#),
         vec![Token::Str {
             value: "a".into(),
+            raw: r#"'\x61'"#.into(),
             has_escape: true,
         }
         .span(0..6)
@@ -297,6 +312,7 @@ fn str_escape_octal() {
         lex(Syntax::default(), r#"'Hello\012World'"#),
         vec![Token::Str {
             value: "Hello\nWorld".into(),
+            raw: r#"'Hello\012World'"#.into(),
             has_escape: true,
         }
         .span(0..16)
@@ -310,6 +326,7 @@ fn str_escape_unicode_long() {
         lex(Syntax::default(), r#"'\u{00000000034}'"#),
         vec![Token::Str {
             value: "4".into(),
+            raw: r#"'\u{00000000034}'"#.into(),
             has_escape: true,
         }
         .span(0..17)
@@ -400,6 +417,29 @@ fn simple_regex() {
     );
 }
 
+#[test]
+fn invalid_regex() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), "/a/gg"),
+        vec![Token::Error(Error {
+            span: sp(4..5),
+            error: SyntaxError::DuplicateRegexpFlag { c: 'g' },
+        })]
+    );
+
+    assert_eq!(
+        lex_tokens(Syntax::default(), "x = /(?<a>.)(?<a>.)/"),
+        vec![
+            "x".into_token(),
+            Assign,
+            Token::Error(Error {
+                span: sp(12..17),
+                error: SyntaxError::RegexpDuplicateGroupName,
+            }),
+        ]
+    );
+}
+
 #[test]
 fn complex_regex() {
     testing::assert_eq_ignore_span!(
@@ -450,6 +490,22 @@ fn complex_divide() {
     )
 }
 
+#[test]
+fn logical_assign() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), "a &&= b ||= c ??= d"),
+        vec![
+            Word(Word::Ident("a".into())),
+            AssignOp(AndAssign),
+            Word(Word::Ident("b".into())),
+            AssignOp(OrAssign),
+            Word(Word::Ident("c".into())),
+            AssignOp(NullishAssign),
+            Word(Word::Ident("d".into())),
+        ]
+    )
+}
+
 // ---------- Tests from tc39 spec
 
 #[test]
@@ -625,6 +681,7 @@ fn str_lit() {
         lex_tokens(Syntax::default(), "'abcde'"),
         vec![Token::Str {
             value: "abcde".into(),
+            raw: "'abcde'".into(),
             has_escape: false,
         }],
     );
@@ -632,6 +689,7 @@ fn str_lit() {
         lex_tokens(Syntax::default(), "'\\\nabc'"),
         vec![Token::Str {
             value: "abc".into(),
+            raw: "'\\\nabc'".into(),
             has_escape: true,
         }]
     );
@@ -899,6 +957,7 @@ fn issue_299_01() {
             tok!('='),
             Token::Str {
                 value: " ".into(),
+                raw: "'\\ '".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -933,6 +992,7 @@ fn issue_299_02() {
             tok!('='),
             Token::Str {
                 value: "'".into(),
+                raw: "'\\''".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -967,6 +1027,7 @@ fn issue_299_03() {
             tok!('='),
             Token::Str {
                 value: "\\".into(),
+                raw: "'\\\\'".into(),
                 has_escape: true
             },
             Token::JSXTagEnd,
@@ -988,6 +1049,7 @@ fn issue_316() {
         lex_tokens(Default::default(), "'Hi\\r\\n..'"),
         vec![Token::Str {
             value: "Hi\r\n..".into(),
+            raw: "'Hi\\r\\n..'".into(),
             has_escape: true
         }]
     );
@@ -1000,6 +1062,7 @@ fn issue_401() {
         vec![
             Token::Str {
                 value: "17".into(),
+                raw: "'17'".into(),
                 has_escape: false
             },
             tok!("as"),
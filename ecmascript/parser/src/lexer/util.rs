@@ -116,7 +116,13 @@ impl<'a, I: Input> Lexer<'a, I> {
             span,
             error: kind,
         };
-        DiagnosticBuilder::from(err).emit();
+        let mut err = DiagnosticBuilder::from(err);
+        if let Some(ref buffer) = self.error_buffer {
+            buffer.borrow_mut().push((*err).clone());
+            err.cancel();
+        } else {
+            err.emit();
+        }
     }
 
     /// Skip comments or whitespaces.
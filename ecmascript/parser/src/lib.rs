@@ -109,6 +109,7 @@ mod macros;
 mod error;
 pub mod lexer;
 mod parser;
+pub mod regexp;
 pub mod token;
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -119,6 +120,8 @@ pub enum Syntax {
     Es(EsConfig),
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -132,7 +135,8 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         match self {
             Syntax::Es(EsConfig { jsx: true, .. })
-            | Syntax::Typescript(TsConfig { tsx: true, .. }) => true,
+            | Syntax::Typescript(TsConfig { tsx: true, .. })
+            | Syntax::Flow(FlowConfig { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -146,6 +150,10 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 dynamic_import: true,
                 ..
+            })
+            | Syntax::Flow(FlowConfig {
+                dynamic_import: true,
+                ..
             }) => true,
             _ => false,
         }
@@ -158,6 +166,15 @@ impl Syntax {
         }
     }
 
+    pub fn pipeline_operator(self) -> Option<PipelineProposal> {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator, ..
+            }) => pipeline_operator,
+            _ => None,
+        }
+    }
+
     pub fn num_sep(self) -> bool {
         match self {
             Syntax::Es(EsConfig { num_sep: true, .. }) | Syntax::Typescript(..) => true,
@@ -172,6 +189,9 @@ impl Syntax {
             })
             | Syntax::Typescript(TsConfig {
                 decorators: true, ..
+            })
+            | Syntax::Flow(FlowConfig {
+                decorators: true, ..
             }) => true,
             _ => false,
         }
@@ -198,6 +218,7 @@ impl Syntax {
     }
 
     pub fn class_props(self) -> bool {
+        // Flow declares class fields like typescript.
         if self.typescript() {
             return true;
         }
@@ -221,7 +242,27 @@ impl Syntax {
     }
 
     /// Should we pare typescript?
+    ///
+    /// This is also true for flow, as flow annotations are parsed with the
+    /// type grammar of typescript.
     pub fn typescript(self) -> bool {
+        match self {
+            Syntax::Typescript(..) | Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse flow?
+    pub fn flow(self) -> bool {
+        match self {
+            Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse syntax of typescript which is not valid flow, like
+    /// `enum`, `namespace`, `x as T` and `x!`?
+    pub fn typescript_only(self) -> bool {
         match self {
             Syntax::Typescript(..) => true,
             _ => false,
@@ -248,6 +289,54 @@ impl Syntax {
         }
     }
 
+    pub fn logical_assignment(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                logical_assignment: true,
+                ..
+            })
+            | Syntax::Typescript(..) => true,
+
+            _ => false,
+        }
+    }
+
+    pub fn static_blocks(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                static_blocks: true,
+                ..
+            })
+            | Syntax::Typescript(..) => true,
+
+            _ => false,
+        }
+    }
+
+    pub fn import_assertions(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                import_assertions: true,
+                ..
+            })
+            | Syntax::Typescript(..) => true,
+
+            _ => false,
+        }
+    }
+
+    pub fn top_level_await(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                ..
+            })
+            | Syntax::Typescript(..) => true,
+
+            _ => false,
+        }
+    }
+
     pub fn nullish_coalescing(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
@@ -285,6 +374,25 @@ pub struct TsConfig {
     pub dynamic_import: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FlowConfig {
+    /// Parse files without a `@flow` pragma as flow.
+    ///
+    /// If false, such files are parsed as ecmascript.
+    #[serde(default)]
+    pub all: bool,
+
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+
+    #[serde(default)]
+    pub dynamic_import: bool,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
 pub enum JscTarget {
     #[serde(rename = "es3")]
@@ -301,6 +409,8 @@ pub enum JscTarget {
     Es2018,
     #[serde(rename = "es2019")]
     Es2019,
+    #[serde(rename = "es2020")]
+    Es2020,
 }
 
 impl Default for JscTarget {
@@ -337,6 +447,10 @@ pub struct EsConfig {
     #[serde(default)]
     pub fn_bind: bool,
 
+    /// Support pipeline operator, `a |> f`.
+    #[serde(default)]
+    pub pipeline_operator: Option<PipelineProposal>,
+
     /// Enable decorators.
     #[serde(default)]
     pub decorators: bool,
@@ -361,11 +475,37 @@ pub struct EsConfig {
     #[serde(default)]
     pub nullish_coalescing: bool,
 
+    /// `&&=`, `||=` and `??=`
+    #[serde(default)]
+    pub logical_assignment: bool,
+
+    /// `static { ... }` in classes.
+    #[serde(default)]
+    pub static_blocks: bool,
+
+    /// `await` outside of async functions in modules.
+    #[serde(default)]
+    pub top_level_await: bool,
+
+    /// `assert { type: "json" }` in imports and re-exports.
+    #[serde(default)]
+    pub import_assertions: bool,
+
     /// Stage 3.
     #[serde(default)]
     pub import_meta: bool,
 }
 
+/// Variant of the pipeline operator proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PipelineProposal {
+    /// `a |> f` is `f(a)`.
+    Minimal,
+    /// Same as minimal, but also allows `a |> await`.
+    Fsharp,
+}
+
 /// Syntactic context.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Context {
@@ -45,6 +45,9 @@ macro_rules! tok {
     ("::") => {
         crate::token::Token::ColonColon
     };
+    ("|>") => {
+        crate::token::Token::Pipeline
+    };
     ('.') => {
         crate::token::Token::Dot
     };
@@ -122,6 +125,9 @@ macro_rules! tok {
     ("as") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("as")))
     };
+    ("assert") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("assert")))
+    };
     ("await") => {
         crate::token::Token::Word(crate::token::Word::Keyword(crate::token::Keyword::Await))
     };
@@ -344,6 +350,16 @@ macro_rules! tok {
     ("meta") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("meta")))
     };
+    ("type") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("type")))
+    };
+
+    // ----------
+    // Flow
+    // ----------
+    ("opaque") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("opaque")))
+    };
 }
 
 macro_rules! token_including_semi {
@@ -318,6 +318,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         if let Some(static_token) = static_token {
+            if is!('{') {
+                return self.parse_static_block(start, static_token, decorators);
+            }
+
             // Handle static(){}
             if self.is_class_method()? {
                 let key = Either::Right(PropName::Ident(Ident::new(
@@ -326,7 +330,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 )));
                 let is_optional = self.input.syntax().typescript() && eat!('?');
                 return self.make_method(
-                    |p| p.parse_unique_formal_params(),
+                    |p| p.parse_method_params(),
                     MakeMethodArgs {
                         start,
                         accessibility,
@@ -366,6 +370,40 @@ impl<'a, I: Tokens> Parser<'a, I> {
         self.parse_class_member_with_is_static(start, accessibility, static_token, decorators)
     }
 
+    /// `static { ... }`
+    fn parse_static_block(
+        &mut self,
+        start: BytePos,
+        static_token: Span,
+        decorators: Vec<Decorator>,
+    ) -> PResult<'a, ClassMember> {
+        if !self.syntax().static_blocks() {
+            self.emit_err(static_token, SyntaxError::StaticBlocksNotEnabled);
+        }
+        for dec in &decorators {
+            self.emit_err(dec.span, SyntaxError::DecoratorOnStaticBlock);
+        }
+
+        let ctx = Context {
+            in_async: false,
+            in_generator: false,
+            in_function: false,
+            is_break_allowed: false,
+            is_continue_allowed: false,
+            ..self.ctx()
+        };
+        let state = State {
+            labels: vec![],
+            ..Default::default()
+        };
+        let body = self.with_ctx(ctx).with_state(state).parse_block(false)?;
+
+        Ok(ClassMember::StaticBlock(StaticBlock {
+            span: span!(start),
+            body,
+        }))
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn parse_class_member_with_is_static(
         &mut self,
@@ -400,7 +438,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 unexpected!();
             }
             return self.make_method(
-                |p| p.parse_unique_formal_params(),
+                |p| p.parse_method_params(),
                 MakeMethodArgs {
                     start,
                     decorators,
@@ -480,7 +518,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         // TODO: Search deeply for assignment pattern using a Visitor
 
                         let span = match *p {
-                            PatOrTsParamProp::Pat(Pat::Assign(ref p)) => Some(p.span()),
+                            PatOrTsParamProp::Pat(Pat::Assign(ref p))
+                            | PatOrTsParamProp::Param(Param {
+                                pat: Pat::Assign(ref p),
+                                ..
+                            }) => Some(p.span()),
                             PatOrTsParamProp::TsParamProp(TsParamProp {
                                 param: TsParamPropParam::Assign(ref p),
                                 ..
@@ -508,7 +550,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }));
             } else {
                 return self.make_method(
-                    |p| p.parse_formal_params(),
+                    |p| p.parse_method_params(),
                     MakeMethodArgs {
                         start,
                         is_optional,
@@ -556,7 +598,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             // handle async foo(){}
             return self.make_method(
-                |p| p.parse_unique_formal_params(),
+                |p| p.parse_method_params(),
                 MakeMethodArgs {
                     start,
                     is_static,
@@ -597,7 +639,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                                 p.emit_err(key_span, SyntaxError::TS1094);
                             }
 
-                            Ok(params)
+                            Ok((params, vec![]))
                         },
                         MakeMethodArgs {
                             decorators,
@@ -626,7 +668,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                                 }
                             }
 
-                            Ok(params)
+                            Ok((params, vec![]))
                         },
                         MakeMethodArgs {
                             decorators,
@@ -821,6 +863,28 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ) -> PResult<'a, Function>
     where
         F: FnOnce(&mut Self) -> PResult<'a, Vec<Pat>>,
+    {
+        self.parse_method_args_body(
+            decorators,
+            start,
+            |p| Ok((parse_args(p)?, vec![])),
+            is_async,
+            is_generator,
+        )
+    }
+
+    /// Same as `parse_fn_args_body`, but `parse_args` also returns decorators
+    /// of parameters.
+    fn parse_method_args_body<F>(
+        &mut self,
+        decorators: Vec<Decorator>,
+        start: BytePos,
+        parse_args: F,
+        is_async: bool,
+        is_generator: bool,
+    ) -> PResult<'a, Function>
+    where
+        F: FnOnce(&mut Self) -> PResult<'a, (Vec<Pat>, Vec<ParamDecorator>)>,
     {
         let ctx = Context {
             in_async: is_async,
@@ -842,7 +906,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 in_parameters: true,
                 ..p.ctx()
             };
-            let params = p.with_ctx(arg_ctx).parse_with(|mut p| parse_args(&mut p))?;
+            let (params, param_decorators) =
+                p.with_ctx(arg_ctx).parse_with(|mut p| parse_args(&mut p))?;
 
             expect!(')');
 
@@ -875,6 +940,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             Ok(Function {
                 span: span!(start),
                 decorators,
+                param_decorators,
                 type_params,
                 params,
                 body,
@@ -939,14 +1005,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }: MakeMethodArgs,
     ) -> PResult<'a, ClassMember>
     where
-        F: FnOnce(&mut Self) -> PResult<'a, Vec<Pat>>,
+        F: FnOnce(&mut Self) -> PResult<'a, (Vec<Pat>, Vec<ParamDecorator>)>,
     {
         let ctx = Context {
             span_of_fn_name: Some(key.span()),
             ..self.ctx()
         };
         let function = self.with_ctx(ctx).parse_with(|p| {
-            p.parse_fn_args_body(decorators, start, parse_args, is_async, is_generator)
+            p.parse_method_args_body(decorators, start, parse_args, is_async, is_generator)
         })?;
 
         match kind {
@@ -1159,6 +1225,7 @@ struct MakeMethodArgs {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EsConfig;
     use swc_common::DUMMY_SP as span;
 
     fn lhs(s: &'static str) -> Box<Expr> {
@@ -1199,4 +1266,68 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn static_block() {
+        let syntax = Syntax::Es(EsConfig {
+            static_blocks: true,
+            ..Default::default()
+        });
+
+        testing::assert_eq_ignore_span!(
+            test_parser("(class { static { a } })", syntax, |p| {
+                p.parse_expr().map_err(|mut e| {
+                    e.emit();
+                })
+            }),
+            Box::new(Expr::Paren(ParenExpr {
+                span,
+                expr: Box::new(Expr::Class(ClassExpr {
+                    ident: None,
+                    class: Class {
+                        decorators: vec![],
+                        span,
+                        body: vec![ClassMember::StaticBlock(StaticBlock {
+                            span,
+                            body: BlockStmt {
+                                span,
+                                stmts: vec![Stmt::Expr(ExprStmt {
+                                    span,
+                                    expr: expr("a"),
+                                })],
+                            },
+                        })],
+                        super_class: None,
+                        implements: vec![],
+                        is_abstract: false,
+                        super_type_params: None,
+                        type_params: None,
+                    },
+                })),
+            }))
+        );
+    }
+
+    #[test]
+    fn method_param_decorator() {
+        let syntax = Syntax::Typescript(crate::TsConfig {
+            decorators: true,
+            ..Default::default()
+        });
+
+        let m = test_parser("class A { m(a, @Body() @Inject b) {} }", syntax, |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+            })
+        });
+        let f = match m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(ref c))) => match c.class.body[0] {
+                ClassMember::Method(ref m) => &m.function,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let indices: Vec<_> = f.param_decorators.iter().map(|dec| dec.index).collect();
+        assert_eq!(indices, vec![1, 1]);
+    }
 }
@@ -127,6 +127,17 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ) -> PResult<'a, Box<Expr>> {
         match cur!(false) {
             Ok(&Token::AssignOp(op)) => {
+                match op {
+                    AssignOpToken::AndAssign
+                    | AssignOpToken::OrAssign
+                    | AssignOpToken::NullishAssign
+                        if !self.syntax().logical_assignment() =>
+                    {
+                        syntax_error!(cond.span(), SyntaxError::LogicalAssignmentNotEnabled)
+                    }
+                    _ => {}
+                }
+
                 let left = if op == AssignOpToken::Assign {
                     self.reparse_expr_as_pat(PatType::AssignPat, cond)
                         .map(Box::new)
@@ -204,6 +215,21 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return Ok(Box::new(Expr::This(ThisExpr { span: span!(start) })));
         }
 
+        // `::obj.func`
+        if eat!("::") {
+            let callee = self.parse_member_expr()?;
+            match *callee {
+                Expr::Member(..) => {}
+                _ => syntax_error!(callee.span(), SyntaxError::InvalidBindCallee),
+            }
+
+            return Ok(Box::new(Expr::Bind(BindExpr {
+                span: span!(start),
+                object: None,
+                callee,
+            })));
+        }
+
         if is!("import") {
             let import = self.parse_ident_name()?;
             if self.input.syntax().import_meta() && is!('.') {
@@ -261,7 +287,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             Ok(&tok!("null"))
             | Ok(&tok!("true"))
             | Ok(&tok!("false"))
-            | Ok(&Token::Num(..))
+            | Ok(&Token::Num { .. })
             | Ok(&Token::BigInt(..))
             | Ok(Token::Str { .. }) => true,
             _ => false,
@@ -765,11 +791,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         span: span!(start),
                         value: raw,
                         has_escape,
+                        raw: None,
                     },
                     Some(Str {
                         span: span!(start),
                         value: cooked,
                         has_escape,
+                        raw: None,
                     }),
                 ),
                 _ => unreachable!(),
@@ -806,7 +834,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = obj.span().lo();
 
         if self.input.syntax().typescript() {
-            if !self.input.had_line_break_before_cur() && is!('!') {
+            if self.input.syntax().typescript_only()
+                && !self.input.had_line_break_before_cur()
+                && is!('!')
+            {
                 self.input.set_expr_allowed(false);
                 assert_and_bump!('!');
 
@@ -888,7 +919,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let is_optional_chaining =
-            self.input.syntax().typescript() && is!('?') && peeked_is!('.') && eat!('?');
+            self.input.syntax().typescript_only() && is!('?') && peeked_is!('.') && eat!('?');
 
         /// Wrap with optional chaining
         macro_rules! wrap {
@@ -962,6 +993,25 @@ impl<'a, I: Tokens> Parser<'a, I> {
             ));
         }
 
+        // $obj::func
+        if !no_call && is!("::") {
+            let object = match obj {
+                ExprOrSuper::Expr(expr) => expr,
+                ExprOrSuper::Super(..) => unexpected!(),
+            };
+            bump!();
+            let callee = self.parse_member_expr()?;
+
+            return Ok((
+                Box::new(Expr::Bind(BindExpr {
+                    span: span!(start),
+                    object: Some(object),
+                    callee,
+                })),
+                true,
+            ));
+        }
+
         match obj {
             ExprOrSuper::Expr(expr) => {
                 // MemberExpression[?Yield, ?Await] TemplateLiteral[?Yield, ?Await, +Tagged]
@@ -1056,6 +1106,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         // 'CallExpr' rule contains 'MemberExpr (...)',
         // and 'MemberExpr' rule contains 'new MemberExpr (...)'
 
+        if type_args.is_none() && is!("::") {
+            return self.parse_subscripts(ExprOrSuper::Expr(callee), false);
+        }
+
         if is!('(') {
             // This is parsed using production MemberExpression,
             // which is left-recursive.
@@ -1205,6 +1259,34 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 false
             };
 
+            // `(a: T)` of flow is a type cast. It's reparsed as a parameter if this is the head
+            // of an arrow function.
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(':') {
+                let type_ann = self.parse_ts_type_ann(true, cur_pos!())?;
+                arg = ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                        span: span!(start),
+                        expr: arg.expr,
+                        type_ann,
+                    })),
+                };
+
+                if is!('=') {
+                    let pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
+                    assert_and_bump!('=');
+                    let right = self.parse_assignment_expr()?;
+                    items.push(PatOrExprOrSpread::Pat(Pat::Assign(AssignPat {
+                        span: span!(start),
+                        left: Box::new(pat),
+                        right,
+                        type_ann: None,
+                    })));
+                    first = false;
+                    continue;
+                }
+            }
+
             if optional || (self.input.syntax().typescript() && is!(':')) {
                 let start = cur_pos!();
 
@@ -1417,17 +1499,23 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 Lit::Bool(Bool { span, value })
             }
             Token::Str { .. } => match bump!() {
-                Token::Str { value, has_escape } => Lit::Str(Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Lit::Str(Str {
                     span: span!(start),
                     value,
                     has_escape,
+                    raw: Some(raw),
                 }),
                 _ => unreachable!(),
             },
-            Token::Num(..) => match bump!() {
-                Token::Num(value) => Lit::Num(Number {
+            Token::Num { .. } => match bump!() {
+                Token::Num { value, raw } => Lit::Num(Number {
                     span: span!(start),
                     value,
+                    raw: Some(raw),
                 }),
                 _ => unreachable!(),
             },
@@ -1453,6 +1541,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let args = self.parse_args(true)?;
+        if args.len() > 1 && !self.input.syntax().import_assertions() {
+            self.emit_err(args[1].expr.span(), SyntaxError::ImportAssertionsNotEnabled);
+        }
         let import = Box::new(Expr::Call(CallExpr {
             span: span!(start),
             callee: ExprOrSuper::Expr(Box::new(Expr::Ident(import_ident))),
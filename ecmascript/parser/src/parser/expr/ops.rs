@@ -1,6 +1,6 @@
 //! Parser for unary operations and binary operations.
 use super::*;
-use crate::token::Keyword;
+use crate::{token::Keyword, PipelineProposal};
 use log::trace;
 use swc_common::Spanned;
 
@@ -51,7 +51,43 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         return_if_arrow!(left);
-        self.parse_bin_op_recursively(left, 0)
+        let left = self.parse_bin_op_recursively(left, 0)?;
+        self.parse_pipeline(left)
+    }
+
+    /// Parses `|> f` after `left`. The pipeline operator binds looser than
+    /// any other binary operator and is left associative.
+    fn parse_pipeline(&mut self, mut left: Box<Expr>) -> PResult<'a, Box<Expr>> {
+        while is!("|>") {
+            let start = left.span().lo();
+            bump!();
+
+            // `a |> await` awaits `a`
+            if self.input.syntax().pipeline_operator() == Some(PipelineProposal::Fsharp)
+                && self.ctx().in_async
+                && is!("await")
+                && self.input.peek().map(|t| !t.starts_expr()).unwrap_or(true)
+            {
+                bump!();
+                left = Box::new(Expr::Await(AwaitExpr {
+                    span: span!(start),
+                    arg: left,
+                }));
+                continue;
+            }
+
+            let right = {
+                let left_of_right = self.parse_unary_expr()?;
+                self.parse_bin_op_recursively(left_of_right, 0)?
+            };
+            left = Box::new(Expr::Pipeline(PipelineExpr {
+                span: span!(start),
+                left,
+                right,
+            }));
+        }
+
+        Ok(left)
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -68,7 +104,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ) -> PResult<'a, Box<Expr>> {
         const PREC_OF_IN: u8 = 7;
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().typescript_only()
             && PREC_OF_IN > min_prec
             && !self.input.had_line_break_before_cur()
             && is!("as")
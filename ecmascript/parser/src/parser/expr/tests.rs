@@ -309,7 +309,8 @@ fn max_integer() {
         expr("1.7976931348623157e+308"),
         box Expr::Lit(Lit::Num(Number {
             span,
-            value: 1.797_693_134_862_315_7e308
+            value: 1.797_693_134_862_315_7e308,
+            raw: Some("1.7976931348623157e+308".into()),
         }))
     )
 }
@@ -368,7 +369,8 @@ fn issue_328() {
                     expr: box Expr::Lit(Lit::Str(Str {
                         span,
                         value: "test".into(),
-                        has_escape: false
+                        has_escape: false,
+                        raw: Some("'test'".into()),
                     }))
                 }],
                 type_args: Default::default(),
@@ -402,6 +404,7 @@ hehe.";"#,
             span,
             value: "okokhehe.".into(),
             has_escape: true,
+            raw: Some("\"ok\\\nok\\\nhehe.\"".into()),
         }))
     );
 }
@@ -418,6 +421,91 @@ fn issue_380() {
     );
 }
 
+#[test]
+fn pipeline() {
+    fn ident(s: &str) -> Box<Expr> {
+        box Expr::Ident(Ident::new(s.into(), span))
+    }
+
+    assert_eq_ignore_span!(
+        test_parser(
+            "a + b |> f |> g",
+            Syntax::Es(EsConfig {
+                pipeline_operator: Some(crate::PipelineProposal::Minimal),
+                ..Default::default()
+            }),
+            |p| p.parse_expr().map_err(|mut e| {
+                e.emit();
+            }),
+        ),
+        box Expr::Pipeline(PipelineExpr {
+            span,
+            left: box Expr::Pipeline(PipelineExpr {
+                span,
+                left: box Expr::Bin(BinExpr {
+                    span,
+                    op: op!(bin, "+"),
+                    left: ident("a"),
+                    right: ident("b"),
+                }),
+                right: ident("f"),
+            }),
+            right: ident("g"),
+        })
+    );
+}
+
+#[test]
+fn fn_bind() {
+    fn parse(s: &'static str) -> Box<Expr> {
+        test_parser(
+            s,
+            Syntax::Es(EsConfig {
+                fn_bind: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_expr().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        )
+    }
+
+    assert_eq_ignore_span!(
+        parse("a::b"),
+        box Expr::Bind(BindExpr {
+            span,
+            object: Some(box Expr::Ident(Ident::new("a".into(), span))),
+            callee: box Expr::Ident(Ident::new("b".into(), span)),
+        })
+    );
+    assert_eq_ignore_span!(
+        parse("::a.b"),
+        box Expr::Bind(BindExpr {
+            span,
+            object: None,
+            callee: member_expr("a.b"),
+        })
+    );
+}
+
+#[test]
+fn flow_ts_only_expr() {
+    for &src in &["a as b;", "a!;"] {
+        let failed = test_parser(src, Syntax::Flow(Default::default()), |p| {
+            Ok(match p.parse_stmt(true) {
+                Ok(..) => false,
+                Err(mut e) => {
+                    e.cancel();
+                    true
+                }
+            })
+        });
+        assert!(failed, "{} should not be parsed as flow", src);
+    }
+}
+
 #[bench]
 fn bench_new_expr_ts(b: &mut Bencher) {
     bench_parser(
@@ -6,7 +6,7 @@ use crate::{
 };
 use lexer::TokenContexts;
 use std::{cell::RefCell, mem, rc::Rc};
-use swc_common::{BytePos, Span, DUMMY_SP};
+use swc_common::{errors::Diagnostic, BytePos, Span, DUMMY_SP};
 
 pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
     fn set_ctx(&mut self, ctx: Context);
@@ -18,6 +18,11 @@ pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
     fn token_context(&self) -> &lexer::TokenContexts;
     fn token_context_mut(&mut self) -> &mut lexer::TokenContexts;
     fn set_token_context(&mut self, _c: lexer::TokenContexts);
+
+    /// Makes errors found while lexing go to `buffer` instead of the handler.
+    ///
+    /// This is a no-op for already lexed tokens.
+    fn set_error_buffer(&mut self, _buffer: Option<Rc<RefCell<Vec<Diagnostic>>>>) {}
 }
 
 #[derive(Clone)]
@@ -142,6 +147,10 @@ impl<I: Tokens> Tokens for Capturing<I> {
     fn set_token_context(&mut self, c: TokenContexts) {
         self.inner.set_token_context(c)
     }
+
+    fn set_error_buffer(&mut self, buffer: Option<Rc<RefCell<Vec<Diagnostic>>>>) {
+        self.inner.set_error_buffer(buffer)
+    }
 }
 
 /// This struct is responsible for managing current token and peeked token.
@@ -153,6 +162,8 @@ pub(super) struct Buffer<I: Tokens> {
     cur: Option<TokenAndSpan>,
     /// Peeked token
     next: Option<TokenAndSpan>,
+    /// Number of `{` and `${` bumped, minus the number of `}` bumped.
+    brace_depth: usize,
 }
 
 impl<I: Tokens> Parser<'_, I> {
@@ -168,6 +179,7 @@ impl<I: Tokens> Buffer<I> {
             cur: None,
             prev_span: DUMMY_SP,
             next: None,
+            brace_depth: 0,
         }
     }
 
@@ -212,9 +224,20 @@ impl<I: Tokens> Buffer<I> {
         };
         self.prev_span = prev.span;
 
+        match prev.token {
+            tok!('{') | tok!("${") => self.brace_depth += 1,
+            tok!('}') => self.brace_depth = self.brace_depth.saturating_sub(1),
+            _ => {}
+        }
+
         prev.token
     }
 
+    /// Returns the number of braces opened by bumped tokens.
+    pub fn brace_depth(&self) -> usize {
+        self.brace_depth
+    }
+
     pub fn knows_cur(&self) -> bool {
         self.cur.is_some()
     }
@@ -80,6 +80,7 @@ fn escape_in_attr() {
                             span,
                             value: "w < w".into(),
                             has_escape: false,
+                            raw: Some("\"w &lt; w\"".into()),
                         })))
                     })),
                 })],
@@ -107,7 +108,11 @@ fn issue_584() {
                     name: JSXAttrName::Ident(Ident::new("other".into(), span)),
                     value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
                         span,
-                        expr: JSXExpr::Expr(box Expr::Lit(Lit::Num(Number { span, value: 4.0 })))
+                        expr: JSXExpr::Expr(box Expr::Lit(Lit::Num(Number {
+                            span,
+                            value: 4.0,
+                            raw: Some("4".into()),
+                        })))
                     })),
                 })],
                 self_closing: true,
@@ -8,9 +8,18 @@ use crate::{
     token::{Token, Word},
     Context, JscTarget, Session, Syntax,
 };
-use std::ops::{Deref, DerefMut};
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
 use swc_atoms::JsWord;
-use swc_common::{comments::Comments, errors::DiagnosticBuilder, input::Input, BytePos, Span};
+use swc_common::{
+    comments::Comments,
+    errors::{Diagnostic, DiagnosticBuilder},
+    input::Input,
+    BytePos, Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_parser_macros::parser;
 #[cfg(test)]
@@ -39,6 +48,9 @@ pub type PResult<'a, T> = Result<T, DiagnosticBuilder<'a>>;
 pub struct Parser<'a, I: Tokens> {
     /// [false] while backtracking
     emit_err: bool,
+    /// [Some] while parsing with [Parser::parse_module_recovering]. Errors are
+    /// collected instead of being emitted.
+    recovered: Option<Rc<RefCell<Vec<Diagnostic>>>>,
     session: Session<'a>,
     state: State,
     input: Buffer<I>,
@@ -71,6 +83,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     pub fn new_from(session: Session<'a>, input: I) -> Self {
         Parser {
             emit_err: true,
+            recovered: None,
             session,
             input: Buffer::new(input),
             state: Default::default(),
@@ -126,6 +139,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let ctx = Context {
             module: true,
             strict: true,
+            in_async: self.syntax().top_level_await(),
             ..self.ctx()
         };
         // Module code is always in strict mode
@@ -141,6 +155,120 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
+    /// Parses a module without stopping at the first error.
+    ///
+    /// A statement which cannot be parsed is replaced with an
+    /// [Expr::Invalid] and the parser skips to the next statement boundary.
+    /// Errors are returned instead of being emitted to the handler.
+    pub fn parse_module_recovering(&mut self) -> (Module, Vec<Diagnostic>) {
+        let ctx = Context {
+            module: true,
+            strict: true,
+            in_async: self.syntax().top_level_await(),
+            ..self.ctx()
+        };
+        self.set_ctx(ctx);
+
+        let recovered = Rc::new(RefCell::new(vec![]));
+        self.recovered = Some(recovered.clone());
+        self.input().set_error_buffer(Some(recovered.clone()));
+
+        let start = cur_pos!();
+        let shebang = match self.parse_shebang() {
+            Ok(shebang) => shebang,
+            Err(err) => {
+                self.recover(err);
+                None
+            }
+        };
+
+        let body = match self.parse_block_body(true, true, None) {
+            Ok(body) => body,
+            Err(err) => {
+                self.recover(err);
+                vec![]
+            }
+        };
+
+        self.recovered = None;
+        self.input().set_error_buffer(None);
+        let errors = recovered.replace(vec![]);
+
+        (
+            Module {
+                span: span!(start),
+                body,
+                shebang,
+            },
+            errors,
+        )
+    }
+
+    /// Stores a hard error while recovering.
+    fn recover(&self, mut err: DiagnosticBuilder<'a>) {
+        if let Some(ref recovered) = self.recovered {
+            recovered.borrow_mut().push((*err).clone());
+            err.cancel();
+        } else {
+            err.emit();
+        }
+    }
+
+    /// Skips tokens until the end of a statement which started when
+    /// `brace_depth` braces were open.
+    ///
+    /// This stops after a `;` or a `}` closing a block of the statement, before
+    /// the `}` closing the enclosing block, or before a keyword starting a
+    /// statement on a new line. Unless the enclosing block ends, at least one
+    /// token is consumed.
+    fn skip_to_stmt_boundary(&mut self, brace_depth: usize) {
+        let mut bumped = false;
+
+        loop {
+            if self.input.cur().is_none() {
+                return;
+            }
+
+            let depth = self.input.brace_depth().saturating_sub(brace_depth);
+            if depth == 0 && brace_depth != 0 && self.input.is(&tok!('}')) {
+                return;
+            }
+
+            if depth == 0
+                && bumped
+                && self.input.had_line_break_before_cur()
+                && is_one_of!(
+                    "var", "let", "const", "function", "class", "if", "for", "while", "do",
+                    "return", "throw", "try", "switch", "import", "export"
+                )
+            {
+                return;
+            }
+
+            let is_semi = self.input.is(&tok!(';'));
+            // A `}` at depth 0 is a stray one in the top level.
+            let closes_block = self.input.is(&tok!('}')) && depth <= 1;
+
+            if let Token::Error(e) = self.input.bump() {
+                self.recover(DiagnosticBuilder::from(ErrorToDiag {
+                    handler: self.session.handler,
+                    span: e.span,
+                    error: e.error,
+                }));
+            }
+            bumped = true;
+
+            if closes_block {
+                // `;` of e.g. `var a = { b: 1 + };`
+                self.input.eat(&tok!(';'));
+                return;
+            }
+            if is_semi && depth == 0 {
+                return;
+            }
+        }
+    }
+
     fn parse_shebang(&mut self) -> PResult<'a, Option<JsWord>> {
         match cur!(false) {
             Ok(&Token::Shebang(..)) => match bump!() {
@@ -160,12 +288,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return;
         }
 
-        DiagnosticBuilder::from(ErrorToDiag {
+        self.recover(DiagnosticBuilder::from(ErrorToDiag {
             handler: self.session.handler,
             span,
             error,
-        })
-        .emit();
+        }));
     }
 }
 
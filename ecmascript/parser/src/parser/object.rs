@@ -47,17 +47,23 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             let v = match *cur!(true)? {
                 Token::Str { .. } => match bump!() {
-                    Token::Str { value, has_escape } => PropName::Str(Str {
+                    Token::Str {
+                        value,
+                        raw,
+                        has_escape,
+                    } => PropName::Str(Str {
                         span: span!(start),
                         value,
                         has_escape,
+                        raw: Some(raw),
                     }),
                     _ => unreachable!(),
                 },
-                Token::Num(_) => match bump!() {
-                    Token::Num(value) => PropName::Num(Number {
+                Token::Num { .. } => match bump!() {
+                    Token::Num { value, raw } => PropName::Num(Number {
                         span: span!(start),
                         value,
+                        raw: Some(raw),
                     }),
                     _ => unreachable!(),
                 },
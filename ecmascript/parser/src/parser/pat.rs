@@ -288,7 +288,16 @@ impl<'a, I: Tokens> Parser<'a, I> {
             (None, false)
         };
         if accessibility == None && !readonly {
-            self.parse_formal_param().map(PatOrTsParamProp::from)
+            let pat = self.parse_formal_param()?;
+            if decorators.is_empty() {
+                Ok(PatOrTsParamProp::Pat(pat))
+            } else {
+                Ok(PatOrTsParamProp::Param(Param {
+                    span: span!(start),
+                    decorators,
+                    pat,
+                }))
+            }
         } else {
             let param = match self.parse_formal_param()? {
                 Pat::Ident(i) => TsParamPropParam::Ident(i),
@@ -306,8 +315,20 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     pub(super) fn parse_formal_params(&mut self) -> PResult<'a, Vec<Pat>> {
+        let (params, decorators) = self.parse_method_params()?;
+        for dec in decorators {
+            self.emit_err(dec.span, SyntaxError::UnsupportedParamDecorator);
+        }
+
+        Ok(params)
+    }
+
+    /// Parses parameters of a method, which can have decorators like
+    /// `m(@Body() b) {}`.
+    pub(super) fn parse_method_params(&mut self) -> PResult<'a, (Vec<Pat>, Vec<ParamDecorator>)> {
         let mut first = true;
         let mut params = vec![];
+        let mut param_decorators = vec![];
         let mut dot3_token = Span::default();
 
         while !eof!() && !is!(')') {
@@ -334,6 +355,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 self.emit_err(dot3_token, SyntaxError::TS1014);
             }
 
+            let index = params.len();
+            param_decorators.extend(self.parse_decorators(false)?.into_iter().map(|decorator| {
+                ParamDecorator {
+                    span: decorator.span,
+                    index,
+                    decorator,
+                }
+            }));
+
             if eat!("...") {
                 dot3_token = span!(start);
 
@@ -381,7 +411,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             params.push(self.parse_formal_param()?);
         }
 
-        Ok(params)
+        Ok((params, param_decorators))
     }
 
     pub(super) fn parse_unique_formal_params(&mut self) -> PResult<'a, Vec<Pat>> {
@@ -529,6 +559,36 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     type_ann: None,
                 }))
             }
+            // `(a: T) => {}` of flow is parsed as a type cast at first.
+            Expr::TsTypeCast(TsTypeCastExpr {
+                span,
+                expr,
+                type_ann,
+            }) => {
+                let mut pat = self.reparse_expr_as_pat(pat_ty, expr)?;
+                match pat {
+                    Pat::Ident(Ident {
+                        type_ann: ref mut pat_type_ann,
+                        span: ref mut pat_span,
+                        ..
+                    })
+                    | Pat::Array(ArrayPat {
+                        type_ann: ref mut pat_type_ann,
+                        span: ref mut pat_span,
+                        ..
+                    })
+                    | Pat::Object(ObjectPat {
+                        type_ann: ref mut pat_type_ann,
+                        span: ref mut pat_span,
+                        ..
+                    }) => {
+                        *pat_type_ann = Some(type_ann);
+                        *pat_span = span;
+                    }
+                    _ => syntax_error!(span, SyntaxError::InvalidPat),
+                }
+                Ok(pat)
+            }
             Expr::Object(ObjectLit { span, props }) => {
                 // {}
                 Ok(Pat::Object(ObjectPat {
@@ -26,7 +26,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let c = cur!(false).ok();
             c != end
         } {
-            let stmt = self.parse_stmt_like(true, top_level)?;
+            let stmt = self.parse_stmt_like_recovering(top_level)?;
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -58,6 +58,41 @@ impl<'a, I: Tokens> Parser<'a, I> {
         Ok(stmts)
     }
 
+    /// Parses a statement, declaration or module item in a block.
+    ///
+    /// While recovering, a statement which cannot be parsed is replaced with
+    /// an [Expr::Invalid] and the parser skips to the next statement boundary.
+    fn parse_stmt_like_recovering<Type>(&mut self, top_level: bool) -> PResult<'a, Type>
+    where
+        Self: StmtLikeParser<'a, Type>,
+        Type: IsDirective + From<Stmt>,
+    {
+        if self.recovered.is_none() || !self.emit_err {
+            return self.parse_stmt_like(true, top_level);
+        }
+
+        let start = cur_pos!();
+        let (ctx, state) = (self.ctx(), self.state.clone());
+        let brace_depth = self.input.brace_depth();
+
+        match self.parse_stmt_like(true, top_level) {
+            Ok(stmt) => Ok(stmt),
+            Err(err) => {
+                self.recover(err);
+                self.set_ctx(ctx);
+                self.state = state;
+                self.skip_to_stmt_boundary(brace_depth);
+
+                let span = span!(start);
+                Ok(Stmt::Expr(ExprStmt {
+                    span,
+                    expr: Box::new(Expr::Invalid(Invalid { span })),
+                })
+                .into())
+            }
+        }
+    }
+
     pub fn parse_stmt(&mut self, top_level: bool) -> PResult<'a, Stmt> {
         self.parse_stmt_like(false, top_level)
     }
@@ -92,7 +127,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         top_level: bool,
         decorators: Vec<Decorator>,
     ) -> PResult<'a, Stmt> {
-        if self.input.syntax().typescript() && is!("const") && peeked_is!("enum") {
+        if self.input.syntax().typescript_only() && is!("const") && peeked_is!("enum") {
             assert_and_bump!("const");
             assert_and_bump!("enum");
             return self
@@ -1108,7 +1143,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, Stmt> for Parser<'a, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::EsConfig;
+    use crate::{EsConfig, FlowConfig};
     use swc_common::DUMMY_SP as span;
 
     fn stmt(s: &'static str) -> Stmt {
@@ -1634,4 +1669,165 @@ export default function waitUntil(callback, options = {}) {
             },
         );
     }
+
+    #[test]
+    fn top_level_await() {
+        test_parser(
+            "const a = await fetch();
+for await (const b of c) {}",
+            Syntax::Es(EsConfig {
+                top_level_await: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn flow() {
+        test_parser(
+            "import type { A } from './a';
+import typeof B from './b';
+opaque type C: A = string;
+type D = {| a: ?C, b: Array<B> |};
+function foo<T: D>(a: T, b?: ?string): T {
+    return ((a: any): T);
+}
+const bar = (a: number = 1): number => a;",
+            Syntax::Flow(FlowConfig {
+                all: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn flow_object_type() {
+        let module = test_parser(
+            "type A = { +a: string, -b: number, ...B };
+type C = {| ...A, c: boolean |};",
+            Syntax::Flow(FlowConfig {
+                all: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        );
+
+        for (item, len) in module.body.iter().zip(&[2, 1]) {
+            match *item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(TsTypeAliasDecl {
+                    ref type_ann,
+                    ..
+                }))) => match **type_ann {
+                    TsType::TsUnionOrIntersectionType(
+                        TsUnionOrIntersectionType::TsIntersectionType(ref ty),
+                    ) => {
+                        assert_eq!(ty.types.len(), 2);
+                        match *ty.types[0] {
+                            TsType::TsTypeLit(ref lit) => assert_eq!(lit.members.len(), *len),
+                            ref ty => panic!("expected a type literal, got {:?}", ty),
+                        }
+                    }
+                    ref ty => panic!("expected an intersection type, got {:?}", ty),
+                },
+                ref item => panic!("expected a type alias, got {:?}", item),
+            }
+        }
+    }
+
+    #[test]
+    fn recover_module() {
+        let (module, errors) = test_parser(
+            "let a = ;
+foo();
+const = 1;
+bar();",
+            Syntax::default(),
+            |p| Ok(p.parse_module_recovering()),
+        );
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(module.body.len(), 4);
+        for &i in &[0, 2] {
+            match module.body[i] {
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { ref expr, .. })) => match **expr {
+                    Expr::Invalid(..) => {}
+                    _ => panic!("expected an invalid expression, got {:?}", expr),
+                },
+                ref item => panic!("expected an invalid expression, got {:?}", item),
+            }
+        }
+        assert_eq_ignore_span!(module.body[1].clone(), module_item("foo();"));
+        assert_eq_ignore_span!(module.body[3].clone(), module_item("bar();"));
+    }
+
+    #[test]
+    fn recover_nested() {
+        let (module, errors) = test_parser(
+            "function f() {
+    let a = ;
+    return 1;
+}
+let b = { c: 1 +, d: 2 };
+foo();",
+            Syntax::default(),
+            |p| Ok(p.parse_module_recovering()),
+        );
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(module.body.len(), 3);
+        match module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ref function, .. }))) => {
+                let stmts = &function.body.as_ref().unwrap().stmts;
+                assert_eq!(stmts.len(), 2);
+                match stmts[0] {
+                    Stmt::Expr(ExprStmt { ref expr, .. }) => match **expr {
+                        Expr::Invalid(..) => {}
+                        _ => panic!("expected an invalid expression, got {:?}", expr),
+                    },
+                    ref stmt => panic!("expected an invalid expression, got {:?}", stmt),
+                }
+                assert_eq_ignore_span!(
+                    stmts[1].clone(),
+                    Stmt::Return(ReturnStmt {
+                        span,
+                        arg: Some(expr("1")),
+                    })
+                );
+            }
+            ref item => panic!("expected a function, got {:?}", item),
+        }
+        match module.body[1] {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { ref expr, .. })) => match **expr {
+                Expr::Invalid(..) => {}
+                _ => panic!("expected an invalid expression, got {:?}", expr),
+            },
+            ref item => panic!("expected an invalid expression, got {:?}", item),
+        }
+        assert_eq_ignore_span!(module.body[2].clone(), module_item("foo();"));
+    }
+
+    #[test]
+    fn recover_module_lexer_error() {
+        let (module, errors) = test_parser("foo(010);", Syntax::default(), |p| {
+            Ok(p.parse_module_recovering())
+        });
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message(), "Legacy octal escape is not permitted in strict mode");
+        assert_eq!(module.body.len(), 1);
+    }
 }
@@ -39,22 +39,39 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 .map(ModuleItem::from);
         }
 
+        // `import type { A } from 'mod'` and `import typeof A from 'mod'` of flow.
+        //
+        // `import type from 'mod'` imports a default export named `type`.
+        let type_only = self.input.syntax().flow()
+            && (is!("typeof") || (is!("type") && !peeked_is!("from") && !peeked_is!(',')));
+        if type_only {
+            bump!();
+        }
+
         // Handle import 'mod.js'
         let str_start = cur_pos!();
         if let Ok(&Token::Str { .. }) = cur!(false) {
             let src = match bump!() {
-                Token::Str { value, has_escape } => Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Str {
                     span: span!(str_start),
                     value,
                     has_escape,
+                    raw: Some(raw),
                 },
                 _ => unreachable!(),
             };
+            let asserts = self.parse_import_asserts()?;
             expect!(';');
             return Ok(ModuleDecl::Import(ImportDecl {
                 span: span!(start),
                 src,
                 specifiers: vec![],
+                asserts,
+                type_only,
             }))
             .map(ModuleItem::from);
         }
@@ -97,12 +114,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        let src = self.parse_from_clause_and_semi()?;
+        let (src, asserts) = self.parse_from_clause_and_semi()?;
 
         Ok(ModuleDecl::Import(ImportDecl {
             span: span!(start),
             specifiers,
             src,
+            asserts,
+            type_only,
         }))
         .map(ModuleItem::from)
     }
@@ -110,6 +129,17 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// Parse `foo`, `foo2 as bar` in `import { foo, foo2 as bar }`
     fn parse_import_specifier(&mut self) -> PResult<'a, ImportSpecifier> {
         let start = cur_pos!();
+
+        // `import { type A, typeof B } from 'mod'` of flow.
+        let type_only = self.input.syntax().flow()
+            && (is!("type") || is!("typeof"))
+            && !peeked_is!("as")
+            && !peeked_is!(',')
+            && !peeked_is!('}');
+        if type_only {
+            bump!();
+        }
+
         match cur!(false) {
             Ok(&Word(..)) => {
                 let orig_name = self.parse_ident_name()?;
@@ -120,6 +150,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         span: Span::new(start, local.span.hi(), Default::default()),
                         local,
                         imported: Some(orig_name),
+                        type_only,
                     }));
                 }
 
@@ -136,6 +167,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     span: span!(start),
                     local,
                     imported: None,
+                    type_only,
                 }))
             }
             _ => unexpected!(),
@@ -228,10 +260,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if eat!('*') {
             has_star = true;
             if is!("from") {
-                let src = self.parse_from_clause_and_semi()?;
+                let (src, asserts) = self.parse_from_clause_and_semi()?;
                 return Ok(ModuleDecl::ExportAll(ExportAll {
                     span: span!(start),
                     src,
+                    asserts,
                 }));
             }
             if eat!("as") {
@@ -320,7 +353,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             self.parse_async_fn_decl(decorators)?
         } else if is!("function") {
             self.parse_fn_decl(decorators)?
-        } else if self.input.syntax().typescript() && is!("const") && peeked_is!("enum") {
+        } else if self.input.syntax().typescript_only() && is!("const") && peeked_is!("enum") {
             let start = cur_pos!();
             assert_and_bump!("const");
             let _ = cur!(true);
@@ -351,11 +384,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if is!("from") {
                 if let Some(s) = export_ns {
-                    let src = self.parse_from_clause_and_semi()?;
+                    let (src, asserts) = self.parse_from_clause_and_semi()?;
                     return Ok(ModuleDecl::ExportNamed(NamedExport {
                         span: Span::new(start, src.span.hi(), Default::default()),
                         specifiers: vec![s],
                         src: Some(src),
+                        asserts,
                     }));
                 }
             }
@@ -373,23 +407,25 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if is!("from") {
                 if let Some(default) = default {
-                    let src = self.parse_from_clause_and_semi()?;
+                    let (src, asserts) = self.parse_from_clause_and_semi()?;
                     return Ok(ModuleDecl::ExportNamed(NamedExport {
                         span: Span::new(start, src.span.hi(), Default::default()),
                         specifiers: vec![ExportSpecifier::Default(DefaultExportSpecifier {
                             exported: default,
                         })],
                         src: Some(src),
+                        asserts,
                     }));
                 }
             }
 
             if has_star && export_ns.is_none() {
                 // improve error message for `export * from foo`
-                let src = self.parse_from_clause_and_semi()?;
+                let (src, asserts) = self.parse_from_clause_and_semi()?;
                 return Ok(ModuleDecl::ExportAll(ExportAll {
                     span: Span::new(start, src.span.hi(), Default::default()),
                     src,
+                    asserts,
                 }));
             }
 
@@ -424,19 +460,21 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
             expect!('}');
 
-            let src = if is!("from") {
-                Some(self.parse_from_clause_and_semi()?)
+            let (src, asserts) = if is!("from") {
+                let (src, asserts) = self.parse_from_clause_and_semi()?;
+                (Some(src), asserts)
             } else {
                 eat!(';');
                 if has_default || has_ns {
                     syntax_error!(span!(start), SyntaxError::ExportDefaultWithOutFrom);
                 }
-                None
+                (None, None)
             };
             return Ok(ModuleDecl::ExportNamed(NamedExport {
                 span: span!(start),
                 specifiers,
                 src,
+                asserts,
             }));
         };
 
@@ -464,23 +502,74 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
-    fn parse_from_clause_and_semi(&mut self) -> PResult<'a, Str> {
+    /// Parses `from 'mod'`, followed by the optional assert clause.
+    fn parse_from_clause_and_semi(&mut self) -> PResult<'a, (Str, Option<ObjectLit>)> {
         expect!("from");
 
         let str_start = cur_pos!();
         let src = match *cur!(true)? {
             Token::Str { .. } => match bump!() {
-                Token::Str { value, has_escape } => Str {
+                Token::Str {
+                    value,
+                    raw,
+                    has_escape,
+                } => Str {
                     value,
                     has_escape,
+                    raw: Some(raw),
                     span: span!(str_start),
                 },
                 _ => unreachable!(),
             },
             _ => unexpected!(),
         };
+        let asserts = self.parse_import_asserts()?;
         expect!(';');
-        Ok(src)
+        Ok((src, asserts))
+    }
+
+    /// Parses `assert { type: "json" }`.
+    fn parse_import_asserts(&mut self) -> PResult<'a, Option<ObjectLit>> {
+        if !self.input.syntax().import_assertions()
+            || !is!("assert")
+            || self.input.had_line_break_before_cur()
+        {
+            return Ok(None);
+        }
+        assert_and_bump!("assert");
+
+        if !is!('{') {
+            unexpected!()
+        }
+        let obj = match *self.parse_object::<Box<Expr>>()? {
+            Expr::Object(obj) => obj,
+            _ => unreachable!(),
+        };
+
+        for prop in &obj.props {
+            let is_valid = match *prop {
+                PropOrSpread::Prop(ref prop) => match **prop {
+                    Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(..),
+                        ref value,
+                    })
+                    | Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(..),
+                        ref value,
+                    }) => match **value {
+                        Expr::Lit(Lit::Str(..)) => true,
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                _ => false,
+            };
+            if !is_valid {
+                self.emit_err(prop.span(), SyntaxError::InvalidImportAssertion);
+            }
+        }
+
+        Ok(Some(obj))
     }
 }
 
@@ -523,6 +612,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<'a, I> {
 #[cfg(test)]
 mod tests {
     use crate::{EsConfig, Syntax};
+    use swc_ecma_ast::*;
 
     #[test]
     fn test_legacy_decorator() {
@@ -541,4 +631,35 @@ export default class Foo {
             |p| p.parse_module().map_err(|mut e| e.emit()),
         );
     }
+
+    #[test]
+    fn import_assertions() {
+        let m = crate::test_parser(
+            "import data from './data.json' assert { type: 'json' };
+import './style.css' assert { type: 'css' };
+export { a } from './a.json' assert { type: 'json' };
+export * from './b.json' assert { 'type': 'json' };
+import('./c.json', { assert: { type: 'json' } });",
+            Syntax::Es(EsConfig {
+                dynamic_import: true,
+                import_assertions: true,
+                ..Default::default()
+            }),
+            |p| p.parse_module().map_err(|mut e| e.emit()),
+        );
+
+        let count = m
+            .body
+            .iter()
+            .filter(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { asserts, .. }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport { asserts, .. }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { asserts, .. })) => {
+                    asserts.is_some()
+                }
+                _ => false,
+            })
+            .count();
+        assert_eq!(count, 4);
+    }
 }
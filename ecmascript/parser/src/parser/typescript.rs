@@ -60,7 +60,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         debug_assert!(self.input.syntax().typescript());
 
         Ok(match kind {
-            ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!('}'),
+            ParsingContext::EnumMembers => is!('}'),
+            // `|}` closes an exact object type of flow.
+            ParsingContext::TypeMembers => {
+                is!('}') || (self.input.syntax().flow() && is!('|') && peeked_is!('}'))
+            }
             ParsingContext::HeritageClauseElement { .. } => {
                 is!('{') || is!("implements") || is!("extends")
             }
@@ -308,6 +312,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     span: lit.span(),
                     value: "".into(),
                     has_escape: false,
+                    raw: None,
                 }
             }
         };
@@ -362,8 +367,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let start = cur_pos!();
 
+        // Variance of flow is not preserved.
+        if self.input.syntax().flow() && (is!('+') || is!('-')) {
+            bump!();
+        }
+
         let name = self.parse_ident_name()?;
-        let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
+        // `<T: Bound>` of flow
+        let constraint = if self.input.syntax().flow() && is!(':') {
+            self.eat_then_parse_ts_type(&tok!(':'))?
+        } else {
+            self.eat_then_parse_ts_type(&tok!("extends"))?
+        };
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
 
         Ok(TsTypeParam {
@@ -590,7 +605,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 Lit::Str(s) => TsEnumMemberId::Str(s),
                 _ => unreachable!(),
             })?,
-            Token::Num(v) => {
+            Token::Num { value, .. } => {
                 bump!();
                 let span = span!(start);
                 // Recover from error
@@ -598,8 +613,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
                 TsEnumMemberId::Str(Str {
                     span,
-                    value: v.to_string().into(),
+                    value: value.to_string().into(),
                     has_escape: false,
+                    raw: None,
                 })
             }
             Token::LBracket => {
@@ -953,6 +969,38 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
+    /// `opaque type A: Super = B;` of flow. The super type is not preserved.
+    ///
+    /// `declare opaque type A;` is parsed as an alias of `unknown`.
+    fn parse_flow_opaque_type_alias_decl(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let super_type = self.eat_then_parse_ts_type(&tok!(':'))?;
+        let type_ann = match self.eat_then_parse_ts_type(&tok!('='))? {
+            Some(type_ann) => type_ann,
+            None if self.ctx().in_declare => super_type.unwrap_or_else(|| {
+                Box::new(TsType::TsKeywordType(TsKeywordType {
+                    span: id.span,
+                    kind: TsKeywordTypeKind::TsUnknownKeyword,
+                }))
+            }),
+            None => unexpected!(),
+        };
+        expect!(';');
+        Ok(TsTypeAliasDecl {
+            declare: self.ctx().in_declare,
+            span: span!(start),
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
     /// `tsParseImportEqualsDeclaration`
     pub(super) fn parse_ts_import_equals_decl(
         &mut self,
@@ -1087,6 +1135,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     fn parse_ts_type_member_semicolon(&mut self) -> PResult<'a, ()> {
         debug_assert!(self.input.syntax().typescript());
 
+        // `|}` closes an exact object type of flow.
+        if self.input.syntax().flow() && is!('|') && peeked_is!('}') {
+            return Ok(());
+        }
+
         if !eat!(',') {
             expect!(';');
         }
@@ -1211,7 +1264,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             self.with_ctx(ctx).parse_with(|p| {
                 // We check if it's valid for it to be a private name when we push it.
                 let key = match *cur!(true)? {
-                    Token::Num(..) | Token::Str { .. } => p.parse_new_expr(),
+                    Token::Num { .. } | Token::Str { .. } => p.parse_new_expr(),
                     _ => p.parse_maybe_private_name().map(|e| match e {
                         Either::Left(_) => unreachable!(
                             "private name inside parse_ts_property_or_method_signature"
@@ -1292,6 +1345,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
         // Instead of fullStart, we create a node here.
         let start = cur_pos!();
+        // Variance of flow is not preserved.
+        if self.input.syntax().flow() && (is!('+') || is!('-')) {
+            bump!();
+        }
         let readonly = self.parse_ts_modifier(&["readonly"])?.is_some();
 
         let idx = self.try_parse_ts_index_signature(start, readonly)?;
@@ -1316,26 +1373,62 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseTypeLiteral`
-    fn parse_ts_type_lit(&mut self) -> PResult<'a, TsTypeLit> {
+    ///
+    /// `{ a: T, ...B }` of flow is parsed as `{ a: T } & B`.
+    fn parse_ts_type_lit(&mut self) -> PResult<'a, TsType> {
         debug_assert!(self.input.syntax().typescript());
 
         let start = cur_pos!();
-        let members = self.parse_ts_object_type_members()?;
-        Ok(TsTypeLit {
+        let mut spreads = vec![];
+        let members = self.parse_ts_object_type_members_with_spreads(Some(&mut spreads))?;
+        let lit = TsTypeLit {
             span: span!(start),
             members,
-        })
+        };
+        if spreads.is_empty() {
+            return Ok(lit.into());
+        }
+
+        let mut types = vec![Box::new(TsType::from(lit))];
+        types.extend(spreads);
+        Ok(TsIntersectionType {
+            span: span!(start),
+            types,
+        }
+        .into())
     }
 
     /// `tsParseObjectTypeMembers`
     fn parse_ts_object_type_members(&mut self) -> PResult<'a, Vec<TsTypeElement>> {
+        self.parse_ts_object_type_members_with_spreads(None)
+    }
+
+    /// Spreads of flow are collected into `spreads` if it's [Some].
+    fn parse_ts_object_type_members_with_spreads(
+        &mut self,
+        mut spreads: Option<&mut Vec<Box<TsType>>>,
+    ) -> PResult<'a, Vec<TsTypeElement>> {
         debug_assert!(self.input.syntax().typescript());
 
         expect!('{');
-        let members =
-            self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?;
+        // `{| a: T |}` of flow
+        let exact = self.input.syntax().flow() && eat!('|');
+        let members = self.parse_ts_list(ParsingContext::TypeMembers, |p| {
+            if let Some(ref mut spreads) = spreads {
+                if p.input.syntax().flow() && p.input.eat(&tok!("...")) {
+                    spreads.push(p.parse_ts_type()?);
+                    p.parse_ts_type_member_semicolon()?;
+                    return Ok(None);
+                }
+            }
+
+            p.parse_ts_type_member().map(Some)
+        })?;
+        if exact {
+            expect!('|');
+        }
         expect!('}');
-        Ok(members)
+        Ok(members.into_iter().filter_map(|member| member).collect())
     }
 
     /// `tsIsStartOfMappedType`
@@ -1691,7 +1784,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 let start = cur_pos!();
                 bump!();
                 if match *cur!(true)? {
-                    Token::Num(..) => false,
+                    Token::Num { .. } => false,
                     _ => true,
                 } {
                     unexpected!()
@@ -1701,6 +1794,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     Lit::Num(num) => TsLit::Number(Number {
                         span: num.span,
                         value: -num.value,
+                        raw: None,
                     }),
                     _ => unreachable!(),
                 };
@@ -1735,7 +1829,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 return if self.ts_look_ahead(|p| p.is_ts_start_of_mapped_type())? {
                     self.parse_ts_mapped_type().map(TsType::from).map(Box::new)
                 } else {
-                    self.parse_ts_type_lit().map(TsType::from).map(Box::new)
+                    self.parse_ts_type_lit().map(Box::new)
                 };
             }
             tok!('[') => {
@@ -1824,6 +1918,26 @@ impl<'a, I: Tokens> Parser<'a, I> {
     fn parse_ts_type_operator_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
         debug_assert!(self.input.syntax().typescript());
 
+        // `?T` of flow
+        if self.input.syntax().flow() && is!('?') {
+            let start = cur_pos!();
+            bump!();
+            let ty = self.parse_ts_type_operator_or_higher()?;
+            let span = span!(start);
+            let keyword = |kind| Box::new(TsType::TsKeywordType(TsKeywordType { span, kind }));
+            return Ok(Box::new(
+                TsUnionType {
+                    span,
+                    types: vec![
+                        ty,
+                        keyword(TsKeywordTypeKind::TsNullKeyword),
+                        keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+                    ],
+                }
+                .into(),
+            ));
+        }
+
         let operator = if is!("keyof") {
             Some(TsTypeOperatorOp::KeyOf)
         } else if is!("unique") {
@@ -1962,7 +2076,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     .map(Some);
             }
 
-            if is!("const") && peeked_is!("enum") {
+            if p.input.syntax().typescript_only() && is!("const") && peeked_is!("enum") {
                 assert_and_bump!("const");
                 let _ = cur!(true);
                 assert_and_bump!("enum");
@@ -2032,7 +2146,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ) -> PResult<'a, Option<Decl>> {
         match value {
             js_word!("abstract") => {
-                if next || is!("class") {
+                if self.input.syntax().typescript_only() && (next || is!("class")) {
                     if next {
                         bump!();
                     }
@@ -2053,7 +2167,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
 
             js_word!("enum") => {
-                if next || is!(IdentRef) {
+                if self.input.syntax().typescript_only() && (next || is!(IdentRef)) {
                     if next {
                         bump!();
                     }
@@ -2099,7 +2213,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
 
             js_word!("namespace") => {
-                if next || is!(IdentRef) {
+                if self.input.syntax().typescript_only() && (next || is!(IdentRef)) {
                     if next {
                         bump!();
                     }
@@ -2122,6 +2236,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             }
 
+            js_word!("opaque") => {
+                if self.input.syntax().flow() && (next || is!("type")) {
+                    if next {
+                        bump!();
+                    }
+                    expect!("type");
+                    return self
+                        .parse_flow_opaque_type_alias_decl(start)
+                        .map(From::from)
+                        .map(Some);
+                }
+            }
+
             _ => {}
         }
 
@@ -2240,10 +2367,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         let start = cur_pos!();
         let ty = parse_constituent_type(self)?;
-        if self.input.is(&operator) {
+        if self.is_ts_union_or_intersection_operator(operator) {
             let mut types = vec![ty];
 
-            while self.input.eat(operator) {
+            while self.is_ts_union_or_intersection_operator(operator) {
+                bump!();
                 types.push(parse_constituent_type(self)?);
             }
 
@@ -2263,6 +2391,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         Ok(ty)
     }
+
+    /// `|}` closes an exact object type of flow.
+    fn is_ts_union_or_intersection_operator(&mut self, operator: &'static Token) -> bool {
+        self.input.is(operator) && !(self.input.syntax().flow() && peeked_is!('}'))
+    }
 }
 
 impl<'a, I: Tokens> Parser<'a, I> {
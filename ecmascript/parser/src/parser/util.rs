@@ -253,6 +253,8 @@ pub(super) trait ExprExt {
 
             Expr::TsConstAssertion(..) => false,
 
+            Expr::Pipeline(..) | Expr::Bind(..) => false,
+
             Expr::Invalid(..) => false,
         }
     }
@@ -0,0 +1,1151 @@
+//! Parser for the body and flags of regular expression literals.
+//!
+//! The lexer only validates regular expressions with this module, but the
+//! typed ast is public so that passes like `sticky_regex` can work on a
+//! structured form instead of re-parsing the raw string.
+//!
+//! In non-unicode mode the lenient grammar from Annex B is used.
+//!
+//! https://tc39.github.io/ecma262/#sec-patterns
+
+use crate::error::{Error, SyntaxError};
+use std::char;
+use swc_atoms::JsWord;
+use swc_common::{BytePos, Span};
+use unicode_xid::UnicodeXID;
+
+/// Flags of a regular expression literal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Flags {
+    /// `g`
+    pub global: bool,
+    /// `i`
+    pub ignore_case: bool,
+    /// `m`
+    pub multiline: bool,
+    /// `s`
+    pub dot_all: bool,
+    /// `u`
+    pub unicode: bool,
+    /// `y`
+    pub sticky: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub span: Span,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alternative {
+    pub span: Span,
+    pub terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    Assertion(Assertion),
+    Atom(Atom),
+    Quantified(Quantified),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assertion {
+    pub span: Span,
+    pub kind: AssertionKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssertionKind {
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NotWordBoundary,
+    /// `(?=...)` and `(?!...)`
+    Lookahead {
+        negated: bool,
+        alternatives: Vec<Alternative>,
+    },
+    /// `(?<=...)` and `(?<!...)`
+    Lookbehind {
+        negated: bool,
+        alternatives: Vec<Alternative>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantified {
+    pub span: Span,
+    pub term: Box<Term>,
+    pub min: u32,
+    /// `None` if unbounded.
+    pub max: Option<u32>,
+    /// `false` for lazy quantifiers like `*?`.
+    pub greedy: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Atom {
+    Char(Char),
+    /// `.`
+    Dot(Span),
+    ClassEscape(ClassEscape),
+    Class(Class),
+    Group(Group),
+    Backreference(Backreference),
+}
+
+/// A single character, either written literally or as an escape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Char {
+    pub span: Span,
+    /// Code point. This is not a `char` because lone surrogates are allowed.
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassEscape {
+    pub span: Span,
+    pub kind: ClassEscapeKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassEscapeKind {
+    /// `\d`
+    Digit,
+    /// `\D`
+    NotDigit,
+    /// `\s`
+    Space,
+    /// `\S`
+    NotSpace,
+    /// `\w`
+    Word,
+    /// `\W`
+    NotWord,
+    /// `\p{name=value}` and `\P{name=value}`.
+    ///
+    /// Names are not checked against the unicode property tables.
+    Property {
+        negated: bool,
+        name: JsWord,
+        value: Option<JsWord>,
+    },
+}
+
+/// `[...]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+    pub span: Span,
+    pub negated: bool,
+    pub ranges: Vec<ClassRange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassRange {
+    Char(Char),
+    Escape(ClassEscape),
+    /// `a-z`
+    Range(Char, Char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub span: Span,
+    pub kind: GroupKind,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupKind {
+    /// `(...)` and `(?<name>...)`
+    Capturing {
+        /// Starts from 1.
+        index: u32,
+        name: Option<JsWord>,
+    },
+    /// `(?:...)`
+    NonCapturing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backreference {
+    pub span: Span,
+    pub kind: BackreferenceKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackreferenceKind {
+    /// `\1`
+    Index(u32),
+    /// `\k<name>`
+    Name(JsWord),
+}
+
+/// Parses flags of a regular expression.
+///
+/// `start` is the position of the first flag.
+pub fn parse_flags(flags: &str, start: BytePos) -> Result<Flags, Error> {
+    let mut parsed = Flags::default();
+
+    for (i, c) in flags.char_indices() {
+        let span = Span::new(
+            start + BytePos(i as u32),
+            start + BytePos((i + c.len_utf8()) as u32),
+            Default::default(),
+        );
+        let flag = match c {
+            'g' => &mut parsed.global,
+            'i' => &mut parsed.ignore_case,
+            'm' => &mut parsed.multiline,
+            's' => &mut parsed.dot_all,
+            'u' => &mut parsed.unicode,
+            'y' => &mut parsed.sticky,
+            _ => {
+                return Err(Error {
+                    span,
+                    error: SyntaxError::UnknownRegexpFlag { c },
+                })
+            }
+        };
+        if *flag {
+            return Err(Error {
+                span,
+                error: SyntaxError::DuplicateRegexpFlag { c },
+            });
+        }
+        *flag = true;
+    }
+
+    Ok(parsed)
+}
+
+/// Parses the body of a regular expression.
+///
+/// `start` is the position of the first character after `/`.
+pub fn parse_pattern(pattern: &str, flags: Flags, start: BytePos) -> Result<Pattern, Error> {
+    let (group_count, has_named_groups) = scan_groups(pattern);
+
+    let mut p = PatternParser {
+        src: pattern,
+        pos: 0,
+        start,
+        unicode: flags.unicode,
+        // `\k` is an identity escape in legacy patterns without named groups.
+        named_groups: flags.unicode || has_named_groups,
+        group_count,
+        group_index: 0,
+        group_names: vec![],
+        named_refs: vec![],
+    };
+
+    let alternatives = p.parse_disjunction()?;
+    if p.cur().is_some() {
+        // Only `)` can stop a disjunction.
+        return Err(p.error(p.pos, SyntaxError::RegexpUnmatchedParen));
+    }
+
+    for &(span, ref name) in &p.named_refs {
+        if !p.group_names.contains(name) {
+            return Err(Error {
+                span,
+                error: SyntaxError::RegexpInvalidNamedReference,
+            });
+        }
+    }
+
+    Ok(Pattern {
+        span: p.span(0),
+        alternatives,
+    })
+}
+
+/// Counts capturing groups and checks if the pattern has a named group.
+///
+/// Backreferences may point to a group which comes after them, so this is
+/// done before parsing.
+fn scan_groups(src: &str) -> (u32, bool) {
+    let bytes = src.as_bytes();
+    let (mut count, mut named, mut in_class) = (0, false, false);
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'[' => in_class = true,
+            b']' => in_class = false,
+            b'(' if !in_class => {
+                if bytes.get(i + 1) != Some(&b'?') {
+                    count += 1;
+                } else if bytes.get(i + 2) == Some(&b'<')
+                    && bytes.get(i + 3) != Some(&b'=')
+                    && bytes.get(i + 3) != Some(&b'!')
+                {
+                    count += 1;
+                    named = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    (count, named)
+}
+
+/// Parses `{n}`, `{n,}` and `{n,m}`.
+///
+/// Returns minimum, maximum and the length of the quantifier.
+fn braced_quantifier(src: &str) -> Option<(u32, Option<u32>, usize)> {
+    fn digits(src: &str, pos: &mut usize) -> Option<u32> {
+        let len = src[*pos..]
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+        let value = src[*pos..*pos + len]
+            .bytes()
+            .fold(0u32, |v, b| v.saturating_mul(10).saturating_add(u32::from(b - b'0')));
+        *pos += len;
+        Some(value)
+    }
+
+    if !src.starts_with('{') {
+        return None;
+    }
+    let mut pos = 1;
+    let min = digits(src, &mut pos)?;
+    let max = if src[pos..].starts_with(',') {
+        pos += 1;
+        digits(src, &mut pos)
+    } else {
+        Some(min)
+    };
+    if !src[pos..].starts_with('}') {
+        return None;
+    }
+
+    Some((min, max, pos + 1))
+}
+
+fn is_syntax_char(c: char) -> bool {
+    match c {
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' => true,
+        _ => false,
+    }
+}
+
+struct PatternParser<'a> {
+    src: &'a str,
+    /// Byte offset in `src`.
+    pos: usize,
+    start: BytePos,
+    unicode: bool,
+    named_groups: bool,
+    group_count: u32,
+    group_index: u32,
+    group_names: Vec<JsWord>,
+    /// Checked after parsing because groups can come after references.
+    named_refs: Vec<(Span, JsWord)>,
+}
+
+impl<'a> PatternParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn cur(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    fn bump(&mut self) -> char {
+        let c = self.cur().expect("bump() called at the end of pattern");
+        self.pos += c.len_utf8();
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.cur() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn span(&self, start: usize) -> Span {
+        Span::new(
+            self.start + BytePos(start as u32),
+            self.start + BytePos(self.pos as u32),
+            Default::default(),
+        )
+    }
+
+    /// Creates an error spanning from `start` to the current position, or the
+    /// character at `start` if nothing is consumed.
+    fn error(&self, start: usize, error: SyntaxError) -> Error {
+        let end = if self.pos > start {
+            self.pos
+        } else {
+            start
+                + self.src[start..]
+                    .chars()
+                    .next()
+                    .map(|c| c.len_utf8())
+                    .unwrap_or(0)
+        };
+
+        Error {
+            span: Span::new(
+                self.start + BytePos(start as u32),
+                self.start + BytePos(end as u32),
+                Default::default(),
+            ),
+            error,
+        }
+    }
+
+    fn parse_disjunction(&mut self) -> Result<Vec<Alternative>, Error> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative, Error> {
+        let start = self.pos;
+        let mut terms = vec![];
+        while let Some(c) = self.cur() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            terms.push(self.parse_term()?);
+        }
+
+        Ok(Alternative {
+            span: self.span(start),
+            terms,
+        })
+    }
+
+    fn parse_term(&mut self) -> Result<Term, Error> {
+        let start = self.pos;
+
+        let kind = if self.eat('^') {
+            Some(AssertionKind::Start)
+        } else if self.eat('$') {
+            Some(AssertionKind::End)
+        } else if self.eat_str("\\b") {
+            Some(AssertionKind::WordBoundary)
+        } else if self.eat_str("\\B") {
+            Some(AssertionKind::NotWordBoundary)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            return Ok(Term::Assertion(Assertion {
+                span: self.span(start),
+                kind,
+            }));
+        }
+
+        let lookaround = if self.eat_str("(?=") {
+            Some((false, false))
+        } else if self.eat_str("(?!") {
+            Some((false, true))
+        } else if self.eat_str("(?<=") {
+            Some((true, false))
+        } else if self.eat_str("(?<!") {
+            Some((true, true))
+        } else {
+            None
+        };
+        if let Some((lookbehind, negated)) = lookaround {
+            let alternatives = self.parse_disjunction()?;
+            if !self.eat(')') {
+                return Err(self.error(start, SyntaxError::RegexpUnterminatedGroup));
+            }
+            let kind = if lookbehind {
+                AssertionKind::Lookbehind {
+                    negated,
+                    alternatives,
+                }
+            } else {
+                AssertionKind::Lookahead {
+                    negated,
+                    alternatives,
+                }
+            };
+            let assertion = Term::Assertion(Assertion {
+                span: self.span(start),
+                kind,
+            });
+
+            // Annex B allows quantifying lookaheads.
+            if !lookbehind && !self.unicode {
+                return self.parse_quantifier(start, assertion);
+            }
+            return Ok(assertion);
+        }
+
+        match self.cur() {
+            Some('*') | Some('+') | Some('?') => {
+                return Err(self.error(start, SyntaxError::RegexpNothingToRepeat));
+            }
+            Some('{') => {
+                if let Some((_, _, len)) = braced_quantifier(self.rest()) {
+                    self.pos += len;
+                    return Err(self.error(start, SyntaxError::RegexpNothingToRepeat));
+                }
+            }
+            _ => {}
+        }
+
+        let atom = self.parse_atom()?;
+        self.parse_quantifier(start, Term::Atom(atom))
+    }
+
+    fn parse_quantifier(&mut self, start: usize, term: Term) -> Result<Term, Error> {
+        let quantifier_start = self.pos;
+        let (min, max) = match self.cur() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => match braced_quantifier(self.rest()) {
+                Some((min, max, len)) => {
+                    self.pos += len;
+                    if max.map_or(false, |max| max < min) {
+                        return Err(
+                            self.error(quantifier_start, SyntaxError::RegexpQuantifierOutOfOrder)
+                        );
+                    }
+                    (min, max)
+                }
+                // `{` is parsed as an atom in non-unicode mode.
+                None => return Ok(term),
+            },
+            _ => return Ok(term),
+        };
+        if self.pos == quantifier_start {
+            self.bump();
+        }
+        let greedy = !self.eat('?');
+
+        Ok(Term::Quantified(Quantified {
+            span: self.span(start),
+            term: Box::new(term),
+            min,
+            max,
+            greedy,
+        }))
+    }
+
+    fn parse_atom(&mut self) -> Result<Atom, Error> {
+        let start = self.pos;
+
+        match self.cur() {
+            Some('.') => {
+                self.bump();
+                Ok(Atom::Dot(self.span(start)))
+            }
+            Some('(') => self.parse_group().map(Atom::Group),
+            Some('[') => self.parse_class().map(Atom::Class),
+            Some('\\') => self.parse_atom_escape(),
+            Some(']') | Some('{') | Some('}') if self.unicode => {
+                Err(self.error(start, SyntaxError::RegexpLoneQuantifierBrackets))
+            }
+            _ => {
+                let value = self.bump() as u32;
+                Ok(Atom::Char(Char {
+                    span: self.span(start),
+                    value,
+                }))
+            }
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Group, Error> {
+        let start = self.pos;
+        self.bump(); // '('
+
+        let kind = if self.eat_str("?:") {
+            GroupKind::NonCapturing
+        } else if self.eat_str("?<") {
+            let name = self.parse_group_name()?;
+            if self.group_names.contains(&name) {
+                return Err(self.error(start, SyntaxError::RegexpDuplicateGroupName));
+            }
+            self.group_names.push(name.clone());
+            self.group_index += 1;
+            GroupKind::Capturing {
+                index: self.group_index,
+                name: Some(name),
+            }
+        } else if self.cur() == Some('?') {
+            return Err(self.error(start, SyntaxError::RegexpInvalidGroup));
+        } else {
+            self.group_index += 1;
+            GroupKind::Capturing {
+                index: self.group_index,
+                name: None,
+            }
+        };
+
+        let alternatives = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(self.error(start, SyntaxError::RegexpUnterminatedGroup));
+        }
+
+        Ok(Group {
+            span: self.span(start),
+            kind,
+            alternatives,
+        })
+    }
+
+    /// Parses a group name and the following `>`.
+    fn parse_group_name(&mut self) -> Result<JsWord, Error> {
+        let start = self.pos;
+        let mut name = String::new();
+
+        loop {
+            let c = match self.cur() {
+                Some('>') => {
+                    self.bump();
+                    break;
+                }
+                Some('\\') => {
+                    self.bump();
+                    let c = if self.eat('u') {
+                        self.parse_unicode_escape(true)?
+                            .and_then(char::from_u32)
+                    } else {
+                        None
+                    };
+                    match c {
+                        Some(c) => c,
+                        None => return Err(self.error(start, SyntaxError::RegexpInvalidGroupName)),
+                    }
+                }
+                Some(_) => self.bump(),
+                None => return Err(self.error(start, SyntaxError::RegexpInvalidGroupName)),
+            };
+
+            let valid = if name.is_empty() {
+                c == '$' || c == '_' || UnicodeXID::is_xid_start(c)
+            } else {
+                c == '$' || c == '\u{200c}' || c == '\u{200d}' || UnicodeXID::is_xid_continue(c)
+            };
+            if !valid {
+                return Err(self.error(start, SyntaxError::RegexpInvalidGroupName));
+            }
+            name.push(c);
+        }
+
+        if name.is_empty() {
+            return Err(self.error(start, SyntaxError::RegexpInvalidGroupName));
+        }
+        Ok(name.into())
+    }
+
+    /// Expects current char to be '\\'
+    fn parse_atom_escape(&mut self) -> Result<Atom, Error> {
+        let start = self.pos;
+        self.bump(); // '\\'
+
+        match self.cur() {
+            None => Err(self.error(start, SyntaxError::RegexpInvalidEscape)),
+
+            Some('1'..='9') => {
+                let digits_start = self.pos;
+                while self.cur().map_or(false, |c| c.is_ascii_digit()) {
+                    self.bump();
+                }
+                let index = self.src[digits_start..self.pos]
+                    .parse::<u32>()
+                    .unwrap_or(u32::max_value());
+                if index <= self.group_count {
+                    return Ok(Atom::Backreference(Backreference {
+                        span: self.span(start),
+                        kind: BackreferenceKind::Index(index),
+                    }));
+                }
+                if self.unicode {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidReference));
+                }
+
+                // Annex B: legacy octal escape or identity escape
+                self.pos = digits_start;
+                let value = self.parse_char_escape(start, false)?;
+                Ok(Atom::Char(Char {
+                    span: self.span(start),
+                    value,
+                }))
+            }
+
+            Some('k') if self.named_groups => {
+                self.bump();
+                if !self.eat('<') {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidNamedReference));
+                }
+                let name = self.parse_group_name()?;
+                let span = self.span(start);
+                self.named_refs.push((span, name.clone()));
+                Ok(Atom::Backreference(Backreference {
+                    span,
+                    kind: BackreferenceKind::Name(name),
+                }))
+            }
+
+            Some('d') | Some('D') | Some('s') | Some('S') | Some('w') | Some('W') => {
+                self.parse_class_escape(start).map(Atom::ClassEscape)
+            }
+            Some('p') | Some('P') if self.unicode => {
+                self.parse_class_escape(start).map(Atom::ClassEscape)
+            }
+
+            Some(_) => {
+                let value = self.parse_char_escape(start, false)?;
+                Ok(Atom::Char(Char {
+                    span: self.span(start),
+                    value,
+                }))
+            }
+        }
+    }
+
+    /// Parses the part after `\` of `\d`, `\s`, `\w`, `\p{..}` and their
+    /// negations.
+    fn parse_class_escape(&mut self, start: usize) -> Result<ClassEscape, Error> {
+        let kind = match self.bump() {
+            'd' => ClassEscapeKind::Digit,
+            'D' => ClassEscapeKind::NotDigit,
+            's' => ClassEscapeKind::Space,
+            'S' => ClassEscapeKind::NotSpace,
+            'w' => ClassEscapeKind::Word,
+            'W' => ClassEscapeKind::NotWord,
+            c @ 'p' | c @ 'P' => {
+                if !self.eat('{') {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidPropertyName));
+                }
+                let text_start = self.pos;
+                while self
+                    .cur()
+                    .map_or(false, |c| c.is_ascii_alphanumeric() || c == '_' || c == '=')
+                {
+                    self.bump();
+                }
+                let text = &self.src[text_start..self.pos];
+                if !self.eat('}') {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidPropertyName));
+                }
+
+                let (name, value) = match text.find('=') {
+                    Some(i) => (&text[..i], Some(&text[i + 1..])),
+                    None => (text, None),
+                };
+                let valid_value = value.map_or(true, |v| !v.is_empty() && !v.contains('='));
+                if name.is_empty() || !valid_value {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidPropertyName));
+                }
+
+                ClassEscapeKind::Property {
+                    negated: c == 'P',
+                    name: name.into(),
+                    value: value.map(From::from),
+                }
+            }
+            _ => unreachable!("parse_class_escape() called with invalid escape"),
+        };
+
+        Ok(ClassEscape {
+            span: self.span(start),
+            kind,
+        })
+    }
+
+    /// Parses the part after `\` of a character escape and returns the code
+    /// point.
+    fn parse_char_escape(&mut self, start: usize, in_class: bool) -> Result<u32, Error> {
+        let c = self.bump();
+
+        let value = match c {
+            'f' => 0x0c,
+            'n' => 0x0a,
+            'r' => 0x0d,
+            't' => 0x09,
+            'v' => 0x0b,
+
+            'c' => match self.cur() {
+                Some(l) if l.is_ascii_alphabetic() => {
+                    self.bump();
+                    l as u32 % 32
+                }
+                // Annex B: `[\c_]` and `[\c1]`
+                Some(l) if in_class && !self.unicode && (l.is_ascii_digit() || l == '_') => {
+                    self.bump();
+                    l as u32 % 32
+                }
+                _ if self.unicode => {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidEscape));
+                }
+                // Annex B: `\c` is a backslash followed by `c`.
+                _ => {
+                    self.pos -= 1;
+                    '\\' as u32
+                }
+            },
+
+            '0' if !self.cur().map_or(false, |c| c.is_ascii_digit()) => 0,
+
+            // Annex B: legacy octal escape
+            '0'..='7' if !self.unicode => {
+                let mut value = c.to_digit(8).unwrap();
+                let max_len = if value <= 3 { 2 } else { 1 };
+                for _ in 0..max_len {
+                    match self.cur().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            self.bump();
+                            value = value * 8 + d;
+                        }
+                        None => break,
+                    }
+                }
+                value
+            }
+
+            'x' => match self.eat_hex_digits(2) {
+                Some(value) => value,
+                None if self.unicode => {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidEscape));
+                }
+                None => 'x' as u32,
+            },
+
+            'u' => match self.parse_unicode_escape(self.unicode)? {
+                Some(value) => value,
+                None if self.unicode => {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidUnicodeEscape));
+                }
+                None => 'u' as u32,
+            },
+
+            '-' if in_class => '-' as u32,
+
+            _ if self.unicode => {
+                if !is_syntax_char(c) && c != '/' {
+                    return Err(self.error(start, SyntaxError::RegexpInvalidEscape));
+                }
+                c as u32
+            }
+
+            'k' if self.named_groups => {
+                return Err(self.error(start, SyntaxError::RegexpInvalidEscape));
+            }
+
+            // Annex B: identity escape
+            _ => c as u32,
+        };
+
+        Ok(value)
+    }
+
+    /// Reads exactly `count` hex digits. Nothing is consumed on failure.
+    fn eat_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.rest().get(..count)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    /// Parses the part after `\u`.
+    ///
+    /// Returns `Ok(None)` without consuming anything if it's not a valid
+    /// `\uXXXX` escape. `\u{...}` is only allowed if `allow_braces` is true.
+    fn parse_unicode_escape(&mut self, allow_braces: bool) -> Result<Option<u32>, Error> {
+        let start = self.pos;
+
+        if allow_braces && self.eat('{') {
+            let digits_start = self.pos;
+            let mut value = 0u32;
+            while let Some(d) = self.cur().and_then(|c| c.to_digit(16)) {
+                self.bump();
+                value = value.saturating_mul(16).saturating_add(d);
+            }
+            if self.pos == digits_start || !self.eat('}') || value > 0x10ffff {
+                // Include `\u`
+                return Err(self.error(start - 2, SyntaxError::RegexpInvalidUnicodeEscape));
+            }
+            return Ok(Some(value));
+        }
+
+        let lead = match self.eat_hex_digits(4) {
+            Some(lead) => lead,
+            None => return Ok(None),
+        };
+
+        // Surrogate pairs are a single character in unicode mode.
+        if self.unicode && lead >= 0xd800 && lead <= 0xdbff && self.rest().starts_with("\\u") {
+            let pair_start = self.pos;
+            self.pos += 2;
+            match self.eat_hex_digits(4) {
+                Some(trail) if trail >= 0xdc00 && trail <= 0xdfff => {
+                    return Ok(Some(0x10000 + ((lead - 0xd800) << 10) + (trail - 0xdc00)));
+                }
+                _ => self.pos = pair_start,
+            }
+        }
+
+        Ok(Some(lead))
+    }
+
+    /// Expects current char to be '['
+    fn parse_class(&mut self) -> Result<Class, Error> {
+        let start = self.pos;
+        self.bump(); // '['
+        let negated = self.eat('^');
+
+        let mut ranges = vec![];
+        loop {
+            match self.cur() {
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                Some(_) => {}
+                None => return Err(self.error(start, SyntaxError::RegexpUnterminatedClass)),
+            }
+
+            let atom_start = self.pos;
+            let from = self.parse_class_atom()?;
+            if self.cur() != Some('-') || self.peek().map_or(true, |c| c == ']') {
+                ranges.push(from);
+                continue;
+            }
+
+            let dash_start = self.pos;
+            self.bump(); // '-'
+            let dash = Char {
+                span: self.span(dash_start),
+                value: '-' as u32,
+            };
+            let to = self.parse_class_atom()?;
+
+            match (from, to) {
+                (ClassRange::Char(from), ClassRange::Char(to)) => {
+                    if from.value > to.value {
+                        return Err(self.error(atom_start, SyntaxError::RegexpRangeOutOfOrder));
+                    }
+                    ranges.push(ClassRange::Range(from, to));
+                }
+                (from, to) => {
+                    if self.unicode {
+                        return Err(self.error(atom_start, SyntaxError::RegexpInvalidClass));
+                    }
+                    // Annex B: `[\d-a]` matches `\d`, `-` and `a`.
+                    ranges.push(from);
+                    ranges.push(ClassRange::Char(dash));
+                    ranges.push(to);
+                }
+            }
+        }
+
+        Ok(Class {
+            span: self.span(start),
+            negated,
+            ranges,
+        })
+    }
+
+    fn parse_class_atom(&mut self) -> Result<ClassRange, Error> {
+        let start = self.pos;
+
+        if !self.eat('\\') {
+            let value = self.bump() as u32;
+            return Ok(ClassRange::Char(Char {
+                span: self.span(start),
+                value,
+            }));
+        }
+
+        let value = match self.cur() {
+            None => return Err(self.error(start, SyntaxError::RegexpInvalidEscape)),
+            Some('b') => {
+                self.bump();
+                0x08
+            }
+            Some('d') | Some('D') | Some('s') | Some('S') | Some('w') | Some('W') => {
+                return self.parse_class_escape(start).map(ClassRange::Escape);
+            }
+            Some('p') | Some('P') if self.unicode => {
+                return self.parse_class_escape(start).map(ClassRange::Escape);
+            }
+            Some(_) => self.parse_char_escape(start, true)?,
+        };
+
+        Ok(ClassRange::Char(Char {
+            span: self.span(start),
+            value,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sp(lo: u32, hi: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(hi), Default::default())
+    }
+
+    fn parse(pattern: &str, flags: &str) -> Result<Pattern, Error> {
+        let flags = parse_flags(flags, BytePos(0))?;
+        parse_pattern(pattern, flags, BytePos(0))
+    }
+
+    fn error(pattern: &str, flags: &str) -> SyntaxError {
+        parse(pattern, flags)
+            .expect_err("should fail to parse pattern")
+            .error
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(
+            parse_flags("gimsuy", BytePos(0)),
+            Ok(Flags {
+                global: true,
+                ignore_case: true,
+                multiline: true,
+                dot_all: true,
+                unicode: true,
+                sticky: true,
+            })
+        );
+        assert_eq!(
+            parse_flags("gg", BytePos(10)),
+            Err(Error {
+                span: sp(11, 12),
+                error: SyntaxError::DuplicateRegexpFlag { c: 'g' },
+            })
+        );
+        assert_eq!(
+            parse_flags("ga", BytePos(0)).unwrap_err().error,
+            SyntaxError::UnknownRegexpFlag { c: 'a' }
+        );
+    }
+
+    #[test]
+    fn valid() {
+        for &(pattern, flags) in &[
+            ("a|b|", ""),
+            ("^(?:ab)+?$", ""),
+            ("a{1}b{1,}c{1,2}?", ""),
+            ("(?=a)(?!b)(?<=c)(?<!d)", ""),
+            ("[a-z\\d-]", ""),
+            ("\\1(a)", ""),
+            ("(?<year>\\d{4})-\\k<year>", ""),
+            ("(?<year>\\d{4})-\\k<year>", "u"),
+            ("\\u{1F600}\\uD83D\\uDE00", "u"),
+            ("\\p{Script=Greek}\\P{L}", "u"),
+            ("[\\-]", "u"),
+        ] {
+            if let Err(err) = parse(pattern, flags) {
+                panic!("failed to parse /{}/{}: {:?}", pattern, flags, err);
+            }
+        }
+    }
+
+    #[test]
+    fn annex_b() {
+        for &pattern in &[
+            "a{", "a{1", "}", "]", "\\c", "[\\c_]", "\\8", "\\1", "\\k", "\\u{1}", "(?=a)*",
+            "[\\d-z]", "\\_",
+        ] {
+            if let Err(err) = parse(pattern, "") {
+                panic!("failed to parse /{}/: {:?}", pattern, err);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(error("(a", ""), SyntaxError::RegexpUnterminatedGroup);
+        assert_eq!(error("a)", ""), SyntaxError::RegexpUnmatchedParen);
+        assert_eq!(error("[a", ""), SyntaxError::RegexpUnterminatedClass);
+        assert_eq!(error("*", ""), SyntaxError::RegexpNothingToRepeat);
+        assert_eq!(error("a|{1}", ""), SyntaxError::RegexpNothingToRepeat);
+        assert_eq!(error("a{2,1}", ""), SyntaxError::RegexpQuantifierOutOfOrder);
+        assert_eq!(error("[z-a]", ""), SyntaxError::RegexpRangeOutOfOrder);
+        assert_eq!(error("(?a)", ""), SyntaxError::RegexpInvalidGroup);
+        assert_eq!(error("(?<a>.)(?<a>.)", ""), SyntaxError::RegexpDuplicateGroupName);
+        assert_eq!(error("(?<1>.)", ""), SyntaxError::RegexpInvalidGroupName);
+        assert_eq!(error("(?<a>.)\\k<b>", ""), SyntaxError::RegexpInvalidNamedReference);
+        assert_eq!(error("(?<a>.)\\k", ""), SyntaxError::RegexpInvalidNamedReference);
+    }
+
+    #[test]
+    fn invalid_unicode() {
+        assert_eq!(error("\\u{110000}", "u"), SyntaxError::RegexpInvalidUnicodeEscape);
+        assert_eq!(error("\\u{}", "u"), SyntaxError::RegexpInvalidUnicodeEscape);
+        assert_eq!(error("\\u12", "u"), SyntaxError::RegexpInvalidUnicodeEscape);
+        assert_eq!(error("a{", "u"), SyntaxError::RegexpLoneQuantifierBrackets);
+        assert_eq!(error("\\_", "u"), SyntaxError::RegexpInvalidEscape);
+        assert_eq!(error("\\1", "u"), SyntaxError::RegexpInvalidReference);
+        assert_eq!(error("[\\d-z]", "u"), SyntaxError::RegexpInvalidClass);
+        assert_eq!(error("\\p{}", "u"), SyntaxError::RegexpInvalidPropertyName);
+    }
+
+    #[test]
+    fn named_group() {
+        let pattern = parse("(?<a>x)\\k<a>", "").unwrap();
+        let terms = &pattern.alternatives[0].terms;
+
+        match terms[0] {
+            Term::Atom(Atom::Group(Group {
+                kind: GroupKind::Capturing { index: 1, ref name },
+                ..
+            })) => assert_eq!(*name, Some("a".into())),
+            ref term => panic!("expected a named group, got {:?}", term),
+        }
+        assert_eq!(
+            terms[1],
+            Term::Atom(Atom::Backreference(Backreference {
+                span: sp(7, 12),
+                kind: BackreferenceKind::Name("a".into()),
+            }))
+        );
+    }
+
+    #[test]
+    fn error_span() {
+        let flags = Flags::default();
+        assert_eq!(
+            parse_pattern("a{2,1}", flags, BytePos(1)).unwrap_err().span,
+            sp(2, 7)
+        );
+    }
+}
@@ -82,6 +82,9 @@ pub enum Token {
     /// '::'
     #[kind(before_expr)]
     ColonColon,
+    /// '|>'
+    #[kind(before_expr)]
+    Pipeline,
     ///
     #[kind(delegate)]
     BinOp(BinOpToken),
@@ -112,6 +115,8 @@ pub enum Token {
     #[kind(starts_expr)]
     Str {
         value: JsWord,
+        /// Source text, including quotes.
+        raw: JsWord,
         /// This field exsits because 'use\x20strict' is **not** an use strict
         /// directive.
         has_escape: bool,
@@ -123,7 +128,7 @@ pub enum Token {
 
     /// TODO: Make Num as enum and separate decimal, binary, ..etc
     #[kind(starts_expr)]
-    Num(f64),
+    Num { value: f64, raw: JsWord },
 
     #[kind(starts_expr)]
     BigInt(#[cfg_attr(feature = "fold", fold(ignore))] BigIntValue),
@@ -245,3 +245,15 @@ impl Fold<PatOrExpr> for Normalizer {
         }
     }
 }
+
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
@@ -356,10 +356,16 @@ impl Fold<Str> for Normalizer {
         Str {
             span: Default::default(),
             has_escape: false,
+            raw: None,
             ..s
         }
     }
 }
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
 impl Fold<Expr> for Normalizer {
     fn fold(&mut self, e: Expr) -> Expr {
         let e = e.fold_children(self);
@@ -402,11 +408,13 @@ impl Fold<PropName> for Normalizer {
                 span: Default::default(),
                 value: sym,
                 has_escape: false,
+                raw: None,
             }),
             PropName::Num(num) => PropName::Str(Str {
                 span: Default::default(),
                 value: num.to_string().into(),
                 has_escape: false,
+                raw: None,
             }),
             _ => n,
         }
@@ -216,6 +216,18 @@ impl Fold<PatOrExpr> for Normalizer {
     }
 }
 
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
+
 impl Fold<PropName> for Normalizer {
     fn fold(&mut self, node: PropName) -> PropName {
         let node = node.fold_children(self);
@@ -117,6 +117,7 @@ impl Fold<ImportDecl> for Entry {
                 src: Str {
                     span: DUMMY_SP,
                     value: js_word!(""),
+                    raw: None,
                     ..i.src
                 },
                 ..i
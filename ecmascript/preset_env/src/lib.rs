@@ -20,6 +20,7 @@ use swc_ecma_ast::*;
 use swc_ecma_transforms::{
     compat::{es2015, es2016, es2017, es2018, es3},
     pass::{noop, Optional, Pass},
+    proposals,
     util::prepend_stmts,
 };
 
@@ -67,6 +68,13 @@ pub fn preset_env(c: Config) -> impl Pass {
         }};
     }
 
+    // ES2021
+    let pass = add!(
+        pass,
+        LogicalAssignmentOperators,
+        proposals::logical_assignment()
+    );
+
     // ES2018
     let pass = add!(pass, ObjectRestSpread, es2018::object_rest_spread());
     let pass = add!(pass, OptionalCatchBinding, es2018::optional_catch_binding());
@@ -288,7 +296,10 @@ impl Fold<Module> for Polyfills {
                             span: DUMMY_SP,
                             value: src,
                             has_escape: false,
+                            raw: None,
                         },
+                        asserts: None,
+                        type_only: false,
                     }))
                 }),
             );
@@ -303,7 +314,10 @@ impl Fold<Module> for Polyfills {
                             span: DUMMY_SP,
                             value: src,
                             has_escape: false,
+                            raw: None,
                         },
+                        asserts: None,
+                        type_only: false,
                     }))
                 }),
             );
@@ -16,7 +16,7 @@ use std::{
     io::Read,
     path::{Path, PathBuf},
 };
-use swc_common::{fold::FoldWith, input::SourceFileInput, FromVariant};
+use swc_common::{fold::FoldWith, input::SourceFileInput, Fold, FromVariant};
 use swc_ecma_ast::*;
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{EsConfig, Parser, Session, Syntax};
@@ -202,7 +202,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                 {
                     let handlers = box MyHandlers;
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify: false },
+                        cfg: Default::default(),
                         comments: None,
                         cm: cm.clone(),
                         wr: box swc_ecma_codegen::text_writer::JsWriter::new(
@@ -233,7 +233,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
             );
 
             let module = p.parse_module().map_err(|mut e| e.emit())?;
-            let actual = module.fold_with(&mut pass);
+            let actual = module.fold_with(&mut pass).fold_with(&mut Normalizer);
 
             // debug mode?
             if dir.join("stdout.txt").exists() {
@@ -263,7 +263,10 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                     None,
                 );
 
-                let mut m = p.parse_module().map_err(|mut e| e.emit())?;
+                let mut m = p
+                    .parse_module()
+                    .map_err(|mut e| e.emit())?
+                    .fold_with(&mut Normalizer);
 
                 m.body.sort_by(|a, b| match *a {
                     ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
@@ -326,3 +329,18 @@ fn fixtures() {
 struct MyHandlers;
 
 impl swc_ecma_codegen::Handlers for MyHandlers {}
+
+/// Prints literals of the input and the expected output in the same way.
+struct Normalizer;
+
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
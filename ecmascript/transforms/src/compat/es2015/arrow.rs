@@ -86,6 +86,7 @@ impl Fold<Expr> for Arrow {
                     ident: None,
                     function: Function {
                         decorators: vec![],
+                        param_decorators: Default::default(),
                         span,
                         params,
                         is_async,
@@ -118,6 +118,7 @@ impl BlockScoping {
                                     })
                                     .collect(),
                                 decorators: Default::default(),
+                                param_decorators: Default::default(),
                                 body: Some(match body {
                                     Stmt::Block(bs) => bs,
                                     _ => BlockStmt {
@@ -103,6 +103,7 @@ where
                                             }
                                             .into()],
                                             src: None,
+                                            asserts: None,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -333,6 +334,7 @@ impl Classes {
                     params,
                     body: Some(body),
                     decorators: Default::default(),
+                    param_decorators: Default::default(),
                     type_params: Default::default(),
                     return_type: Default::default(),
                 },
@@ -375,6 +377,9 @@ impl Classes {
                 ClassMember::PrivateProp(..) => unreachable!(
                     "classes pass: private property\nclass_properties pass should remove this"
                 ),
+                ClassMember::StaticBlock(..) => unreachable!(
+                    "classes pass: static block\nclass_properties pass should remove this"
+                ),
                 ClassMember::TsIndexSignature(..) => {
                     // We just strip this.
                 }
@@ -554,6 +559,7 @@ impl Classes {
                     span: DUMMY_SP,
                     value: "use strict".into(),
                     has_escape: false,
+                    raw: None,
                 })
                 .into_stmt(),
             );
@@ -658,7 +664,7 @@ impl Classes {
                         box Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone())))
                     }
                     PropName::Str(ref s) => box Expr::Lit(Lit::Str(s.clone())),
-                    PropName::Num(n) => box Expr::Lit(Lit::Num(n)),
+                    PropName::Num(ref n) => box Expr::Lit(Lit::Num(n.clone())),
                     PropName::Computed(ref c) => c.expr.clone(),
                 },
             })
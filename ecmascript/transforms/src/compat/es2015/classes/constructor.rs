@@ -1,5 +1,5 @@
 use super::get_prototype_of;
-use crate::util::ExprFactory;
+use crate::util::{constructor::param_pat, ExprFactory};
 use ast::*;
 use std::iter;
 use swc_atoms::JsWord;
@@ -116,14 +116,8 @@ pub(super) fn constructor_fn(c: Constructor) -> Function {
     Function {
         span: DUMMY_SP,
         decorators: Default::default(),
-        params: c
-            .params
-            .into_iter()
-            .map(|pat| match pat {
-                PatOrTsParamProp::Pat(p) => p,
-                _ => unimplemented!("TsParamProp in constructor"),
-            })
-            .collect(),
+        param_decorators: Default::default(),
+        params: c.params.into_iter().map(param_pat).collect(),
         body: c.body,
         is_async: false,
         is_generator: false,
@@ -1,5 +1,7 @@
 use super::get_prototype_of;
-use crate::util::{alias_ident_for, is_rest_arguments, ExprFactory};
+use crate::util::{
+    alias_ident_for, is_literal, is_rest_arguments, short_circuit_assign, ExprFactory,
+};
 use ast::*;
 use std::iter;
 use swc_atoms::js_word;
@@ -125,6 +127,7 @@ impl<'a> Fold<Expr> for SuperCalleeFolder<'a> {
                         box Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         })),
                     )
                 }
@@ -207,6 +210,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref expr => expr.clone(),
         }
@@ -245,6 +249,13 @@ impl<'a> SuperCalleeFolder<'a> {
     ) -> Expr {
         self.inject_set = true;
 
+        match op {
+            op!("&&=") | op!("||=") | op!("??=") => {
+                return self.super_to_logical_set_call(super_token, prop, op, rhs)
+            }
+            _ => {}
+        }
+
         let mut ref_ident = alias_ident_for(&rhs, "_ref");
         ref_ident.span = ref_ident.span.apply_mark(Mark::fresh(Mark::root()));
 
@@ -270,9 +281,6 @@ impl<'a> SuperCalleeFolder<'a> {
             }
         }
 
-        let proto_arg =
-            get_prototype_of(self.class_name.clone().member(quote_ident!("prototype"))).as_arg();
-
         let prop_arg = match *prop {
             Expr::Ident(Ident {
                 sym: ref value,
@@ -282,6 +290,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 span,
                 value: value.clone(),
                 has_escape: false,
+                raw: None,
             })),
             ref e => e.clone(),
         };
@@ -335,6 +344,7 @@ impl<'a> SuperCalleeFolder<'a> {
                         op!("&=") => op!("&"),
                         op!("^=") => op!("^"),
                         op!("**=") => op!("**"),
+                        op!("&&=") | op!("||=") | op!("??=") => unreachable!(),
                     },
                     right: rhs,
                 }
@@ -342,9 +352,70 @@ impl<'a> SuperCalleeFolder<'a> {
             }
         };
 
+        let expr = self.set_call(super_token, prop_arg, rhs_arg);
+
+        if is_update {
+            Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![box expr, box Expr::Ident(update_ident)],
+            })
+        } else {
+            expr
+        }
+    }
+
+    /// `super.foo ||= bar` =>
+    /// `_get(..., 'foo', this) || _set(..., 'foo', bar, this, true)`
+    fn super_to_logical_set_call(
+        &mut self,
+        super_token: Span,
+        prop: Box<Expr>,
+        op: AssignOp,
+        rhs: Box<Expr>,
+    ) -> Expr {
+        let prop = match *prop {
+            Expr::Ident(Ident { sym, span, .. }) => box Expr::Lit(Lit::Str(Str {
+                span,
+                value: sym,
+                has_escape: false,
+                raw: None,
+            })),
+            prop => box prop,
+        };
+        let (get_prop, set_prop) = if is_literal(&*prop) {
+            (prop.clone(), prop)
+        } else {
+            let ref_ident = alias_ident_for(&prop, "_ref");
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(ref_ident.clone()),
+                init: None,
+                definite: false,
+            });
+
+            (
+                box Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Pat(box Pat::Ident(ref_ident.clone())),
+                    op: op!("="),
+                    right: prop,
+                }),
+                box Expr::Ident(ref_ident),
+            )
+        };
+
+        let get = self.super_to_get_call(super_token, get_prop, true);
+        let set = self.set_call(super_token, set_prop.as_arg(), rhs.as_arg());
+
+        short_circuit_assign(DUMMY_SP, box get, op, box set, self.vars)
+    }
+
+    fn set_call(&self, super_token: Span, prop_arg: ExprOrSpread, rhs_arg: ExprOrSpread) -> Expr {
+        let proto_arg =
+            get_prototype_of(self.class_name.clone().member(quote_ident!("prototype"))).as_arg();
         let this_arg = ThisExpr { span: super_token }.as_arg();
 
-        let expr = Expr::Call(CallExpr {
+        Expr::Call(CallExpr {
             span: super_token,
             callee: helper!(set, "set"),
             args: vec![
@@ -360,16 +431,7 @@ impl<'a> SuperCalleeFolder<'a> {
                 .as_arg(),
             ],
             type_args: Default::default(),
-        });
-
-        if is_update {
-            Expr::Seq(SeqExpr {
-                span: DUMMY_SP,
-                exprs: vec![box expr, box Expr::Ident(update_ident)],
-            })
-        } else {
-            expr
-        }
+        })
     }
 }
 
@@ -101,6 +101,7 @@ impl Fold<Expr> for ObjectLitFolder {
                                     span: ident.span,
                                     value: ident.sym.clone(),
                                     has_escape: false,
+                                    raw: None,
                                 })),
                                 Expr::Ident(ident),
                             ),
@@ -135,6 +136,7 @@ impl Fold<Expr> for ObjectLitFolder {
                                             is_generator: false,
                                             params: vec![],
                                             decorators: Default::default(),
+                                            param_decorators: Default::default(),
                                             type_params: Default::default(),
                                             return_type: type_ann,
                                         },
@@ -153,6 +155,7 @@ impl Fold<Expr> for ObjectLitFolder {
                                             is_generator: false,
                                             params: vec![param],
                                             decorators: Default::default(),
+                                            param_decorators: Default::default(),
                                             type_params: Default::default(),
                                             return_type: Default::default(),
                                         },
@@ -326,6 +329,7 @@ fn prop_name_to_expr(p: PropName) -> Expr {
             value: i.sym,
             span: i.span,
             has_escape: false,
+            raw: None,
         })),
         PropName::Str(s) => Expr::Lit(Lit::Str(s)),
         PropName::Num(n) => Expr::Lit(Lit::Num(n)),
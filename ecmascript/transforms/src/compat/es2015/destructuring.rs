@@ -275,6 +275,7 @@ impl AssignFolder {
                                 args: vec![Lit::Num(Number {
                                     value: i as f64,
                                     span: dot3_token,
+                                    raw: None,
                                 })
                                 .as_arg()],
                                 type_args: Default::default(),
@@ -340,6 +341,7 @@ impl AssignFolder {
                                         span: DUMMY_SP,
                                         value: "Cannot destructure undefined".into(),
                                         has_escape: false,
+                                        raw: None,
                                     })
                                     .as_arg()]),
                                     type_args: Default::default(),
@@ -947,6 +949,7 @@ fn make_ref_ident_for_array(
                                         Lit::Num(Number {
                                             span: DUMMY_SP,
                                             value: value as _,
+                                            raw: None,
                                         })
                                         .as_arg(),
                                     ],
@@ -993,6 +996,7 @@ fn make_cond_expr(tmp: Ident, def_value: Box<Expr>) -> Expr {
                 arg: box Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: 0.0,
+                    raw: None,
                 })),
             }),
         }),
@@ -1051,6 +1055,8 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. })
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::OptChain(ref e) => can_be_null(&e.expr),
+        Expr::Pipeline(..) => true,
+        Expr::Bind(..) => false,
 
         Expr::Invalid(..) => unreachable!(),
     }
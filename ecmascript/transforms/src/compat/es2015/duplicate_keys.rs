@@ -103,6 +103,7 @@ impl<'a> Fold<PropName> for PropNameFolder<'a> {
                             span,
                             value: ident.sym,
                             has_escape: false,
+                            raw: None,
                         })),
                     })
                 } else {
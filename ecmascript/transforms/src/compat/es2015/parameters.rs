@@ -90,6 +90,7 @@ impl Params {
                                 right: box Expr::Lit(Lit::Num(Number {
                                     span,
                                     value: i as f64,
+                                    raw: None,
                                 })),
                             })
                             .into();
@@ -106,11 +107,16 @@ impl Params {
                                     right: box Expr::Lit(Lit::Num(Number {
                                         span,
                                         value: i as _,
+                                        raw: None,
                                     })),
                                 }
                                 .into(),
                                 cons: box bin,
-                                alt: box Expr::Lit(Lit::Num(Number { span, value: 0.0 })),
+                                alt: box Expr::Lit(Lit::Num(Number {
+                                    span,
+                                    value: 0.0,
+                                    raw: None,
+                                })),
                             }))
                         }
                     };
@@ -150,6 +156,7 @@ impl Params {
                                     init: Some(box Expr::Lit(Lit::Num(Number {
                                         span,
                                         value: i as f64,
+                                        raw: None,
                                     }))),
                                     definite: false,
                                 },
@@ -23,6 +23,7 @@ impl Loc {
         Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
             value: self.stmt_index as _,
+            raw: None,
         }))
     }
 
@@ -305,6 +306,22 @@ impl CaseHandler<'_> {
                 })
             }
 
+            Expr::Pipeline(e) => {
+                return Expr::Pipeline(PipelineExpr {
+                    left: e.left.map(|e| self.explode_expr(e, false)),
+                    right: e.right.map(|e| self.explode_expr(e, false)),
+                    ..e
+                })
+            }
+
+            Expr::Bind(e) => {
+                return Expr::Bind(BindExpr {
+                    object: e.object.map(|e| e.map(|e| self.explode_expr(e, false))),
+                    callee: e.callee.map(|e| self.explode_expr(e, false)),
+                    ..e
+                })
+            }
+
             Expr::Await(..) => unimplemented!("regenerator: await in generator"),
 
             Expr::Paren(ParenExpr { span, expr }) => {
@@ -402,6 +419,7 @@ impl CaseHandler<'_> {
                                         box Lit::Num(Number {
                                             span: DUMMY_SP,
                                             value: 0.0,
+                                            raw: None,
                                         })
                                         .into(),
                                         box callee,
@@ -719,6 +737,7 @@ impl CaseHandler<'_> {
                 test: Some(box Expr::Lit(Lit::Num(Number {
                     span: DUMMY_SP,
                     value: i as _,
+                    raw: None,
                 }))),
                 cons: vec![],
             };
@@ -784,6 +803,7 @@ impl CaseHandler<'_> {
                             span: DUMMY_SP,
                             value: ty.into(),
                             has_escape: false,
+                            raw: None,
                         })
                         .as_arg();
 
@@ -1400,6 +1420,7 @@ impl Fold<Expr> for UnmarkedInvalidHandler {
             Expr::Invalid(Invalid { span }) => Expr::Lit(Lit::Num(Number {
                 span,
                 value: self.case_id as _,
+                raw: None,
             })),
             _ => e,
         }
@@ -1426,6 +1447,7 @@ impl Fold<Expr> for InvalidToLit<'_> {
                         return Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: (*stmt_index) as _,
+                            raw: None,
                         }));
                     }
                 }
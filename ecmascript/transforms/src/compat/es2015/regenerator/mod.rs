@@ -120,6 +120,7 @@ impl Fold<Prop> for Regenerator {
                         span: DUMMY_SP,
                         params: vec![],
                         decorators: vec![],
+                        param_decorators: Default::default(),
                         body: Some(BlockStmt {
                             span: DUMMY_SP,
                             stmts: vec![ReturnStmt {
@@ -330,6 +331,7 @@ impl Regenerator {
             test: Some(box Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: handler.final_loc() as _,
+                raw: None,
             }))),
             // fallthrough
             cons: vec![],
@@ -340,6 +342,7 @@ impl Regenerator {
                 span: DUMMY_SP,
                 value: "end".into(),
                 has_escape: false,
+                raw: None,
             }))),
             cons: vec![ReturnStmt {
                 span: DUMMY_SP,
@@ -359,6 +362,7 @@ impl Regenerator {
             test: box Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 value: 1.0,
+                raw: None,
             })),
             body: box SwitchStmt {
                 span: DUMMY_SP,
@@ -409,6 +413,7 @@ impl Regenerator {
                                             function: Function {
                                                 params: vec![Pat::Ident(ctx.clone())],
                                                 decorators: Default::default(),
+                                                param_decorators: Default::default(),
                                                 span: DUMMY_SP,
                                                 body: Some(BlockStmt {
                                                     span: DUMMY_SP,
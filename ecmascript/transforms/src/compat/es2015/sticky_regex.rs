@@ -32,6 +32,7 @@ impl Fold<Expr> for StickyRegex {
                             span: DUMMY_SP,
                             value: s,
                             has_escape: false,
+                            raw: None,
                         }))
                     };
 
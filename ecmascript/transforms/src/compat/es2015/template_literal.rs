@@ -108,18 +108,21 @@ impl Fold<Expr> for TemplateLiteral {
                                 span,
                                 value,
                                 has_escape,
+                                ..
                             })) = *obj
                             {
                                 if let Expr::Lit(Lit::Str(Str {
                                     span: r_span,
                                     value: r_value,
                                     has_escape: r_has_escape,
+                                    ..
                                 })) = *expr
                                 {
                                     obj = box Expr::Lit(Lit::Str(Str {
                                         span: span.with_hi(r_span.hi()),
                                         value: format!("{}{}", value, r_value).into(),
                                         has_escape: has_escape || r_has_escape,
+                                        raw: None,
                                     }));
 
                                     continue;
@@ -128,6 +131,7 @@ impl Fold<Expr> for TemplateLiteral {
                                         span,
                                         value,
                                         has_escape,
+                                        raw: None,
                                     }))
                                 }
                             }
@@ -292,6 +296,8 @@ impl Fold<Expr> for TemplateLiteral {
                                             })],
                                         }),
                                         decorators: Default::default(),
+                                        param_decorators: Default::default(),
+                                        param_decorators: Default::default(),
                                         type_params: Default::default(),
                                         return_type: Default::default(),
                                     },
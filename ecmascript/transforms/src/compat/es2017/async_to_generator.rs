@@ -108,6 +108,7 @@ impl Fold<MethodProp> for Actual {
                     })],
                 }),
                 decorators: Default::default(),
+                param_decorators: Default::default(),
                 return_type: Default::default(),
                 type_params: Default::default(),
             },
@@ -381,6 +382,7 @@ impl Fold<ClassMethod> for Actual {
                         .collect(),
                 }),
                 decorators: Default::default(),
+                param_decorators: Default::default(),
                 type_params: Default::default(),
                 return_type: Default::default(),
             },
@@ -583,6 +585,7 @@ impl Actual {
                     is_async: false,
                     is_generator: false,
                     decorators: Default::default(),
+                    param_decorators: Default::default(),
                     type_params: Default::default(),
                     return_type: Default::default(),
                 },
@@ -630,6 +633,8 @@ impl Actual {
                             stmts: vec![apply],
                         }),
                         decorators: Default::default(),
+                        param_decorators: Default::default(),
+                        param_decorators: Default::default(),
                         type_params: Default::default(),
                         return_type: Default::default(),
                     };
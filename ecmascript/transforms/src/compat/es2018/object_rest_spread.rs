@@ -361,6 +361,7 @@ impl Fold<ModuleDecl> for RestFolder {
                     span,
                     specifiers,
                     src: None,
+                    asserts: None,
                 };
 
                 let mut var_decl = var_decl.fold_with(self);
@@ -788,12 +789,13 @@ impl RestFolder {
                             let value = value.clone();
                             (key, box Expr::Ident(quote_ident!(span, value)))
                         }
-                        PropName::Num(Number { span, value }) => (
+                        PropName::Num(Number { span, value, .. }) => (
                             key,
                             box Expr::Lit(Lit::Str(Str {
                                 span,
                                 value: format!("{}", value).into(),
                                 has_escape: false,
+                                raw: None,
                             })),
                         ),
                         PropName::Computed(ref c) if is_literal(&c.expr) => {
@@ -905,11 +907,12 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
         .into_iter()
         .map(|v| {
             v.map(|v| match *v.expr {
-                Expr::Lit(Lit::Num(Number { span, value })) => ExprOrSpread {
+                Expr::Lit(Lit::Num(Number { span, value, .. })) => ExprOrSpread {
                     expr: box Expr::Lit(Lit::Str(Str {
                         span,
                         value: value.to_string().into(),
                         has_escape: false,
+                        raw: None,
                     })),
                     ..v
                 },
@@ -957,13 +960,15 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                     span: ident.span,
                     value: ident.sym.clone(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Str(s) => Lit::Str(s.clone()).as_arg(),
-                PropName::Num(Number { span, value }) => Lit::Str(Str {
+                PropName::Num(Number { span, value, .. }) => Lit::Str(Str {
                     span: *span,
                     value: format!("{}", value).into(),
                     has_escape: false,
+                    raw: None,
                 })
                 .as_arg(),
                 PropName::Computed(c) => c.expr.clone().as_arg(),
@@ -972,6 +977,7 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                 span: key.span,
                 value: key.sym.clone(),
                 has_escape: false,
+                raw: None,
             })
             .as_arg(),
             ObjectPatProp::Rest(..) => unreachable!("invalid syntax (multiple rest element)"),
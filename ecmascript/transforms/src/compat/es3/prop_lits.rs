@@ -51,6 +51,7 @@ impl Fold<PropName> for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     })
                 } else {
                     PropName::Ident(Ident::new(sym, span))
@@ -63,6 +64,7 @@ impl Fold<PropName> for PropertyLiteral {
                         span,
                         value: sym,
                         has_escape: false,
+                        raw: None,
                     })
                 } else {
                     PropName::Ident(Ident { span, sym, ..i })
@@ -396,6 +396,12 @@ impl Fold<Expr> for Fixer {
                 obj: ExprOrSuper::Expr(obj @ box Expr::Yield(..)),
                 prop,
             })
+            | Expr::Member(MemberExpr {
+                span,
+                computed,
+                obj: ExprOrSuper::Expr(obj @ box Expr::Pipeline(..)),
+                prop,
+            })
             | Expr::Member(MemberExpr {
                 span,
                 computed,
@@ -484,7 +490,8 @@ impl Fold<Expr> for Fixer {
                     | e @ Expr::Seq(..)
                     | e @ Expr::Yield(..)
                     | e @ Expr::Cond(..)
-                    | e @ Expr::Arrow(..) => box self.wrap(e),
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Pipeline(..) => box self.wrap(e),
                     Expr::Bin(BinExpr { op: op_of_rhs, .. }) => {
                         if op_of_rhs.precedence() <= expr.op.precedence() {
                             box self.wrap(*expr.right)
@@ -513,7 +520,8 @@ impl Fold<Expr> for Fixer {
                     | e @ Expr::Yield(..)
                     | e @ Expr::Cond(..)
                     | e @ Expr::Assign(..)
-                    | e @ Expr::Arrow(..) => validate!(Expr::Bin(BinExpr {
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Pipeline(..) => validate!(Expr::Bin(BinExpr {
                         left: box self.wrap(e),
                         ..expr
                     })),
@@ -574,13 +582,62 @@ impl Fold<Expr> for Fixer {
                     | e @ Expr::Seq(..)
                     | e @ Expr::Cond(..)
                     | e @ Expr::Arrow(..)
-                    | e @ Expr::Yield(..) => box self.wrap(e),
+                    | e @ Expr::Yield(..)
+                    | e @ Expr::Pipeline(..) => box self.wrap(e),
                     _ => expr.arg,
                 };
 
                 validate!(Expr::Unary(UnaryExpr { arg, ..expr }))
             }
 
+            Expr::Pipeline(expr) => {
+                let left = match *expr.left {
+                    e @ Expr::Seq(..)
+                    | e @ Expr::Assign(..)
+                    | e @ Expr::Cond(..)
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Yield(..) => box self.wrap(e),
+                    _ => expr.left,
+                };
+
+                // `|>` is left associative.
+                let right = match *expr.right {
+                    e @ Expr::Seq(..)
+                    | e @ Expr::Assign(..)
+                    | e @ Expr::Cond(..)
+                    | e @ Expr::Arrow(..)
+                    | e @ Expr::Yield(..)
+                    | e @ Expr::Pipeline(..) => box self.wrap(e),
+                    _ => expr.right,
+                };
+
+                validate!(Expr::Pipeline(PipelineExpr { left, right, ..expr }))
+            }
+
+            Expr::Bind(expr) => {
+                let object = expr.object.map(|object| match *object {
+                    e @ Expr::Ident(..)
+                    | e @ Expr::This(..)
+                    | e @ Expr::Lit(..)
+                    | e @ Expr::Member(..)
+                    | e @ Expr::Call(..)
+                    | e @ Expr::Paren(..) => box e,
+                    e => box self.wrap(e),
+                });
+
+                // Calls are not allowed in the callee.
+                let callee = match *expr.callee {
+                    e @ Expr::Ident(..) | e @ Expr::Member(..) | e @ Expr::Paren(..) => box e,
+                    e => box self.wrap(e),
+                };
+
+                validate!(Expr::Bind(BindExpr {
+                    object,
+                    callee,
+                    ..expr
+                }))
+            }
+
             Expr::Assign(expr) => {
                 let right = match *expr.right {
                     // `foo = (bar = baz)` => foo = bar = baz
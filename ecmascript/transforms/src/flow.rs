@@ -0,0 +1,40 @@
+use crate::{pass::Pass, typescript};
+use ast::*;
+use swc_common::{util::move_map::MoveMap, Fold};
+
+/// Strips flow type annotations out.
+///
+/// Flow annotations are parsed into the nodes of typescript, so this shares
+/// the implementation with [typescript::strip].
+pub fn strip() -> impl Pass {
+    chain!(TypeOnlySpecifiers, typescript::strip())
+}
+
+/// Removes `type A` and `typeof B` in `import { type A, typeof B } from 'mod'`.
+///
+/// An import is removed if all of its specifiers are type-only.
+#[derive(Clone, Copy)]
+struct TypeOnlySpecifiers;
+
+impl Fold<Vec<ModuleItem>> for TypeOnlySpecifiers {
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        items.move_flat_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
+                let had_specifiers = !import.specifiers.is_empty();
+                import.specifiers.retain(|s| match *s {
+                    ImportSpecifier::Specific(ImportSpecific {
+                        type_only: true, ..
+                    }) => false,
+                    _ => true,
+                });
+
+                if had_specifiers && import.specifiers.is_empty() {
+                    None
+                } else {
+                    Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import)))
+                }
+            }
+            _ => Some(item),
+        })
+    }
+}
@@ -239,6 +239,8 @@ impl InjectHelpers {
                         local: quote_ident!(DUMMY_SP.apply_mark(mark), "swcHelpers"),
                     })],
                     src: quote_str!("@swc/helpers"),
+                    asserts: None,
+                    type_only: false,
                 }))]
             } else {
                 vec![]
@@ -30,6 +30,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                                 exported: Some($orig),
                             })],
                             src: None,
+                            asserts: None,
                         },
                     )));
                 };
@@ -131,6 +132,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                             span,
                             specifiers: renamed,
                             src: None,
+                            asserts: None,
                         },
                     )));
                 }
@@ -414,6 +414,7 @@ fn mark_root() {
                         is_async: false,
                         is_generator: false,
                         decorators: vec![],
+                        param_decorators: Default::default(),
                         body: Some(BlockStmt {
                             span: DUMMY_SP,
                             stmts: vec![
@@ -500,6 +501,7 @@ fn fn_args() {
                     is_async: false,
                     is_generator: false,
                     decorators: vec![],
+                    param_decorators: Default::default(),
                     body: Some(BlockStmt {
                         span: DUMMY_SP,
                         stmts: vec![tester
@@ -536,6 +538,7 @@ fn block_in_fn() {
                     is_async: false,
                     is_generator: false,
                     decorators: vec![],
+                    param_decorators: Default::default(),
                     body: Some(BlockStmt {
                         span: DUMMY_SP,
                         stmts: vec![
@@ -580,6 +583,7 @@ fn flat_in_fn() {
                     is_async: false,
                     is_generator: false,
                     decorators: vec![],
+                    param_decorators: Default::default(),
                     body: Some(BlockStmt {
                         span: DUMMY_SP,
                         stmts: vec![
@@ -622,6 +626,7 @@ fn params_in_fn() {
                     is_async: false,
                     is_generator: false,
                     decorators: vec![],
+                    param_decorators: Default::default(),
                     body: Some(BlockStmt {
                         span: DUMMY_SP,
                         stmts: vec![],
@@ -28,6 +28,7 @@ pub mod compat;
 mod const_modules;
 pub mod debug;
 mod fixer;
+pub mod flow;
 pub mod modules;
 pub mod optimization;
 pub mod pass;
@@ -138,12 +138,7 @@ macro_rules! impl_fold_fn {
                 let params = f
                     .params
                     .into_iter()
-                    .map(|pat| match pat {
-                        PatOrTsParamProp::Pat(p) => p,
-                        _ => unreachable!(
-                            "TsParameterProperty should be removed by typescript::strip pass"
-                        ),
-                    })
+                    .map(crate::util::constructor::param_pat)
                     .collect();
 
                 let (params, body) = self.fold_fn_like(params, f.body.unwrap());
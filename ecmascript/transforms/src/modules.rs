@@ -2,5 +2,6 @@
 pub mod util;
 pub mod amd;
 pub mod common_js;
+pub mod es;
 pub mod import_analysis;
 pub mod umd;
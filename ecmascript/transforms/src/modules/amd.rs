@@ -1,6 +1,7 @@
 use super::util::{
     self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, use_strict, Exports, ModulePass, Scope,
+    local_name_for_src, make_descriptor, report_dropped_asserts, report_top_level_await, use_strict,
+    Exports, ModulePass, Scope,
 };
 use crate::{
     pass::Pass,
@@ -44,6 +45,9 @@ impl Fold<Module> for Amd {
         let items = module.body;
         self.in_top_level = true;
 
+        report_top_level_await(&items, "AMD");
+        report_dropped_asserts(&items, "AMD");
+
         // Inserted after initializing exported names to undefined.
         let mut extra_stmts = vec![];
         let mut stmts = Vec::with_capacity(items.len() + 2);
@@ -56,6 +60,7 @@ impl Fold<Module> for Amd {
         let mut export_alls = vec![];
         let mut emitted_esmodule = false;
         let mut has_export = false;
+        let mut export_assign = None;
         let exports_ident = self.exports.0.clone();
 
         // Process items
@@ -389,9 +394,39 @@ impl Fold<Module> for Amd {
                     }
                 }
 
-                ModuleDecl::TsImportEquals(..)
-                | ModuleDecl::TsExportAssignment(..)
-                | ModuleDecl::TsNamespaceExport(..) => {}
+                ModuleDecl::TsImportEquals(import) => {
+                    self.scope.insert_import_equals(&import);
+
+                    if import.is_export {
+                        has_export = true;
+                        if !self.config.config.strict && !emitted_esmodule {
+                            emitted_esmodule = true;
+                            stmts.push(define_es_module(exports_ident.clone()));
+                        }
+
+                        // export import foo = require('foo');
+                        //  -> exports.foo = foo;
+                        extra_stmts.push(
+                            AssignExpr {
+                                span: import.span,
+                                left: PatOrExpr::Expr(
+                                    box exports_ident.clone().member(import.id.clone()),
+                                ),
+                                op: op!("="),
+                                right: box Expr::Ident(import.id).fold_with(self),
+                            }
+                            .into_stmt(),
+                        );
+                    }
+                }
+
+                // export = foo;
+                //  -> return foo;
+                ModuleDecl::TsExportAssignment(export) => {
+                    export_assign = Some(export.expr.fold_with(self))
+                }
+
+                ModuleDecl::TsNamespaceExport(..) => {}
             }
         }
 
@@ -513,6 +548,12 @@ impl Fold<Module> for Amd {
 
         prepend_stmts(&mut stmts, import_stmts.into_iter());
         stmts.append(&mut extra_stmts);
+        if let Some(arg) = export_assign {
+            stmts.push(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(arg),
+            }));
+        }
 
         // ====================
         //  Emit
@@ -537,6 +578,7 @@ impl Fold<Module> for Amd {
                                 is_async: false,
                                 is_generator: false,
                                 decorators: Default::default(),
+                                param_decorators: Default::default(),
                                 params: factory_params,
                                 body: Some(BlockStmt {
                                     span: DUMMY_SP,
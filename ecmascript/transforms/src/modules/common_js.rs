@@ -1,7 +1,8 @@
 pub use super::util::Config;
 use super::util::{
     define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
-    make_require_call, use_strict, ModulePass, Scope,
+    make_require_call, report_dropped_asserts, report_top_level_await, use_strict, ModulePass,
+    Scope,
 };
 use crate::{
     pass::Pass,
@@ -32,6 +33,9 @@ impl Fold<Vec<ModuleItem>> for CommonJs {
         let mut stmts = Vec::with_capacity(items.len() + 4);
         let mut extra_stmts = Vec::with_capacity(items.len());
 
+        report_top_level_await(&items, "CommonJS");
+        report_dropped_asserts(&items, "CommonJS");
+
         if self.config.strict_mode && !has_use_strict(&items) {
             stmts.push(ModuleItem::Stmt(use_strict()));
         }
@@ -419,6 +423,48 @@ impl Fold<Vec<ModuleItem>> for CommonJs {
                         _ => unreachable!(),
                     }
                 }
+
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                    self.scope.insert_import_equals(&import);
+
+                    if import.is_export {
+                        if !self.config.strict && !emitted_esmodule {
+                            emitted_esmodule = true;
+
+                            stmts.push(ModuleItem::Stmt(define_es_module(quote_ident!("exports"))));
+                        }
+
+                        // export import foo = require('foo');
+                        //  -> exports.foo = foo;
+                        extra_stmts.push(
+                            AssignExpr {
+                                span: import.span,
+                                left: PatOrExpr::Expr(
+                                    box quote_ident!("exports").member(import.id.clone()),
+                                ),
+                                op: op!("="),
+                                right: box Expr::Ident(import.id).fold_with(self),
+                            }
+                            .into_stmt()
+                            .into(),
+                        );
+                    }
+                }
+
+                // export = foo;
+                //  -> module.exports = foo;
+                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(export)) => extra_stmts
+                    .push(
+                        AssignExpr {
+                            span: export.span,
+                            left: PatOrExpr::Expr(member_expr!(DUMMY_SP, module.exports)),
+                            op: op!("="),
+                            right: export.expr.fold_with(self),
+                        }
+                        .into_stmt()
+                        .into(),
+                    ),
+
                 _ => extra_stmts.push(item.fold_with(self)),
             }
         }
@@ -527,6 +573,7 @@ impl Fold<Vec<ModuleItem>> for CommonJs {
                                 is_async: false,
                                 is_generator: false,
                                 decorators: Default::default(),
+                                param_decorators: Default::default(),
                                 body: Some(BlockStmt {
                                     span: DUMMY_SP,
                                     stmts: vec![
@@ -554,6 +601,7 @@ impl Fold<Vec<ModuleItem>> for CommonJs {
                                                     is_async: false,
                                                     is_generator: false,
                                                     decorators: Default::default(),
+                                                    param_decorators: Default::default(),
                                                     body: Some(BlockStmt {
                                                         span: DUMMY_SP,
                                                         stmts: vec![return_data.clone()],
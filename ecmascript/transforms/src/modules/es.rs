@@ -0,0 +1,47 @@
+use crate::{pass::Pass, util::HANDLER};
+use ast::*;
+use swc_common::Fold;
+
+/// Used when the output is an es module.
+///
+/// `import foo = require('foo')` and `export = foo` cannot be expressed with
+/// es modules, so an error is reported for them.
+pub fn es() -> impl Pass {
+    Es
+}
+
+struct Es;
+
+impl Fold<ModuleDecl> for Es {
+    fn fold(&mut self, decl: ModuleDecl) -> ModuleDecl {
+        match decl {
+            ModuleDecl::TsImportEquals(TsImportEqualsDecl {
+                span,
+                module_ref: TsModuleRef::TsExternalModuleRef(..),
+                ..
+            }) => HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        span,
+                        "Import assignment cannot be used when targeting ECMAScript modules",
+                    )
+                    .emit()
+            }),
+
+            ModuleDecl::TsExportAssignment(TsExportAssignment { span, .. }) => {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            span,
+                            "Export assignment cannot be used when targeting ECMAScript modules",
+                        )
+                        .emit()
+                })
+            }
+
+            _ => {}
+        }
+
+        decl
+    }
+}
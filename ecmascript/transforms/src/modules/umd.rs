@@ -2,7 +2,8 @@ use self::config::BuiltConfig;
 pub use self::config::Config;
 use super::util::{
     self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+    local_name_for_src, make_descriptor, make_require_call, report_dropped_asserts,
+    report_top_level_await, use_strict, Exports, ModulePass, Scope,
 };
 use crate::{
     pass::Pass,
@@ -42,6 +43,8 @@ impl Fold<Module> for Umd {
         let filename = self.cm.span_to_filename(module.span);
 
         let items = module.body;
+        report_top_level_await(&items, "UMD");
+        report_dropped_asserts(&items, "UMD");
 
         // Inserted after initializing exported names to undefined.
         let mut extra_stmts = vec![];
@@ -55,6 +58,7 @@ impl Fold<Module> for Umd {
         let mut export_alls = vec![];
         let mut emitted_esmodule = false;
         let mut has_export = false;
+        let mut export_assign = None;
         let exports_ident = self.exports.0.clone();
 
         // Process items
@@ -382,9 +386,39 @@ impl Fold<Module> for Umd {
                     }
                 }
 
-                ModuleDecl::TsImportEquals(..)
-                | ModuleDecl::TsExportAssignment(..)
-                | ModuleDecl::TsNamespaceExport(..) => {}
+                ModuleDecl::TsImportEquals(import) => {
+                    self.scope.insert_import_equals(&import);
+
+                    if import.is_export {
+                        has_export = true;
+                        if !self.config.config.strict && !emitted_esmodule {
+                            emitted_esmodule = true;
+                            stmts.push(define_es_module(exports_ident.clone()));
+                        }
+
+                        // export import foo = require('foo');
+                        //  -> exports.foo = foo;
+                        extra_stmts.push(
+                            AssignExpr {
+                                span: import.span,
+                                left: PatOrExpr::Expr(
+                                    box exports_ident.clone().member(import.id.clone()),
+                                ),
+                                op: op!("="),
+                                right: box Expr::Ident(import.id).fold_with(self),
+                            }
+                            .into_stmt(),
+                        );
+                    }
+                }
+
+                // export = foo;
+                //  -> return foo;
+                ModuleDecl::TsExportAssignment(export) => {
+                    export_assign = Some(export.expr.fold_with(self))
+                }
+
+                ModuleDecl::TsNamespaceExport(..) => {}
             }
         }
 
@@ -518,6 +552,13 @@ impl Fold<Module> for Umd {
 
         prepend_stmts(&mut stmts, import_stmts.into_iter());
         stmts.append(&mut extra_stmts);
+        let has_export_assign = export_assign.is_some();
+        if let Some(arg) = export_assign {
+            stmts.push(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(arg),
+            }));
+        }
 
         // ====================
         //  Emit
@@ -528,6 +569,7 @@ impl Fold<Module> for Umd {
             is_async: false,
             is_generator: false,
             decorators: Default::default(),
+            param_decorators: Default::default(),
             params: vec![
                 Pat::Ident(quote_ident!("global")),
                 Pat::Ident(quote_ident!("factory")),
@@ -575,66 +617,102 @@ impl Fold<Module> for Umd {
                             test: is_common_js,
                             cons: box Stmt::Block(BlockStmt {
                                 span: DUMMY_SP,
-                                stmts: vec![
+                                stmts: {
                                     // factory(require('foo'))
-                                    CallExpr {
+                                    let call = CallExpr {
                                         span: DUMMY_SP,
                                         callee: quote_ident!("factory").as_callee(),
                                         args: factory_args,
                                         type_args: Default::default(),
+                                    };
+
+                                    if has_export_assign {
+                                        // module.exports = factory(require('foo'))
+                                        vec![AssignExpr {
+                                            span: DUMMY_SP,
+                                            left: PatOrExpr::Expr(member_expr!(
+                                                DUMMY_SP,
+                                                module.exports
+                                            )),
+                                            op: op!("="),
+                                            right: box Expr::Call(call),
+                                        }
+                                        .into_stmt()]
+                                    } else {
+                                        vec![call.into_stmt()]
                                     }
-                                    .into_stmt(),
-                                ],
+                                },
                             }),
                             alt: Some(box Stmt::Block(BlockStmt {
                                 span: DUMMY_SP,
-                                stmts: vec![
-                                    Stmt::Decl(Decl::Var(VarDecl {
+                                stmts: if has_export_assign {
+                                    let exported_name =
+                                        self.config.determine_export_name(filename);
+
+                                    // global.foo = factory(global.bar)
+                                    vec![AssignExpr {
                                         span: DUMMY_SP,
-                                        kind: VarDeclKind::Var,
-                                        decls: vec![VarDeclarator {
+                                        left: PatOrExpr::Expr(
+                                            box quote_ident!("global").member(exported_name),
+                                        ),
+                                        op: op!("="),
+                                        right: box Expr::Call(CallExpr {
                                             span: DUMMY_SP,
-                                            name: Pat::Ident(quote_ident!("mod")),
-                                            init: Some(box Expr::Object(ObjectLit {
+                                            callee: quote_ident!("factory").as_callee(),
+                                            args: global_factory_args,
+                                            type_args: Default::default(),
+                                        }),
+                                    }
+                                    .into_stmt()]
+                                } else {
+                                    vec![
+                                        Stmt::Decl(Decl::Var(VarDecl {
+                                            span: DUMMY_SP,
+                                            kind: VarDeclKind::Var,
+                                            decls: vec![VarDeclarator {
                                                 span: DUMMY_SP,
-                                                props: vec![PropOrSpread::Prop(
-                                                    box Prop::KeyValue(KeyValueProp {
-                                                        key: PropName::Ident(quote_ident!(
-                                                            "exports"
-                                                        )),
-                                                        value: box Expr::Object(ObjectLit {
-                                                            span: DUMMY_SP,
-                                                            props: vec![],
+                                                name: Pat::Ident(quote_ident!("mod")),
+                                                init: Some(box Expr::Object(ObjectLit {
+                                                    span: DUMMY_SP,
+                                                    props: vec![PropOrSpread::Prop(
+                                                        box Prop::KeyValue(KeyValueProp {
+                                                            key: PropName::Ident(quote_ident!(
+                                                                "exports"
+                                                            )),
+                                                            value: box Expr::Object(ObjectLit {
+                                                                span: DUMMY_SP,
+                                                                props: vec![],
+                                                            }),
                                                         }),
-                                                    }),
-                                                )],
-                                            })),
-                                            definite: false,
-                                        }],
-                                        declare: false,
-                                    })),
-                                    CallExpr {
-                                        span: DUMMY_SP,
-                                        callee: quote_ident!("factory").as_callee(),
-                                        args: global_factory_args,
-                                        type_args: Default::default(),
-                                    }
-                                    .into_stmt(),
-                                    {
-                                        let exported_name =
-                                            self.config.determine_export_name(filename);
-
-                                        AssignExpr {
+                                                    )],
+                                                })),
+                                                definite: false,
+                                            }],
+                                            declare: false,
+                                        })),
+                                        CallExpr {
                                             span: DUMMY_SP,
-                                            left: PatOrExpr::Expr(
-                                                box quote_ident!("global").member(exported_name),
-                                            ),
-                                            op: op!("="),
-                                            right: member_expr!(DUMMY_SP,mod.exports),
+                                            callee: quote_ident!("factory").as_callee(),
+                                            args: global_factory_args,
+                                            type_args: Default::default(),
                                         }
-                                        .into_stmt()
-                                    },
-                                ],
+                                        .into_stmt(),
+                                        {
+                                            let exported_name =
+                                                self.config.determine_export_name(filename);
+
+                                            AssignExpr {
+                                                span: DUMMY_SP,
+                                                left: PatOrExpr::Expr(
+                                                    box quote_ident!("global").member(exported_name),
+                                                ),
+                                                op: op!("="),
+                                                right: member_expr!(DUMMY_SP,mod.exports),
+                                            }
+                                            .into_stmt()
+                                        },
+                                    ]
+                                },
                             })),
                         })),
                     })]
@@ -652,6 +730,7 @@ impl Fold<Module> for Umd {
                 is_async: false,
                 is_generator: false,
                 decorators: Default::default(),
+                param_decorators: Default::default(),
                 params: factory_params,
                 body: Some(BlockStmt {
                     span: DUMMY_SP,
@@ -1,4 +1,4 @@
-use crate::util::{undefined, DestructuringFinder, ExprFactory};
+use crate::util::{undefined, DestructuringFinder, ExprFactory, HANDLER};
 use ast::*;
 use fxhash::FxHashSet;
 use hashbrown::{hash_map::Entry, HashMap, HashSet};
@@ -7,7 +7,7 @@ use inflector::Inflector;
 use serde::{Deserialize, Serialize};
 use std::iter;
 use swc_atoms::{js_word, JsWord};
-use swc_common::{FoldWith, Mark, Span, SyntaxContext, VisitWith, DUMMY_SP};
+use swc_common::{FoldWith, Mark, Span, SyntaxContext, Visit, VisitWith, DUMMY_SP};
 
 pub(super) trait ModulePass {
     fn config(&self) -> &Config;
@@ -146,6 +146,7 @@ impl Scope {
             is_async: false,
             is_generator: false,
             decorators: Default::default(),
+            param_decorators: Default::default(),
             params: vec![Pat::Ident(key_ident.clone())],
             body: Some(BlockStmt {
                 span: DUMMY_SP,
@@ -346,6 +347,33 @@ impl Scope {
         }
     }
 
+    /// `import foo = require('foo');` binds the whole module like `import * as
+    /// foo from 'foo'`, but without interop.
+    ///
+    /// `import foo = Bar.Baz` is lowered by `typescript::strip` and ignored
+    /// here.
+    pub fn insert_import_equals(&mut self, import: &TsImportEqualsDecl) {
+        let src = match import.module_ref {
+            TsModuleRef::TsExternalModuleRef(ref r) => r.expr.value.clone(),
+            TsModuleRef::TsEntityName(..) => return,
+        };
+        let local = &import.id;
+
+        self.idents.insert(
+            (local.sym.clone(), local.span.ctxt()),
+            (src.clone(), "".into()),
+        );
+
+        // Override symbol if one exists
+        self.imports
+            .entry(src)
+            .and_modify(|v| match *v {
+                Some(ref mut v) => v.0 = local.sym.clone(),
+                None => *v = Some((local.sym.clone(), local.span)),
+            })
+            .or_insert_with(|| Some((local.sym.clone(), local.span)));
+    }
+
     pub(super) fn fold_shorthand_prop(
         folder: &mut impl ModulePass,
         top_level: bool,
@@ -499,6 +527,7 @@ impl Scope {
                                     right: box Expr::Lit(Lit::Num(Number {
                                         span: DUMMY_SP,
                                         value: 1.0,
+                                        raw: None,
                                     })),
                                 }),
                             })
@@ -547,6 +576,7 @@ impl Scope {
                                     is_async: false,
                                     is_generator: false,
                                     decorators: Default::default(),
+                                    param_decorators: Default::default(),
                                     params: vec![],
                                     body: Some(BlockStmt {
                                         span: DUMMY_SP,
@@ -650,6 +680,7 @@ pub(super) fn make_require_call(src: JsWord) -> Expr {
             span: DUMMY_SP,
             value: src,
             has_escape: false,
+            raw: None,
         })
         .as_arg()],
 
@@ -720,6 +751,87 @@ pub(super) fn use_strict() -> Stmt {
     Lit::Str(quote_str!("use strict")).into_stmt()
 }
 
+/// Warns about import assertions, which are dropped while converting imports
+/// and re-exports to `format`.
+pub(super) fn report_dropped_asserts(items: &[ModuleItem], format: &str) {
+    for item in items {
+        let asserts = match *item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { ref asserts, .. }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport { ref asserts, .. }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { ref asserts, .. })) => {
+                asserts
+            }
+            _ => continue,
+        };
+
+        if let Some(ref asserts) = *asserts {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_warn(
+                        asserts.span,
+                        &format!(
+                            "Import assertions are dropped when targeting {} modules",
+                            format
+                        ),
+                    )
+                    .emit()
+            });
+        }
+    }
+}
+
+/// Reports `await` used outside of functions, as it cannot be expressed with
+/// `format`.
+pub(super) fn report_top_level_await(items: &[ModuleItem], format: &str) {
+    let mut v = TopLevelAwaitFinder { format };
+    items.visit_with(&mut v);
+}
+
+struct TopLevelAwaitFinder<'a> {
+    format: &'a str,
+}
+
+impl TopLevelAwaitFinder<'_> {
+    fn report(&self, span: Span) {
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    span,
+                    &format!(
+                        "Top-level await cannot be used when targeting {} modules",
+                        self.format
+                    ),
+                )
+                .emit()
+        })
+    }
+}
+
+impl Visit<AwaitExpr> for TopLevelAwaitFinder<'_> {
+    fn visit(&mut self, e: &AwaitExpr) {
+        self.report(e.span);
+        e.visit_children(self);
+    }
+}
+
+impl Visit<ForOfStmt> for TopLevelAwaitFinder<'_> {
+    fn visit(&mut self, s: &ForOfStmt) {
+        if let Some(span) = s.await_token {
+            self.report(span);
+        }
+        s.visit_children(self);
+    }
+}
+
+/// Await expressions in nested functions are not top-level.
+impl Visit<Function> for TopLevelAwaitFinder<'_> {
+    fn visit(&mut self, _: &Function) {}
+}
+
+impl Visit<ArrowExpr> for TopLevelAwaitFinder<'_> {
+    fn visit(&mut self, _: &ArrowExpr) {}
+}
+
 /// Creates
 ///
 /// ```js
@@ -769,6 +881,7 @@ pub(super) fn make_descriptor(get_expr: Box<Expr>) -> ObjectLit {
                         is_async: false,
                         is_generator: false,
                         decorators: Default::default(),
+                        param_decorators: Default::default(),
                         params: vec![],
                         body: get_fn_body,
                         return_type: Default::default(),
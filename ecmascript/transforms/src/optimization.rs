@@ -3,6 +3,7 @@ pub use self::{
     json_parse::JsonParse,
     simplify::{expr_simplifier, simplifier},
 };
+pub(crate) use self::simplify::SimplifyExpr;
 
 mod inline_globals;
 mod json_parse;
@@ -1,6 +1,6 @@
 //! Ported from closure compiler.
 pub use self::dce::dce;
-use self::expr::SimplifyExpr;
+pub(crate) use self::expr::SimplifyExpr;
 use crate::pass::Pass;
 use ast::*;
 use swc_common::{Fold, FoldWith};
@@ -8,7 +8,7 @@ use swc_common::{Fold, FoldWith, Span, Spanned};
 mod tests;
 
 /// Ported from [PeepholeFoldConstants](https://github.com/google/closure-compiler/blob/9203e01b/src/com/google/javascript/jscomp/PeepholeFoldConstants.java)
-pub(super) struct SimplifyExpr;
+pub(crate) struct SimplifyExpr;
 
 impl Fold<Pat> for SimplifyExpr {
     #[inline(always)]
@@ -133,6 +133,7 @@ impl Fold<Expr> for SimplifyExpr {
                             span: e.span(),
                             value: value.into(),
                             has_escape: false,
+                            raw: None,
                         }));
                     }
                     unreachable!()
@@ -183,6 +184,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
             KnownOp::Len => Expr::Lit(Lit::Num(Number {
                 value: value.chars().count() as f64,
                 span,
+                raw: None,
             })),
 
             // 'foo'[1]
@@ -199,6 +201,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
                             .into(),
                         span,
                         has_escape: false,
+                        raw: None,
                     }))
                 }
             }
@@ -229,6 +232,7 @@ fn fold_member_expr(e: MemberExpr) -> Expr {
             Expr::Lit(Lit::Num(Number {
                 value: elems.len() as _,
                 span,
+                raw: None,
             }))
         }
 
@@ -365,7 +369,11 @@ fn fold_bin(
                 Known(v) => {
                     return preserve_effects(
                         span,
-                        Expr::Lit(Lit::Num(Number { value: v, span })),
+                        Expr::Lit(Lit::Num(Number {
+                            value: v,
+                            span,
+                            raw: None,
+                        })),
                         { iter::once(left).chain(iter::once(right)) },
                     );
                 }
@@ -387,6 +395,7 @@ fn fold_bin(
                         span,
                         // TODO
                         has_escape: false,
+                        raw: None,
                     }));
                 }
             }
@@ -414,6 +423,7 @@ fn fold_bin(
                                     span,
                                     // TODO
                                     has_escape: false,
+                                    raw: None,
                                 }));
                             }
                         }
@@ -436,7 +446,11 @@ fn fold_bin(
                             Known(v) => {
                                 return preserve_effects(
                                     span,
-                                    Expr::Lit(Lit::Num(Number { value: v, span })),
+                                    Expr::Lit(Lit::Num(Number {
+                                        value: v,
+                                        span,
+                                        raw: None,
+                                    })),
                                     { iter::once(left).chain(iter::once(right)) },
                                 );
                             }
@@ -622,7 +636,11 @@ fn fold_bin(
                             span,
                             left: left_lhs,
                             op: left_op,
-                            right: box Expr::Lit(Lit::Num(Number { value, span })),
+                            right: box Expr::Lit(Lit::Num(Number {
+                                value,
+                                span,
+                                raw: None,
+                            })),
                         });
                     }
                 }
@@ -741,6 +759,7 @@ fn try_fold_typeof(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
         span,
         value: val.into(),
         has_escape: false,
+        raw: None,
     }))
 }
 
@@ -759,7 +778,11 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
             Known(v) => {
                 return preserve_effects(
                     span,
-                    Expr::Lit(Lit::Num(Number { value: v, span })),
+                    Expr::Lit(Lit::Num(Number {
+                        value: v,
+                        span,
+                        raw: None,
+                    })),
                     iter::once(arg),
                 );
             }
@@ -777,7 +800,11 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
                 ..
             }) => return *arg,
             Expr::Lit(Lit::Num(Number { value: f, .. })) => {
-                return Expr::Lit(Lit::Num(Number { value: -f, span }));
+                return Expr::Lit(Lit::Num(Number {
+                    value: -f,
+                    span,
+                    raw: None,
+                }));
             }
             _ => {
 
@@ -791,6 +818,7 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
                 arg: box Expr::Lit(Lit::Num(Number {
                     value: 0.0,
                     span: arg.span(),
+                    raw: None,
                 })),
                 span,
             });
@@ -802,6 +830,7 @@ fn fold_unary(UnaryExpr { span, op, arg }: UnaryExpr) -> Expr {
                     return Expr::Lit(Lit::Num(Number {
                         span,
                         value: !(value as u32) as i32 as f64,
+                        raw: None,
                     }));
                 }
                 // TODO: Report error
@@ -1020,14 +1049,22 @@ fn perform_abstract_eq_cmp(span: Span, left: &Expr, right: &Expr) -> Value<bool>
         (NullType, UndefinedType) | (UndefinedType, NullType) => Known(true),
         (NumberType, StringType) | (_, BoolType) => {
             let rv = right.as_number()?;
-            perform_abstract_eq_cmp(span, left, &Expr::Lit(Lit::Num(Number { value: rv, span })))
+            perform_abstract_eq_cmp(span, left, &Expr::Lit(Lit::Num(Number {
+                value: rv,
+                span,
+                raw: None,
+            })))
         }
 
         (StringType, NumberType) | (BoolType, _) => {
             let lv = left.as_number()?;
             perform_abstract_eq_cmp(
                 span,
-                &Expr::Lit(Lit::Num(Number { value: lv, span })),
+                &Expr::Lit(Lit::Num(Number {
+                    value: lv,
+                    span,
+                    raw: None,
+                })),
                 right,
             )
         }
@@ -1,10 +1,14 @@
 pub use self::{
     class_properties::class_properties, decorators::decorators, export::export,
-    nullish_coalescing::nullish_coalescing, opt_chaining::optional_chaining,
+    function_bind::function_bind, logical_assignment::logical_assignment,
+    nullish_coalescing::nullish_coalescing, opt_chaining::optional_chaining, pipeline::pipeline,
 };
 
 mod class_properties;
 pub mod decorators;
-mod export;
+pub mod export;
+mod function_bind;
+mod logical_assignment;
 mod nullish_coalescing;
 mod opt_chaining;
+mod pipeline;
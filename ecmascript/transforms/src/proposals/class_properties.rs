@@ -14,6 +14,7 @@ use crate::{
 };
 use ast::*;
 use hashbrown::HashSet;
+use std::mem::replace;
 use swc_atoms::JsWord;
 use swc_common::{Fold, FoldWith, Mark, Spanned, VisitWith, DUMMY_SP};
 
@@ -82,6 +83,7 @@ where
                                             }
                                             .into()],
                                             src: None,
+                                            asserts: None,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -195,6 +197,7 @@ impl Fold<Expr> for ClassProperties {
                         function: Function {
                             span: DUMMY_SP,
                             decorators: vec![],
+                            param_decorators: Default::default(),
                             is_async: false,
                             is_generator: false,
                             params: vec![],
@@ -265,6 +268,8 @@ impl ClassProperties {
         let mut used_names = vec![];
         let mut used_key_names = vec![];
         let mut statics = HashSet::default();
+        // Indices of lowered static blocks in `extra_stmts`.
+        let mut static_blocks = vec![];
 
         for member in class.body {
             match member {
@@ -319,6 +324,7 @@ impl ClassProperties {
                             span: i.span,
                             value: i.sym.clone(),
                             has_escape: false,
+                            raw: None,
                         })
                         .as_arg(),
                         Expr::Lit(ref lit) if !prop.computed => lit.clone().as_arg(),
@@ -470,6 +476,49 @@ impl ClassProperties {
                     })));
                 }
 
+                ClassMember::StaticBlock(StaticBlock { body, .. }) => {
+                    // (function () { ... })(), with `this` replaced by the class
+                    let body = body
+                        .fold_with(&mut SuperFieldAccessFolder {
+                            class_name: &ident,
+                            vars: &mut vars,
+                            constructor_this_mark: None,
+                            is_static: true,
+                            folding_constructor: false,
+                            in_injected_define_property_call: false,
+                            in_nested_scope: false,
+                            this_alias_mark: None,
+                        })
+                        .fold_with(&mut ThisInStaticFolder {
+                            ident: ident.clone(),
+                        });
+
+                    static_blocks.push(extra_stmts.len());
+                    extra_stmts.push(
+                        CallExpr {
+                            span: DUMMY_SP,
+                            callee: FnExpr {
+                                ident: None,
+                                function: Function {
+                                    span: DUMMY_SP,
+                                    decorators: vec![],
+                                    param_decorators: Default::default(),
+                                    is_async: false,
+                                    is_generator: false,
+                                    params: vec![],
+                                    body: Some(body),
+                                    type_params: Default::default(),
+                                    return_type: Default::default(),
+                                },
+                            }
+                            .as_callee(),
+                            args: vec![],
+                            type_args: Default::default(),
+                        }
+                        .into_stmt(),
+                    );
+                }
+
                 ClassMember::Constructor(c) => constructor = Some(c),
             }
         }
@@ -488,6 +537,18 @@ impl ClassProperties {
             in_assign_pat: false,
         });
 
+        // Static blocks can access private fields, like methods.
+        for idx in static_blocks {
+            let stmt = replace(&mut extra_stmts[idx], Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+            extra_stmts[idx] = stmt.fold_with(&mut FieldAccessFolder {
+                mark: self.mark,
+                statics: &statics,
+                vars: vec![],
+                class_name: &ident,
+                in_assign_pat: false,
+            });
+        }
+
         (
             vars,
             Decl::Class(ClassDecl {
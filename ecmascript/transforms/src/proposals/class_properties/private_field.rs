@@ -1,4 +1,6 @@
-use crate::util::{alias_ident_for, alias_if_required, prepend, ExprFactory};
+use crate::util::{
+    alias_ident_for, alias_if_required, prepend, short_circuit_assign, ExprFactory,
+};
 use ast::*;
 use hashbrown::HashSet;
 use std::{iter, mem};
@@ -56,6 +58,13 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
 
                 let var = alias_ident_for(&obj, "_ref");
 
+                match op {
+                    op!("&&=") | op!("||=") | op!("??=") => {
+                        return self.fold_private_logical_set(obj, var, is_static, ident, op, right)
+                    }
+                    _ => {}
+                }
+
                 let this = if match *obj {
                     Expr::This(..) => true,
                     _ => false,
@@ -117,6 +126,7 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                         right: box Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
                             value: 1.0,
+                            raw: None,
                         })),
                     }
                     .as_arg()
@@ -253,6 +263,7 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                             op!("&=") => op!("&"),
                             op!("^=") => op!("^"),
                             op!("**=") => op!("**"),
+                            op!("&&=") | op!("||=") | op!("??=") => unreachable!(),
                         },
                         right,
                     }
@@ -364,6 +375,81 @@ impl<'a> FieldAccessFolder<'a> {
     ///
     ///   - `obj_alias`: If alias is already declared, this method will use
     ///     `obj_alias` instead of declaring a new one.
+    /// `obj.#foo ||= bar` => `get(_ref = obj, _foo) || set(_ref, _foo, bar)`
+    fn fold_private_logical_set(
+        &mut self,
+        obj: Box<Expr>,
+        var: Ident,
+        is_static: bool,
+        ident: Ident,
+        op: AssignOp,
+        right: Box<Expr>,
+    ) -> Expr {
+        let (get_obj, set_obj) = match *obj {
+            Expr::This(..) => (obj.clone(), obj),
+            _ => {
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(var.clone()),
+                    init: None,
+                    definite: false,
+                });
+
+                (
+                    box Expr::Assign(AssignExpr {
+                        span: obj.span(),
+                        left: PatOrExpr::Pat(box Pat::Ident(var.clone())),
+                        op: op!("="),
+                        right: obj,
+                    }),
+                    box Expr::Ident(var),
+                )
+            }
+        };
+
+        let (get, set, class_arg) = if is_static {
+            (
+                helper!(
+                    class_static_private_field_spec_get,
+                    "classStaticPrivateFieldSpecGet"
+                ),
+                helper!(
+                    class_static_private_field_spec_set,
+                    "classStaticPrivateFieldSpecSet"
+                ),
+                Some(self.class_name.clone().as_arg()),
+            )
+        } else {
+            (
+                helper!(class_private_field_get, "classPrivateFieldGet"),
+                helper!(class_private_field_set, "classPrivateFieldSet"),
+                None,
+            )
+        };
+
+        let get = box Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: get,
+            args: iter::once(get_obj.as_arg())
+                .chain(class_arg.clone())
+                .chain(iter::once(ident.clone().as_arg()))
+                .collect(),
+            type_args: Default::default(),
+        });
+        let set = box Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: set,
+            args: iter::once(set_obj.as_arg())
+                .chain(class_arg)
+                .chain(iter::once(ident.as_arg()))
+                .chain(iter::once(right.fold_with(self).as_arg()))
+                .collect(),
+            type_args: Default::default(),
+        });
+
+        short_circuit_assign(DUMMY_SP, get, op, set, &mut self.vars)
+    }
+
     fn fold_private_get(
         &mut self,
         e: MemberExpr,
@@ -3,16 +3,19 @@ use crate::{
     pass::Pass,
     util::{
         alias_ident_for, constructor::inject_after_super, prop_name_to_expr_value, undefined,
-        ExprFactory, IdentExt,
+        ExprFactory, IdentExt, HANDLER,
     },
 };
 use ast::*;
 use either::Either;
 use serde::Deserialize;
 use std::iter;
-use swc_common::{Fold, FoldWith, Spanned, Visit, VisitWith, DUMMY_SP};
+use swc_common::{
+    util::move_map::MoveMap, Fold, FoldWith, Spanned, Visit, VisitWith, DUMMY_SP,
+};
 
 mod legacy;
+mod metadata;
 mod usage;
 
 /// ## Simple class decorator
@@ -70,6 +73,15 @@ pub struct Config {
     pub legacy: bool,
 }
 
+/// Adds `design:type`, `design:paramtypes` and `design:returntype` metadata
+/// to decorated classes and members, like `emitDecoratorMetadata` of tsc.
+///
+/// Types are serialized from type annotations, so this should run before
+/// `typescript::strip`. The metadata is applied by legacy decorators.
+pub fn metadata() -> impl Pass {
+    self::metadata::Metadata
+}
+
 #[derive(Debug, Default)]
 struct Decorators {
     is_in_strict: bool,
@@ -134,6 +146,7 @@ impl Fold<Vec<ModuleItem>> for Decorators {
                             }
                             .into()],
                             src: None,
+                            asserts: None,
                         },
                     )));
                 }
@@ -228,6 +241,46 @@ impl Fold<Expr> for Decorators {
     }
 }
 
+impl Fold<Constructor> for Decorators {
+    fn fold(&mut self, c: Constructor) -> Constructor {
+        let c = c.fold_children(self);
+
+        Constructor {
+            params: c.params.move_map(|param| match param {
+                PatOrTsParamProp::Param(p) => {
+                    report_param(&p.decorators);
+                    PatOrTsParamProp::Pat(p.pat)
+                }
+                PatOrTsParamProp::TsParamProp(mut p) => {
+                    report_param(&p.decorators);
+                    p.decorators.clear();
+                    PatOrTsParamProp::TsParamProp(p)
+                }
+                PatOrTsParamProp::Pat(..) => param,
+            }),
+            ..c
+        }
+    }
+}
+
+impl Fold<Function> for Decorators {
+    fn fold(&mut self, f: Function) -> Function {
+        let f = f.fold_children(self);
+
+        let decorators: Vec<_> = f
+            .param_decorators
+            .into_iter()
+            .map(|dec| dec.decorator)
+            .collect();
+        report_param(&decorators);
+
+        Function {
+            param_decorators: vec![],
+            ..f
+        }
+    }
+}
+
 impl Decorators {
     fn fold_class(&self, ident: Ident, mut class: Class) -> Expr {
         let initialize = private_ident!("_initialize");
@@ -377,12 +430,16 @@ impl Decorators {
             }};
         }
 
+        // Private members are kept in the class.
+        let mut private_members = vec![];
+
         let descriptors = class
             .body
             .into_iter()
             .filter_map(|member| {
                 //
                 match member {
+                    ClassMember::Constructor(Constructor { body: None, .. }) => None,
                     ClassMember::Constructor(_) => unreachable!("multiple constructor?"),
                     ClassMember::TsIndexSignature(_) => None,
                     ClassMember::Method(method) => {
@@ -394,25 +451,30 @@ impl Decorators {
                         let key_prop_value = box prop_name_to_expr_value(method.key);
                         fold_method!(method, fn_name, key_prop_value)
                     }
-                    ClassMember::PrivateMethod(method) => {
-                        let fn_name = Ident::new(
-                            format!("_{}", method.key.id.sym).into(),
-                            method.key.id.span,
-                        );
-                        let key_prop_value = box Expr::Lit(Lit::Str(Str {
-                            span: method.key.id.span,
-                            value: method.key.id.sym,
-                            has_escape: false,
-                        }));
-                        fold_method!(method, Some(fn_name), key_prop_value)
+                    ClassMember::PrivateMethod(mut method) => {
+                        report_private(&method.function.decorators);
+                        method.function.decorators.clear();
+                        private_members.push(ClassMember::PrivateMethod(method));
+                        None
+                    }
+                    ClassMember::PrivateProp(mut prop) => {
+                        report_private(&prop.decorators);
+                        prop.decorators.clear();
+                        private_members.push(ClassMember::PrivateProp(prop));
+                        None
+                    }
+                    ClassMember::StaticBlock(block) => {
+                        private_members.push(ClassMember::StaticBlock(block));
+                        None
                     }
                     ClassMember::ClassProp(prop) => {
                         let prop_span = prop.span();
                         let key_prop_value = match *prop.key {
-                            Expr::Ident(i) => box Expr::Lit(Lit::Str(Str {
+                            Expr::Ident(ref i) if !prop.computed => box Expr::Lit(Lit::Str(Str {
                                 span: i.span,
-                                value: i.sym,
+                                value: i.sym.clone(),
                                 has_escape: false,
+                                raw: None,
                             })),
                             _ => prop.key,
                         };
@@ -470,6 +532,7 @@ impl Decorators {
                                             is_async: false,
                                             is_generator: false,
                                             decorators: vec![],
+                                            param_decorators: Default::default(),
                                             params: vec![],
 
                                             body: Some(BlockStmt {
@@ -494,7 +557,6 @@ impl Decorators {
                             .as_arg(),
                         )
                     }
-                    _ => unimplemented!("ClassMember::{:?}", member,),
                 }
             })
             .map(Some)
@@ -514,6 +576,7 @@ impl Decorators {
                             .collect(),
 
                         decorators: Default::default(),
+                        param_decorators: Default::default(),
                         is_async: false,
                         is_generator: false,
 
@@ -530,7 +593,7 @@ impl Decorators {
                                 ident: ident.clone(),
                                 class: Class {
                                     decorators: Default::default(),
-                                    body: vec![constructor],
+                                    body: iter::once(constructor).chain(private_members).collect(),
                                     ..class
                                 },
                                 declare: false,
@@ -591,6 +654,31 @@ fn make_decorate_call(
     }
 }
 
+/// Decorators on private members are not supported.
+fn report_private(decorators: &[Decorator]) {
+    if let Some(dec) = decorators.first() {
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(dec.span, "Decorators are not supported on private members")
+                .emit()
+        });
+    }
+}
+
+/// Parameter decorators are only supported by legacy decorators.
+fn report_param(decorators: &[Decorator]) {
+    if let Some(dec) = decorators.first() {
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    dec.span,
+                    "Parameter decorators are only supported by legacy decorators",
+                )
+                .emit()
+        });
+    }
+}
+
 struct DecoratorFinder {
     found: bool,
 }
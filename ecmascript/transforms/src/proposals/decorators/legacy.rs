@@ -1,4 +1,4 @@
-use super::usage::DecoratorFinder;
+use super::{metadata::is_metadata, report_private, usage::DecoratorFinder};
 use crate::util::{
     alias_if_required, default_constructor, prepend, prop_name_to_expr_value, undefined,
     ExprFactory, ModuleItemLike, StmtLike,
@@ -37,6 +37,7 @@ impl Fold<Module> for Legacy {
                 span: DUMMY_SP,
                 specifiers: replace(&mut self.exports, Default::default()),
                 src: None,
+                asserts: None,
             });
 
             m.body.push(decl.into());
@@ -192,6 +193,8 @@ impl Fold<Decl> for Legacy {
 
 impl Legacy {
     fn handle(&mut self, mut c: ClassExpr) -> Box<Expr> {
+        self.apply_param_decorators(&mut c.class);
+
         let cls_ident = private_ident!("_class");
 
         self.uninitialized_vars.push(VarDeclarator {
@@ -241,11 +244,15 @@ impl Legacy {
 
                 let callee = helper!(apply_decorated_descriptor, "applyDecoratedDescriptor");
 
-                let name = match m.key {
-                    PropName::Computed(..) => {
-                        unimplemented!("decorators on methods with computed key")
+                let (key, name) = match m.key {
+                    PropName::Computed(c) => {
+                        let (expr, name) = self.alias_computed_key(c.expr);
+                        (PropName::Computed(ComputedPropName { expr, ..c }), *name)
+                    }
+                    key => {
+                        let name = prop_name_to_expr_value(key.clone());
+                        (key, name)
                     }
-                    _ => prop_name_to_expr_value(m.key.clone()),
                 };
 
                 extra_exprs.push(box Expr::Call(CallExpr {
@@ -280,6 +287,7 @@ impl Legacy {
                 }));
 
                 Some(ClassMember::Method(ClassMethod {
+                    key,
                     function: Function {
                         decorators: vec![],
                         ..m.function
@@ -288,6 +296,18 @@ impl Legacy {
                 }))
             }
 
+            ClassMember::PrivateMethod(mut m) => {
+                report_private(&m.function.decorators);
+                m.function.decorators.clear();
+                Some(ClassMember::PrivateMethod(m))
+            }
+
+            ClassMember::PrivateProp(mut p) => {
+                report_private(&p.decorators);
+                p.decorators.clear();
+                Some(ClassMember::PrivateProp(p))
+            }
+
             ClassMember::ClassProp(p) if !p.decorators.is_empty() => {
                 let prototype = if p.is_static {
                     cls_ident.clone().as_arg()
@@ -324,14 +344,25 @@ impl Legacy {
                     dec_exprs.push(Some(i.as_arg()))
                 }
 
-                // TODO: Handle s prop name
-                let name = match *p.key {
-                    Expr::Ident(ref i) => box Expr::Lit(Lit::Str(Str {
-                        span: i.span,
-                        value: i.sym.clone(),
-                        has_escape: false,
-                    })),
-                    _ => p.key.clone(),
+                let (key, name) = if p.computed {
+                    let (key, name) = self.alias_computed_key(p.key);
+                    if !p.is_static {
+                        // The property is removed from the class, so the key is
+                        // evaluated here.
+                        extra_exprs.push(key.clone());
+                    }
+                    (key, name)
+                } else {
+                    let name = match *p.key {
+                        Expr::Ident(ref i) => box Expr::Lit(Lit::Str(Str {
+                            span: i.span,
+                            value: i.sym.clone(),
+                            has_escape: false,
+                            raw: None,
+                        })),
+                        _ => p.key.clone(),
+                    };
+                    (p.key, name)
                 };
                 let init = private_ident!("_init");
                 if p.is_static {
@@ -378,6 +409,7 @@ impl Legacy {
                                 ident: None,
                                 function: Function {
                                     decorators: Default::default(),
+                                    param_decorators: Default::default(),
                                     is_generator: false,
                                     is_async: false,
                                     span: DUMMY_SP,
@@ -512,6 +544,7 @@ impl Legacy {
                 if p.is_static {
                     Some(
                         ClassProp {
+                            key,
                             decorators: vec![],
                             value: value.take().unwrap(),
                             ..p
@@ -609,6 +642,159 @@ impl Legacy {
         expr
     }
 
+    /// Stores a computed key in a variable, because it's used multiple times.
+    ///
+    /// Returns the key for the class member and the name used afterwards.
+    fn alias_computed_key(&mut self, key: Box<Expr>) -> (Box<Expr>, Box<Expr>) {
+        match *key {
+            Expr::Lit(..) => return (key.clone(), key),
+            _ => {}
+        }
+
+        let alias = private_ident!("_key");
+        self.uninitialized_vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        (
+            box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Pat(box Pat::Ident(alias.clone())),
+                op: op!("="),
+                right: key,
+            }),
+            box Expr::Ident(alias),
+        )
+    }
+
+    /// Applies decorators of constructor parameters as class decorators.
+    ///
+    /// `@dec` of the first parameter is applied as
+    /// `function (target) { dec(target, undefined, 0); return target; }`.
+    fn apply_param_decorators(&mut self, class: &mut Class) {
+        for member in &mut class.body {
+            let function = match *member {
+                ClassMember::Method(ref mut m) => &mut m.function,
+                ClassMember::PrivateMethod(ref mut m) => &mut m.function,
+                _ => continue,
+            };
+
+            let decorators = replace(&mut function.param_decorators, vec![])
+                .into_iter()
+                .map(|dec| self.param_decorator(dec.decorator, dec.index, true))
+                .collect();
+            insert_param_decorators(&mut function.decorators, decorators);
+        }
+
+        let constructor = class.body.iter_mut().find_map(|m| match *m {
+            ClassMember::Constructor(ref mut c) => Some(c),
+            _ => None,
+        });
+        let constructor = match constructor {
+            Some(c) => c,
+            None => return,
+        };
+
+        let mut decorators = vec![];
+        constructor.params = replace(&mut constructor.params, vec![])
+            .into_iter()
+            .enumerate()
+            .map(|(index, param)| match param {
+                PatOrTsParamProp::TsParamProp(mut p) => {
+                    decorators.extend(p.decorators.drain(..).map(|dec| (dec, index)));
+                    PatOrTsParamProp::TsParamProp(p)
+                }
+                PatOrTsParamProp::Param(p) => {
+                    decorators.extend(p.decorators.into_iter().map(|dec| (dec, index)));
+                    PatOrTsParamProp::Pat(p.pat)
+                }
+                PatOrTsParamProp::Pat(..) => param,
+            })
+            .collect();
+
+        let decorators = decorators
+            .into_iter()
+            .map(|(dec, index)| self.param_decorator(dec, index, false))
+            .collect();
+        insert_param_decorators(&mut class.decorators, decorators);
+    }
+
+    /// Creates `function (target, key) { dec(target, key, index); }` for a
+    /// parameter of a method, and
+    /// `function (target) { dec(target, undefined, index); return target; }`
+    /// for a parameter of the constructor.
+    fn param_decorator(&mut self, dec: Decorator, index: usize, is_method: bool) -> Decorator {
+        let (i, aliased) = alias_if_required(&dec.expr, "_dec");
+        if aliased {
+            self.initialized_vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(i.clone()),
+                init: Some(dec.expr),
+                definite: false,
+            });
+        }
+
+        let target = private_ident!("target");
+        let key = if is_method {
+            Some(private_ident!("key"))
+        } else {
+            None
+        };
+
+        let mut params = vec![Pat::Ident(target.clone())];
+        params.extend(key.clone().map(Pat::Ident));
+
+        let mut stmts = vec![CallExpr {
+            span: DUMMY_SP,
+            callee: i.as_callee(),
+            args: vec![
+                target.clone().as_arg(),
+                match key {
+                    Some(key) => key.as_arg(),
+                    None => undefined(DUMMY_SP).as_arg(),
+                },
+                Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: index as f64,
+                    raw: None,
+                })
+                .as_arg(),
+            ],
+            type_args: None,
+        }
+        .into_stmt()];
+        if !is_method {
+            stmts.push(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(box Expr::Ident(target)),
+            }));
+        }
+
+        Decorator {
+            span: dec.span,
+            expr: box Expr::Fn(FnExpr {
+                ident: None,
+                function: Function {
+                    span: DUMMY_SP,
+                    is_async: false,
+                    is_generator: false,
+                    decorators: Default::default(),
+                    param_decorators: Default::default(),
+                    params,
+                    body: Some(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts,
+                    }),
+                    type_params: Default::default(),
+                    return_type: Default::default(),
+                },
+            }),
+        }
+    }
+
     fn apply(&mut self, mut expr: Box<Expr>, decorators: Vec<Decorator>) -> Box<Expr> {
         for dec in decorators.into_iter().rev() {
             let (i, aliased) = alias_if_required(&dec.expr, "_dec");
@@ -632,3 +818,15 @@ impl Legacy {
         expr
     }
 }
+
+/// Like tsc, parameter decorators are applied after other decorators and
+/// before metadata.
+fn insert_param_decorators(decorators: &mut Vec<Decorator>, param_decorators: Vec<Decorator>) {
+    let pos = decorators
+        .iter()
+        .position(is_metadata)
+        .unwrap_or_else(|| decorators.len());
+    for (i, dec) in param_decorators.into_iter().enumerate() {
+        decorators.insert(pos + i, dec);
+    }
+}
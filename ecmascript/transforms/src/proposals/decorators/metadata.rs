@@ -0,0 +1,299 @@
+use crate::util::{undefined, ExprFactory};
+use ast::*;
+use swc_common::{Fold, FoldWith, DUMMY_SP};
+
+pub(super) struct Metadata;
+
+impl Fold<Class> for Metadata {
+    fn fold(&mut self, class: Class) -> Class {
+        let mut class = class.fold_children(self);
+        add_metadata(&mut class);
+
+        class
+    }
+}
+
+/// Returns true if `dec` is added by [add_metadata].
+pub(super) fn is_metadata(dec: &Decorator) -> bool {
+    if !dec.span.is_dummy() {
+        return false;
+    }
+
+    match *dec.expr {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(box Expr::Member(ref callee)),
+            ..
+        }) => match (&callee.obj, &*callee.prop) {
+            (ExprOrSuper::Expr(box Expr::Ident(ref obj)), Expr::Ident(ref prop)) => {
+                &*obj.sym == "Reflect" && &*prop.sym == "metadata"
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Appends `Reflect.metadata("design:*", ...)` decorators to decorated members
+/// of `class`, like `emitDecoratorMetadata` of tsc.
+///
+/// Types are serialized from type annotations, so this must be called before
+/// they are stripped.
+fn add_metadata(class: &mut Class) {
+    if !class.decorators.is_empty() || has_param_decorators(class) {
+        let constructor = class.body.iter().find_map(|m| match *m {
+            ClassMember::Constructor(ref c) => Some(c),
+            _ => None,
+        });
+
+        if let Some(c) = constructor {
+            let types = c
+                .params
+                .iter()
+                .map(|p| match *p {
+                    PatOrTsParamProp::Pat(ref p)
+                    | PatOrTsParamProp::Param(Param { pat: ref p, .. }) => {
+                        serialize_type(pat_type(p))
+                    }
+                    PatOrTsParamProp::TsParamProp(ref p) => serialize_type(match p.param {
+                        TsParamPropParam::Ident(ref i) => type_of(&i.type_ann),
+                        TsParamPropParam::Assign(ref p) => assign_pat_type(p),
+                    }),
+                })
+                .collect();
+            let dec = metadata("design:paramtypes", type_list(types));
+            class.decorators.push(dec);
+        }
+    }
+
+    for member in &mut class.body {
+        match *member {
+            ClassMember::Method(ref mut m)
+                if !m.function.decorators.is_empty() || !m.function.param_decorators.is_empty() =>
+            {
+                let f = &mut m.function;
+                let param_types = f
+                    .params
+                    .iter()
+                    .map(|p| serialize_type(pat_type(p)))
+                    .collect::<Vec<_>>();
+
+                let decs = match m.kind {
+                    MethodKind::Method => vec![
+                        metadata("design:type", quote_ident!("Function").into()),
+                        metadata("design:paramtypes", type_list(param_types)),
+                        metadata("design:returntype", return_type(f)),
+                    ],
+                    MethodKind::Getter => vec![metadata(
+                        "design:type",
+                        serialize_type(type_of(&f.return_type)),
+                    )],
+                    MethodKind::Setter => vec![
+                        metadata(
+                            "design:type",
+                            param_types
+                                .first()
+                                .cloned()
+                                .unwrap_or_else(|| quote_ident!("Object").into()),
+                        ),
+                        metadata("design:paramtypes", type_list(param_types)),
+                    ],
+                };
+                f.decorators.extend(decs);
+            }
+
+            ClassMember::ClassProp(ref mut p) if !p.decorators.is_empty() => {
+                let dec = metadata("design:type", serialize_type(type_of(&p.type_ann)));
+                p.decorators.push(dec);
+            }
+
+            _ => {}
+        }
+    }
+}
+
+/// Returns true if a parameter of the constructor has decorators.
+fn has_param_decorators(class: &Class) -> bool {
+    class.body.iter().any(|m| match *m {
+        ClassMember::Constructor(ref c) => c.params.iter().any(|p| match *p {
+            PatOrTsParamProp::Param(ref p) => !p.decorators.is_empty(),
+            PatOrTsParamProp::TsParamProp(ref p) => !p.decorators.is_empty(),
+            PatOrTsParamProp::Pat(..) => false,
+        }),
+        _ => false,
+    })
+}
+
+/// `Reflect.metadata(key, value)`
+fn metadata(key: &str, value: Expr) -> Decorator {
+    Decorator {
+        span: DUMMY_SP,
+        expr: box Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: member_expr!(DUMMY_SP, Reflect.metadata).as_callee(),
+            args: vec![Lit::Str(quote_str!(key)).as_arg(), value.as_arg()],
+            type_args: Default::default(),
+        }),
+    }
+}
+
+fn type_list(types: Vec<Expr>) -> Expr {
+    Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: types.into_iter().map(|ty| Some(ty.as_arg())).collect(),
+    })
+}
+
+fn return_type(f: &Function) -> Expr {
+    match f.return_type {
+        Some(ref ty) => serialize_type(Some(&ty.type_ann)),
+        None if f.is_async => quote_ident!("Promise").into(),
+        None => *undefined(DUMMY_SP),
+    }
+}
+
+fn type_of(ty: &Option<TsTypeAnn>) -> Option<&TsType> {
+    ty.as_ref().map(|ty| &*ty.type_ann)
+}
+
+fn pat_type(p: &Pat) -> Option<&TsType> {
+    match *p {
+        Pat::Ident(ref i) => type_of(&i.type_ann),
+        Pat::Array(ArrayPat { ref type_ann, .. })
+        | Pat::Object(ObjectPat { ref type_ann, .. }) => type_of(type_ann),
+        Pat::Rest(RestPat {
+            ref type_ann,
+            ref arg,
+            ..
+        }) => type_of(type_ann).or_else(|| pat_type(arg)),
+        Pat::Assign(ref p) => assign_pat_type(p),
+        _ => None,
+    }
+}
+
+fn assign_pat_type(p: &AssignPat) -> Option<&TsType> {
+    type_of(&p.type_ann).or_else(|| pat_type(&p.left))
+}
+
+/// Serializes a type to the runtime value used by metadata decorators.
+fn serialize_type(ty: Option<&TsType>) -> Expr {
+    let ty = match ty {
+        Some(ty) => ty,
+        None => return quote_ident!("Object").into(),
+    };
+
+    match *ty {
+        TsType::TsKeywordType(TsKeywordType { kind, .. }) => match kind {
+            TsKeywordTypeKind::TsVoidKeyword
+            | TsKeywordTypeKind::TsUndefinedKeyword
+            | TsKeywordTypeKind::TsNullKeyword
+            | TsKeywordTypeKind::TsNeverKeyword => *undefined(DUMMY_SP),
+            TsKeywordTypeKind::TsStringKeyword => quote_ident!("String").into(),
+            TsKeywordTypeKind::TsNumberKeyword => quote_ident!("Number").into(),
+            TsKeywordTypeKind::TsBooleanKeyword => quote_ident!("Boolean").into(),
+            TsKeywordTypeKind::TsBigIntKeyword => quote_ident!("BigInt").into(),
+            TsKeywordTypeKind::TsSymbolKeyword => quote_ident!("Symbol").into(),
+            TsKeywordTypeKind::TsAnyKeyword
+            | TsKeywordTypeKind::TsUnknownKeyword
+            | TsKeywordTypeKind::TsObjectKeyword => quote_ident!("Object").into(),
+        },
+
+        TsType::TsLitType(TsLitType { ref lit, .. }) => match *lit {
+            TsLit::Number(..) => quote_ident!("Number").into(),
+            TsLit::Str(..) => quote_ident!("String").into(),
+            TsLit::Bool(..) => quote_ident!("Boolean").into(),
+        },
+
+        TsType::TsArrayType(..) | TsType::TsTupleType(..) => quote_ident!("Array").into(),
+        TsType::TsFnOrConstructorType(..) => quote_ident!("Function").into(),
+
+        TsType::TsParenthesizedType(TsParenthesizedType { ref type_ann, .. }) => {
+            serialize_type(Some(type_ann))
+        }
+
+        // typeof Foo === "undefined" ? Object : Foo
+        TsType::TsTypeRef(TsTypeRef { ref type_name, .. }) => Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: box is_undefined(type_name),
+            cons: box quote_ident!("Object").into(),
+            alt: box entity_name_to_expr(type_name),
+        }),
+
+        TsType::TsUnionOrIntersectionType(ref ty) => {
+            let types = match *ty {
+                TsUnionOrIntersectionType::TsUnionType(ref u) => &u.types,
+                TsUnionOrIntersectionType::TsIntersectionType(ref i) => &i.types,
+            };
+            serialize_type_list(types)
+        }
+
+        _ => quote_ident!("Object").into(),
+    }
+}
+
+/// Members of unions and intersections are serialized only if all of them
+/// serialize to the same constructor. `null`, `undefined` and `never` are
+/// ignored.
+fn serialize_type_list(types: &[Box<TsType>]) -> Expr {
+    let mut serialized: Option<Ident> = None;
+
+    for ty in types {
+        match **ty {
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNullKeyword,
+                ..
+            })
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsUndefinedKeyword,
+                ..
+            })
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNeverKeyword,
+                ..
+            }) => continue,
+            _ => {}
+        }
+
+        match serialize_type(Some(ty)) {
+            Expr::Ident(i) => match serialized {
+                Some(ref prev) if prev.sym != i.sym => return quote_ident!("Object").into(),
+                Some(..) => {}
+                None => serialized = Some(i),
+            },
+            _ => return quote_ident!("Object").into(),
+        }
+    }
+
+    match serialized {
+        Some(i) => i.into(),
+        None => *undefined(DUMMY_SP),
+    }
+}
+
+/// `typeof Foo === "undefined"`, or
+/// `typeof Foo === "undefined" || typeof Foo.Bar === "undefined"`
+fn is_undefined(n: &TsEntityName) -> Expr {
+    let check = Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("typeof"),
+        arg: box entity_name_to_expr(n),
+    })
+    .make_eq(Lit::Str(quote_str!("undefined")));
+
+    match *n {
+        TsEntityName::Ident(..) => check,
+        TsEntityName::TsQualifiedName(ref q) => is_undefined(&q.left).make_bin(op!("||"), check),
+    }
+}
+
+fn entity_name_to_expr(n: &TsEntityName) -> Expr {
+    match *n {
+        TsEntityName::Ident(ref i) => Ident::new(i.sym.clone(), i.span).into(),
+        TsEntityName::TsQualifiedName(ref q) => MemberExpr {
+            span: DUMMY_SP,
+            obj: ExprOrSuper::Expr(box entity_name_to_expr(&q.left)),
+            prop: box Ident::new(q.right.sym.clone(), q.right.span).into(),
+            computed: false,
+        }
+        .into(),
+    }
+}
@@ -5,11 +5,25 @@ use swc_common::{Fold, DUMMY_SP};
 /// `@babel/plugin-proposal-export-default-from` and
 /// `@babel/plugin-proposal-export-namespace-from`
 pub fn export() -> impl Pass {
-    ExportDefaultFrom
+    export_with_config(Default::default())
+}
+
+pub fn export_with_config(config: Config) -> impl Pass {
+    ExportDefaultFrom { config }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Config {
+    /// Keep `export * as ns from 'mod';`, which is valid since ES2020.
+    ///
+    /// `export * as ns, { a } from 'mod';` is still split into two exports.
+    pub keep_namespace: bool,
 }
 
 #[derive(Clone)]
-struct ExportDefaultFrom;
+struct ExportDefaultFrom {
+    config: Config,
+}
 
 impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
     fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
@@ -31,6 +45,16 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                     }
 
                     match export.specifiers.remove(0) {
+                        ExportSpecifier::Namespace(ns) if self.config.keep_namespace => {
+                            extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                                NamedExport {
+                                    span: export.span,
+                                    specifiers: vec![ExportSpecifier::Namespace(ns)],
+                                    src: export.src.clone(),
+                                    asserts: export.asserts.clone(),
+                                },
+                            )));
+                        }
                         ExportSpecifier::Default(DefaultExportSpecifier { exported: default }) => {
                             let local = default.prefix("_").private();
 
@@ -44,6 +68,8 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
+                                asserts: export.asserts.clone(),
+                                type_only: false,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -56,6 +82,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                         },
                                     )],
                                     src: None,
+                                    asserts: None,
                                 },
                             )));
                         }
@@ -72,6 +99,8 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .src
                                     .clone()
                                     .expect("`export default from` requires source"),
+                                asserts: export.asserts.clone(),
+                                type_only: false,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -84,6 +113,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                         },
                                     )],
                                     src: None,
+                                    asserts: None,
                                 },
                             )));
                         }
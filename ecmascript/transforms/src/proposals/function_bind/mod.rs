@@ -0,0 +1,164 @@
+use crate::{
+    pass::Pass,
+    util::{alias_ident_for, fold_stmts_hoisting_vars, is_literal, ExprFactory, StmtLike},
+};
+use ast::*;
+use std::iter;
+use swc_common::{Fold, FoldWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-function-bind`
+///
+/// `obj::func` => `func.bind(obj)`
+///
+/// `::obj.func(a)` => `obj.func.call(obj, a)`
+pub fn function_bind() -> impl Pass + 'static {
+    FnBind::default()
+}
+
+#[derive(Debug, Default)]
+struct FnBind {
+    vars: Vec<VarDeclarator>,
+}
+
+impl<T> Fold<Vec<T>> for FnBind
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        fold_stmts_hoisting_vars(self, stmts, |f| &mut f.vars)
+    }
+}
+
+impl FnBind {
+    /// Returns `(alias, alias = expr)`.
+    fn memoize(&mut self, expr: Box<Expr>) -> (Ident, Box<Expr>) {
+        let alias = alias_ident_for(&expr, "_context");
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        let init = box Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(box Pat::Ident(alias.clone())),
+            right: expr,
+        });
+
+        (alias, init)
+    }
+
+    /// Returns `(func, context)`. `func` is evaluated before `context`.
+    fn split(&mut self, e: BindExpr) -> (Box<Expr>, Box<Expr>) {
+        match e.object {
+            // obj::func
+            Some(object) => {
+                let is_pure = is_literal(&*object)
+                    || match (&*object, &*e.callee) {
+                        (Expr::Ident(..), Expr::Ident(..)) => true,
+                        _ => false,
+                    };
+                if is_pure {
+                    return (e.callee, object);
+                }
+
+                // (_context = obj, func)
+                let (alias, init) = self.memoize(object);
+                (
+                    box Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs: vec![init, e.callee],
+                    }),
+                    box Expr::Ident(alias),
+                )
+            }
+
+            // ::obj.func
+            None => match *e.callee {
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: ExprOrSuper::Expr(obj),
+                    prop,
+                    computed,
+                }) => {
+                    let is_simple = match *obj {
+                        Expr::Ident(..) | Expr::This(..) => true,
+                        _ => false,
+                    };
+                    let (obj, context) = if is_simple {
+                        (obj.clone(), obj)
+                    } else {
+                        let (alias, init) = self.memoize(obj);
+                        (init, box Expr::Ident(alias))
+                    };
+
+                    (
+                        box Expr::Member(MemberExpr {
+                            span,
+                            obj: ExprOrSuper::Expr(obj),
+                            prop,
+                            computed,
+                        }),
+                        context,
+                    )
+                }
+
+                // ::super.func
+                Expr::Member(member @ MemberExpr {
+                    obj: ExprOrSuper::Super(..),
+                    ..
+                }) => (
+                    box Expr::Member(member),
+                    box Expr::This(ThisExpr { span: DUMMY_SP }),
+                ),
+
+                _ => unreachable!("callee of `::obj.func` should be a member expression"),
+            },
+        }
+    }
+}
+
+impl Fold<Expr> for FnBind {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            // obj::func(a) => func.call(obj, a)
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(box Expr::Bind(bind)),
+                args,
+                type_args,
+            }) => {
+                let bind = bind.fold_children(self);
+                let args = args.fold_with(self);
+                let (func, context) = self.split(bind);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: func.member(quote_ident!("call")).as_callee(),
+                    args: iter::once(context.as_arg()).chain(args).collect(),
+                    type_args,
+                })
+            }
+
+            Expr::Bind(bind) => {
+                let span = bind.span;
+                let bind = bind.fold_children(self);
+                let (func, context) = self.split(bind);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: func.member(quote_ident!("bind")).as_callee(),
+                    args: vec![context.as_arg()],
+                    type_args: Default::default(),
+                })
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+}
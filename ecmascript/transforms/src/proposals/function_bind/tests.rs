@@ -0,0 +1,76 @@
+use super::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn tr(_: ()) -> impl Pass {
+    function_bind()
+}
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        fn_bind: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(()),
+    bind,
+    "obj::func; obj::func(a, b);",
+    "
+func.bind(obj);
+func.call(obj, a, b);
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    bind_complex,
+    "getObj()::obj.func;",
+    "
+var _context;
+(_context = getObj(), obj.func).bind(_context);
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    unary,
+    "::obj.func; ::obj.func(a); ::getObj().func;",
+    "
+obj.func.bind(obj);
+obj.func.call(obj, a);
+var _context;
+(_context = getObj()).func.bind(_context);
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(()),
+    evaluation_order_exec,
+    r#"
+var log = [];
+var obj = {
+    value: 1,
+    get() {
+        return this.value;
+    },
+};
+function getObj() {
+    log.push("obj");
+    return obj;
+}
+function getValue() {
+    log.push("func");
+    return this.value;
+}
+
+expect(getObj()::getValue()).toBe(1);
+expect(log).toEqual(["obj", "func"]);
+expect((::getObj().get)()).toBe(1);
+expect([obj, obj].map(::obj.get)).toEqual([1, 1]);
+"#
+);
@@ -0,0 +1,148 @@
+use crate::{
+    pass::Pass,
+    util::{
+        alias_if_required, fold_stmts_hoisting_vars, is_literal, short_circuit_assign, StmtLike,
+    },
+};
+use ast::*;
+use swc_common::{Fold, FoldWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-logical-assignment-operators`
+///
+/// `a.b ||= c` => `a.b || (a.b = c)`
+pub fn logical_assignment() -> impl Pass + 'static {
+    LogicalAssignment::default()
+}
+
+#[derive(Debug, Default)]
+struct LogicalAssignment {
+    vars: Vec<VarDeclarator>,
+}
+
+impl<T> Fold<Vec<T>> for LogicalAssignment
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        fold_stmts_hoisting_vars(self, stmts, |f| &mut f.vars)
+    }
+}
+
+impl LogicalAssignment {
+    /// Returns `alias` and `alias = expr` if `expr` should be evaluated only
+    /// once.
+    fn memoize(&mut self, expr: Box<Expr>, default: &str) -> (Box<Expr>, Box<Expr>) {
+        let (alias, aliased) = alias_if_required(&expr, default);
+        if !aliased {
+            return (expr.clone(), expr);
+        }
+
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        (
+            box Expr::Ident(alias.clone()),
+            box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(box Pat::Ident(alias)),
+                right: expr,
+            }),
+        )
+    }
+
+    /// Returns `(getter, target)`. Object and computed key of member
+    /// expressions are evaluated only by the getter.
+    fn split_target(&mut self, left: PatOrExpr) -> (Box<Expr>, PatOrExpr) {
+        let left = match left {
+            PatOrExpr::Expr(e) | PatOrExpr::Pat(box Pat::Expr(e)) => e,
+            PatOrExpr::Pat(box Pat::Ident(i)) => box Expr::Ident(i),
+            PatOrExpr::Pat(p) => unreachable!("invalid target of logical assignment: {:?}", p),
+        };
+
+        match *left {
+            Expr::Member(MemberExpr {
+                span,
+                obj,
+                prop,
+                computed,
+            }) => {
+                let (get_obj, set_obj) = match obj {
+                    ExprOrSuper::Expr(obj) => {
+                        let (alias, init) = self.memoize(obj, "_ref");
+                        (ExprOrSuper::Expr(init), ExprOrSuper::Expr(alias))
+                    }
+                    ExprOrSuper::Super(..) => (obj.clone(), obj),
+                };
+                let (get_prop, set_prop) = if computed && !is_literal(&*prop) {
+                    let (alias, init) = self.memoize(prop, "_ref");
+                    (init, alias)
+                } else {
+                    (prop.clone(), prop)
+                };
+
+                (
+                    box Expr::Member(MemberExpr {
+                        span,
+                        obj: get_obj,
+                        prop: get_prop,
+                        computed,
+                    }),
+                    PatOrExpr::Expr(box Expr::Member(MemberExpr {
+                        span,
+                        obj: set_obj,
+                        prop: set_prop,
+                        computed,
+                    })),
+                )
+            }
+            _ => (left.clone(), PatOrExpr::Expr(left)),
+        }
+    }
+}
+
+impl Fold<Expr> for LogicalAssignment {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Assign(AssignExpr {
+                span,
+                op,
+                left,
+                right,
+            }) => {
+                match op {
+                    op!("&&=") | op!("||=") | op!("??=") => {}
+                    _ => {
+                        return Expr::Assign(AssignExpr {
+                            span,
+                            op,
+                            left,
+                            right,
+                        })
+                    }
+                }
+
+                let (get, left) = self.split_target(left);
+                let assign = box Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left,
+                    right,
+                });
+
+                short_circuit_assign(span, get, op, assign, &mut self.vars)
+            }
+
+            _ => e,
+        }
+    }
+}
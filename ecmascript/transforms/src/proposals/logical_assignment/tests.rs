@@ -0,0 +1,80 @@
+use super::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn tr(_: ()) -> impl Pass {
+    logical_assignment()
+}
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        logical_assignment: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(()),
+    ident,
+    "a &&= b; a ||= b; a ??= b;",
+    "
+a && (a = b);
+a || (a = b);
+a !== null && a !== void 0 ? a : a = b;
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    member,
+    "obj.a ||= 1; foo().a &&= 1; obj[key()] ||= 1;",
+    "
+obj.a || (obj.a = 1);
+var _ref;
+(_ref = foo()).a && (_ref.a = 1);
+var _ref1;
+obj[_ref1 = key()] || (obj[_ref1] = 1);
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(()),
+    runtime_semantics_exec,
+    r#"
+var count = 0;
+var obj = {};
+function getObj() {
+    count++;
+    return obj;
+}
+
+getObj().a ||= 1;
+getObj().a ||= 2;
+expect(obj.a).toBe(1);
+
+getObj().a &&= 3;
+expect(obj.a).toBe(3);
+
+getObj()["b"] ??= 4;
+getObj()["b"] ??= 5;
+expect(obj.b).toBe(4);
+expect(count).toBe(5);
+
+var keys = 0;
+function key() {
+    keys++;
+    return "c";
+}
+obj[key()] ??= null;
+obj[key()] ??= 6;
+expect(obj.c).toBe(6);
+expect(keys).toBe(2);
+
+var x = 0;
+x ||= 7;
+x &&= 8;
+expect(x).toBe(8);
+"#
+);
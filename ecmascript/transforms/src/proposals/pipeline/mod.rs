@@ -0,0 +1,88 @@
+use crate::{
+    pass::Pass,
+    util::{alias_ident_for, fold_stmts_hoisting_vars, is_literal, ExprFactory, StmtLike},
+};
+use ast::*;
+use swc_common::{Fold, FoldWith, DUMMY_SP};
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-pipeline-operator`
+///
+/// `a |> f` => `f(a)`
+pub fn pipeline() -> impl Pass + 'static {
+    Pipeline::default()
+}
+
+#[derive(Debug, Default)]
+struct Pipeline {
+    vars: Vec<VarDeclarator>,
+}
+
+impl<T> Fold<Vec<T>> for Pipeline
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        fold_stmts_hoisting_vars(self, stmts, |f| &mut f.vars)
+    }
+}
+
+impl Fold<Expr> for Pipeline {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Pipeline(PipelineExpr { span, left, right }) => {
+                // The topic does not need a temporary if evaluating the function
+                // cannot change it.
+                let is_pure = is_literal(&*left)
+                    || match (&*left, &*right) {
+                        (Expr::Ident(..), Expr::Ident(..)) => true,
+                        _ => false,
+                    };
+                if is_pure {
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: ExprOrSuper::Expr(right),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: left,
+                        }],
+                        type_args: Default::default(),
+                    });
+                }
+
+                // (_ref = left, right(_ref))
+                let alias = alias_ident_for(&left, "_ref");
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(alias.clone()),
+                    init: None,
+                    definite: false,
+                });
+
+                Expr::Seq(SeqExpr {
+                    span,
+                    exprs: vec![
+                        box Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: PatOrExpr::Pat(box Pat::Ident(alias.clone())),
+                            right: left,
+                        }),
+                        box Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: ExprOrSuper::Expr(right),
+                            args: vec![alias.as_arg()],
+                            type_args: Default::default(),
+                        }),
+                    ],
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
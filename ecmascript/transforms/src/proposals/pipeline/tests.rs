@@ -0,0 +1,75 @@
+use super::*;
+use swc_ecma_parser::{EsConfig, PipelineProposal, Syntax};
+
+fn tr(_: ()) -> impl Pass {
+    pipeline()
+}
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: Some(PipelineProposal::Minimal),
+        ..Default::default()
+    })
+}
+
+test!(syntax(), |_| tr(()), simple, "a |> f;", "f(a);");
+
+test!(
+    syntax(),
+    |_| tr(()),
+    chained,
+    "1 |> f |> g;",
+    "
+var _ref;
+_ref = f(1), g(_ref);
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    member,
+    "x |> obj.method;",
+    "
+var _x;
+_x = x, obj.method(_x);
+"
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        pipeline_operator: Some(PipelineProposal::Fsharp),
+        ..Default::default()
+    }),
+    |_| tr(()),
+    fsharp_await,
+    "async function foo() { return url |> fetch |> await; }",
+    "
+async function foo() {
+    return await fetch(url);
+}
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(()),
+    evaluation_order_exec,
+    r#"
+var log = [];
+function topic() {
+    log.push("topic");
+    return 2;
+}
+function fn() {
+    log.push("fn");
+    return function(x) {
+        return x * 3;
+    };
+}
+
+var result = topic() |> fn() |> (x => x + 1);
+expect(result).toBe(7);
+expect(log).toEqual(["topic", "fn"]);
+"#
+);
@@ -1,6 +1,6 @@
 pub use self::{
     display_name::display_name,
-    jsx::{jsx, Options},
+    jsx::{jsx, Options, Runtime},
     jsx_self::jsx_self,
     jsx_src::jsx_src,
 };
@@ -20,9 +20,9 @@ pub fn react(cm: Arc<SourceMap>, options: Options) -> impl Pass {
     let Options { development, .. } = options;
 
     chain!(
-        jsx(options),
-        display_name(),
         jsx_src(development, cm),
-        jsx_self(development)
+        jsx_self(development),
+        jsx(options),
+        display_name()
     )
 }
@@ -24,6 +24,7 @@ impl Fold<VarDeclarator> for DisplayName {
                         span: ident.span,
                         value: ident.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
 
@@ -45,6 +46,7 @@ impl Fold<ModuleDecl> for DisplayName {
                         span: DUMMY_SP,
                         value: "input".into(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 }))
             }
@@ -77,6 +79,7 @@ impl Fold<AssignExpr> for DisplayName {
                         span: prop.span,
                         value: prop.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
                 AssignExpr { right, ..expr }
@@ -89,6 +92,7 @@ impl Fold<AssignExpr> for DisplayName {
                         span: ident.span,
                         value: ident.sym.clone(),
                         has_escape: false,
+                        raw: None,
                     }))),
                 });
 
@@ -111,9 +115,10 @@ impl Fold<Prop> for DisplayName {
                             span: i.span,
                             value: i.sym.clone(),
                             has_escape: false,
+                            raw: None,
                         })),
                         PropName::Str(ref s) => box Expr::Lit(Lit::Str(s.clone())),
-                        PropName::Num(n) => box Expr::Lit(Lit::Num(n)),
+                        PropName::Num(ref n) => box Expr::Lit(Lit::Num(n.clone())),
                         PropName::Computed(ref c) => c.expr.clone(),
                     }),
                 });
@@ -3,7 +3,7 @@ use crate::{
     util::{
         drop_span,
         options::{CM, SESSION},
-        ExprFactory, HANDLER,
+        prepend_stmts, undefined, ExprFactory, COMMENTS, HANDLER,
     },
 };
 use ast::*;
@@ -13,7 +13,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{iter, mem, sync::Arc};
 use swc_atoms::{js_word, JsWord};
-use swc_common::{iter::IdentifyLast, FileName, Fold, FoldWith, Spanned, DUMMY_SP};
+use swc_common::{iter::IdentifyLast, FileName, Fold, FoldWith, Span, Spanned, DUMMY_SP};
 use swc_ecma_parser::{Parser, SourceFileInput, Syntax};
 
 #[cfg(test)]
@@ -35,6 +35,16 @@ pub struct Options {
 
     #[serde(default)]
     pub use_builtins: bool,
+
+    /// Can be overriden by a `@jsxRuntime` comment.
+    #[serde(default)]
+    pub runtime: Runtime,
+
+    /// Module which provides `jsx-runtime` for the automatic runtime.
+    ///
+    /// Can be overriden by a `@jsxImportSource` comment.
+    #[serde(default = "default_import_source")]
+    pub import_source: String,
 }
 
 impl Default for Options {
@@ -45,10 +55,28 @@ impl Default for Options {
             throw_if_namespace: default_throw_if_namespace(),
             development: false,
             use_builtins: false,
+            runtime: Default::default(),
+            import_source: default_import_source(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Runtime {
+    /// `React.createElement(type, props, ...children)`
+    Classic,
+    /// `_jsx(type, { ...props, children }, key)`, with `_jsx` imported from
+    /// `<importSource>/jsx-runtime`.
+    Automatic,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Runtime::Classic
+    }
+}
+
 fn default_pragma() -> String {
     "React.createElement".into()
 }
@@ -61,14 +89,18 @@ fn default_throw_if_namespace() -> bool {
     true
 }
 
-fn parse_option(name: &str, src: String) -> Box<Expr> {
+fn default_import_source() -> String {
+    "react".into()
+}
+
+fn parse_option(name: &str, src: String) -> Result<Box<Expr>, ()> {
     lazy_static! {
         static ref CACHE: CHashMap<Arc<String>, Box<Expr>> = CHashMap::with_capacity(2);
     }
 
     let fm = CM.new_source_file(FileName::Custom(format!("<jsx-config-{}.js>", name)), src);
     if let Some(expr) = CACHE.get(&fm.src) {
-        return expr.clone();
+        return Ok(expr.clone());
     }
 
     let expr = Parser::new(
@@ -79,19 +111,35 @@ fn parse_option(name: &str, src: String) -> Box<Expr> {
     )
     .parse_expr()
     .map_err(|mut e| {
-        e.emit();
+        e.cancel();
     })
-    .map(drop_span)
-    .unwrap_or_else(|()| {
-        panic!(
-            "faield to parse jsx option {}: '{}' is not an expression",
-            name, fm.src,
-        )
-    });
+    .map(drop_span)?;
 
     CACHE.insert(fm.src.clone(), expr.clone());
 
-    expr
+    Ok(expr)
+}
+
+/// Parses `pragma` or `pragmaFrag` from [Options].
+fn parse_option_or_panic(name: &str, src: String) -> Box<Expr> {
+    parse_option(name, src.clone()).unwrap_or_else(|()| {
+        panic!(
+            "failed to parse jsx option {}: '{}' is not an expression",
+            name, src
+        )
+    })
+}
+
+/// Parses the value of a `@jsx` or `@jsxFrag` comment, reporting it if it's
+/// not an expression.
+fn parse_pragma(span: Span, name: &str, value: &str) -> Result<Box<Expr>, ()> {
+    parse_option(name, value.into()).map_err(|()| {
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(span, &format!("@{}: '{}' is not an expression", name, value))
+                .emit()
+        })
+    })
 }
 
 /// `@babel/plugin-transform-react-jsx`
@@ -99,13 +147,20 @@ fn parse_option(name: &str, src: String) -> Box<Expr> {
 /// Turn JSX into React function calls
 pub fn jsx(options: Options) -> impl Pass {
     Jsx {
-        pragma: ExprOrSuper::Expr(parse_option("pragma", options.pragma)),
+        pragma: ExprOrSuper::Expr(parse_option_or_panic("pragma", options.pragma)),
         pragma_frag: ExprOrSpread {
             spread: None,
-            expr: parse_option("pragmaFrag", options.pragma_frag),
+            expr: parse_option_or_panic("pragmaFrag", options.pragma_frag),
         },
         use_builtins: options.use_builtins,
         throw_if_namespace: options.throw_if_namespace,
+        development: options.development,
+        runtime: options.runtime,
+        import_source: options.import_source.into(),
+        import_jsx: None,
+        import_jsxs: None,
+        import_fragment: None,
+        import_create_element: None,
     }
 }
 
@@ -114,12 +169,201 @@ struct Jsx {
     pragma_frag: ExprOrSpread,
     use_builtins: bool,
     throw_if_namespace: bool,
+    development: bool,
+
+    runtime: Runtime,
+    import_source: JsWord,
+    /// `_jsx`, or `_jsxDEV` in development mode.
+    import_jsx: Option<Ident>,
+    import_jsxs: Option<Ident>,
+    import_fragment: Option<Ident>,
+    /// Used by the automatic runtime for elements with `key` after a spread.
+    import_create_element: Option<Ident>,
 }
 
 impl Jsx {
+    /// Applies `@jsx`, `@jsxFrag`, `@jsxRuntime` and `@jsxImportSource`
+    /// comments at the start of the file.
+    fn read_pragmas(&mut self, span: Span) {
+        lazy_static! {
+            static ref PRAGMA: Regex =
+                { Regex::new(r"@(jsxRuntime|jsxImportSource|jsxFrag|jsx)\s+(\S+)").unwrap() };
+        }
+
+        if !COMMENTS.is_set() {
+            return;
+        }
+
+        COMMENTS.with(|comments| {
+            let cmts = match comments.leading_comments(span.lo()) {
+                Some(cmts) => cmts,
+                None => return,
+            };
+
+            for cmt in cmts.iter() {
+                for cap in PRAGMA.captures_iter(&cmt.text) {
+                    let value = &cap[2];
+
+                    match &cap[1] {
+                        "jsxRuntime" => match value {
+                            "classic" => self.runtime = Runtime::Classic,
+                            "automatic" => self.runtime = Runtime::Automatic,
+                            _ => HANDLER.with(|handler| {
+                                handler
+                                    .struct_span_err(
+                                        cmt.span,
+                                        "Runtime must be either \"classic\" or \"automatic\"",
+                                    )
+                                    .emit()
+                            }),
+                        },
+                        "jsxImportSource" => self.import_source = value.into(),
+                        "jsx" => {
+                            if let Ok(expr) = parse_pragma(cmt.span, "jsx", value) {
+                                self.pragma = ExprOrSuper::Expr(expr)
+                            }
+                        }
+                        "jsxFrag" => {
+                            if let Ok(expr) = parse_pragma(cmt.span, "jsxFrag", value) {
+                                self.pragma_frag = ExprOrSpread {
+                                    spread: None,
+                                    expr,
+                                }
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        })
+    }
+
+    /// Reports `ns:name`, which react does not support.
+    fn check_namespace(&self, span: Span) {
+        if self.throw_if_namespace {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        span,
+                        "JSX Namespace is disabled by default because react does not support \
+                         it yet. You can specify jsc.transform.react.throwIfNamespace to false \
+                         to override default behavior",
+                    )
+                    .emit()
+            });
+        }
+    }
+
+    fn jsx_callee(&mut self, is_static: bool) -> ExprOrSuper {
+        let ident = match (self.development, is_static) {
+            (true, _) => self.import_jsx.get_or_insert_with(|| private_ident!("_jsxDEV")),
+            (false, false) => self.import_jsx.get_or_insert_with(|| private_ident!("_jsx")),
+            (false, true) => self.import_jsxs.get_or_insert_with(|| private_ident!("_jsxs")),
+        };
+
+        ident.clone().as_callee()
+    }
+
+    /// `_jsx(type, { ...props, children }, key)` or
+    /// `_jsxDEV(type, { ...props, children }, key, isStaticChildren, source,
+    /// self)`
+    fn jsx_automatic(
+        &mut self,
+        span: Span,
+        name: Box<Expr>,
+        attrs: Vec<JSXAttrOrSpread>,
+        children: Vec<JSXElementChild>,
+    ) -> Expr {
+        let mut key = None;
+        let mut source = None;
+        let mut this = None;
+        let mut props = vec![];
+
+        for attr in attrs {
+            match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => {
+                    let slot = match attr.name {
+                        JSXAttrName::Ident(ref i) if i.sym == *"key" => Some(&mut key),
+                        JSXAttrName::Ident(ref i) if self.development && i.sym == *"__source" => {
+                            Some(&mut source)
+                        }
+                        JSXAttrName::Ident(ref i) if self.development && i.sym == *"__self" => {
+                            Some(&mut this)
+                        }
+                        _ => None,
+                    };
+
+                    match slot {
+                        Some(slot) => *slot = Some(attr_value(attr.value, attr.span)),
+                        None => props.push(PropOrSpread::Prop(box self.attr_to_prop(attr))),
+                    }
+                }
+                JSXAttrOrSpread::SpreadElement(e) => props.push(PropOrSpread::Spread(e)),
+            }
+        }
+
+        let mut children = children
+            .into_iter()
+            .filter_map(|c| self.jsx_elem_child_to_expr(c))
+            .collect::<Vec<_>>();
+        let is_static = children.len() > 1;
+        let children = match children.len() {
+            0 => None,
+            1 if children[0].spread.is_none() => Some(children.pop().unwrap().expr),
+            _ => Some(box Expr::Array(ArrayLit {
+                span: DUMMY_SP,
+                elems: children.into_iter().map(Some).collect(),
+            })),
+        };
+        if let Some(value) = children {
+            props.push(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("children")),
+                value,
+            })));
+        }
+
+        let mut args = vec![
+            name.as_arg(),
+            ObjectLit {
+                span: DUMMY_SP,
+                props,
+            }
+            .as_arg(),
+        ];
+        if self.development {
+            args.push(key.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg());
+            args.push(
+                Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: is_static,
+                })
+                .as_arg(),
+            );
+            args.push(source.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg());
+            args.push(this.unwrap_or_else(|| undefined(DUMMY_SP)).as_arg());
+        } else if let Some(key) = key {
+            args.push(key.as_arg());
+        }
+
+        Expr::Call(CallExpr {
+            span,
+            callee: self.jsx_callee(is_static),
+            args,
+            type_args: Default::default(),
+        })
+    }
+
     fn jsx_frag_to_expr(&mut self, el: JSXFragment) -> Expr {
         let span = el.span();
 
+        if self.runtime == Runtime::Automatic {
+            let fragment = self
+                .import_fragment
+                .get_or_insert_with(|| private_ident!("_Fragment"))
+                .clone();
+            return self.jsx_automatic(span, box Expr::Ident(fragment), vec![], el.children);
+        }
+
         Expr::Call(CallExpr {
             span,
             callee: self.pragma.clone(),
@@ -142,9 +386,23 @@ impl Jsx {
 
         let name = self.jsx_name(el.opening.name);
 
+        let callee = match self.runtime {
+            Runtime::Automatic if !has_key_after_spread(&el.opening.attrs) => {
+                return self.jsx_automatic(span, name, el.opening.attrs, el.children);
+            }
+            // `jsx()` takes `key` separately from props, so only `createElement`
+            // preserves the precedence between a spread and a following `key`.
+            Runtime::Automatic => self
+                .import_create_element
+                .get_or_insert_with(|| private_ident!("_createElement"))
+                .clone()
+                .as_callee(),
+            Runtime::Classic => self.pragma.clone(),
+        };
+
         Expr::Call(CallExpr {
             span,
-            callee: self.pragma.clone(),
+            callee,
             args: iter::once(name.as_arg())
                 .chain(iter::once({
                     // Attributes
@@ -169,6 +427,7 @@ impl Jsx {
                     span: text.span,
                     has_escape: text.raw != text.value,
                     value: jsx_text_to_str(text.value),
+                    raw: None,
                 };
                 if s.value.is_empty() {
                     return None;
@@ -186,9 +445,10 @@ impl Jsx {
             }) => return None,
             JSXElementChild::JSXElement(el) => self.jsx_elem_to_expr(*el).as_arg(),
             JSXElementChild::JSXFragment(el) => self.jsx_frag_to_expr(el).as_arg(),
-            JSXElementChild::JSXSpreadChild(JSXSpreadChild { .. }) => {
-                unimplemented!("jsx sperad child")
-            }
+            JSXElementChild::JSXSpreadChild(JSXSpreadChild { span, expr }) => ExprOrSpread {
+                spread: Some(span),
+                expr,
+            },
         })
     }
 
@@ -221,7 +481,7 @@ impl Jsx {
             for attr in attrs {
                 match attr {
                     JSXAttrOrSpread::JSXAttr(a) => {
-                        cur_obj_props.push(PropOrSpread::Prop(box attr_to_prop(a)))
+                        cur_obj_props.push(PropOrSpread::Prop(box self.attr_to_prop(a)))
                     }
                     JSXAttrOrSpread::SpreadElement(e) => {
                         check!();
@@ -253,7 +513,7 @@ impl Jsx {
                         JSXAttrOrSpread::JSXAttr(a) => a,
                         _ => unreachable!(),
                     })
-                    .map(attr_to_prop)
+                    .map(|a| self.attr_to_prop(a))
                     .map(Box::new)
                     .map(PropOrSpread::Prop)
                     .collect(),
@@ -262,6 +522,109 @@ impl Jsx {
     }
 }
 
+/// Runtime modules used by a file, with pairs of local and imported names.
+type RuntimeImports = Vec<(JsWord, Vec<(Ident, &'static str)>)>;
+
+impl Jsx {
+    /// Folds a file, and returns the runtime modules it uses.
+    fn fold_file<T>(&mut self, span: Span, node: T) -> (T, RuntimeImports)
+    where
+        T: FoldWith<Self>,
+    {
+        let (pragma, pragma_frag) = (self.pragma.clone(), self.pragma_frag.clone());
+        let (runtime, import_source) = (self.runtime, self.import_source.clone());
+        self.read_pragmas(span);
+
+        let node = node.fold_children(self);
+
+        let mut imports = vec![];
+        let specifiers = vec![
+            (
+                self.import_jsx.take(),
+                if self.development { "jsxDEV" } else { "jsx" },
+            ),
+            (self.import_jsxs.take(), "jsxs"),
+            (self.import_fragment.take(), "Fragment"),
+        ]
+        .into_iter()
+        .filter_map(|(local, imported)| local.map(|local| (local, imported)))
+        .collect::<Vec<_>>();
+        if !specifiers.is_empty() {
+            let runtime = if self.development {
+                "jsx-dev-runtime"
+            } else {
+                "jsx-runtime"
+            };
+            imports.push((
+                format!("{}/{}", self.import_source, runtime).into(),
+                specifiers,
+            ));
+        }
+        if let Some(local) = self.import_create_element.take() {
+            imports.push((self.import_source.clone(), vec![(local, "createElement")]));
+        }
+
+        // Pragma comments only apply to the file they are in.
+        self.pragma = pragma;
+        self.pragma_frag = pragma_frag;
+        self.runtime = runtime;
+        self.import_source = import_source;
+
+        (node, imports)
+    }
+}
+
+impl Fold<Module> for Jsx {
+    fn fold(&mut self, module: Module) -> Module {
+        let (mut module, imports) = self.fold_file(module.span, module);
+
+        let imports = imports.into_iter().map(|(src, specifiers)| {
+            let specifiers = specifiers
+                .into_iter()
+                .map(|(local, imported)| import_specifier(local, imported))
+                .collect();
+            import_decl(src, specifiers)
+        });
+        prepend_stmts(&mut module.body, imports);
+
+        module
+    }
+}
+
+impl Fold<Script> for Jsx {
+    fn fold(&mut self, script: Script) -> Script {
+        let (mut script, imports) = self.fold_file(script.span, script);
+
+        // Scripts cannot import, so `var _jsx = require("react/jsx-runtime").jsx;`
+        // is used instead.
+        let requires = imports.into_iter().flat_map(|(src, specifiers)| {
+            specifiers.into_iter().map(move |(local, imported)| {
+                let require = CallExpr {
+                    span: DUMMY_SP,
+                    callee: quote_ident!("require").as_callee(),
+                    args: vec![Lit::Str(quote_str!(src.clone())).as_arg()],
+                    type_args: Default::default(),
+                };
+
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(local),
+                        init: Some(box require.member(quote_ident!(imported))),
+                        definite: false,
+                    }],
+                }))
+            })
+        });
+        prepend_stmts(&mut script.body, requires.collect::<Vec<_>>().into_iter());
+
+        script
+    }
+}
+
 impl Fold<Expr> for Jsx {
     fn fold(&mut self, expr: Expr) -> Expr {
         let expr = expr.fold_children(self);
@@ -289,6 +652,16 @@ impl Fold<Expr> for Jsx {
 }
 
 impl Jsx {
+    fn attr_to_prop(&self, a: JSXAttr) -> Prop {
+        if let JSXAttrName::JSXNamespacedName(..) = a.name {
+            self.check_namespace(a.name.span());
+        }
+
+        let key = to_prop_name(a.name);
+        let value = attr_value(a.value, key.span());
+        Prop::KeyValue(KeyValueProp { key, value })
+    }
+
     fn jsx_name(&self, name: JSXElementName) -> Box<Expr> {
         let span = name.span();
         match name {
@@ -305,29 +678,19 @@ impl Jsx {
                         span,
                         value: i.sym,
                         has_escape: false,
+                        raw: None,
                     }))
                 } else {
                     box Expr::Ident(i)
                 }
             }
             JSXElementName::JSXNamespacedName(JSXNamespacedName { ref ns, ref name }) => {
-                if self.throw_if_namespace {
-                    HANDLER.with(|handler| {
-                        handler
-                            .struct_span_err(
-                                span,
-                                "JSX Namespace is disabled by default because react does not \
-                                 support it yet. You can specify \
-                                 jsc.transform.react.throwIfNamespace to false to override \
-                                 default behavior",
-                            )
-                            .emit()
-                    });
-                }
+                self.check_namespace(span);
                 box Expr::Lit(Lit::Str(Str {
                     span,
                     value: format!("{}:{}", ns.sym, name.sym).into(),
                     has_escape: false,
+                    raw: None,
                 }))
             }
             JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop }) => {
@@ -361,10 +724,50 @@ impl Jsx {
     }
 }
 
-fn attr_to_prop(a: JSXAttr) -> Prop {
-    let key = to_prop_name(a.name);
-    let value = a
-        .value
+fn has_key_after_spread(attrs: &[JSXAttrOrSpread]) -> bool {
+    let mut seen_spread = false;
+
+    for attr in attrs {
+        match *attr {
+            JSXAttrOrSpread::SpreadElement(..) => seen_spread = true,
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(ref i),
+                ..
+            }) if seen_spread && i.sym == *"key" => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+fn import_decl(src: JsWord, specifiers: Vec<ImportSpecifier>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Str {
+            span: DUMMY_SP,
+            value: src,
+            has_escape: false,
+            raw: None,
+        },
+        asserts: None,
+        type_only: false,
+    }))
+}
+
+fn import_specifier(local: Ident, imported: &str) -> ImportSpecifier {
+    ImportSpecifier::Specific(ImportSpecific {
+        span: DUMMY_SP,
+        local,
+        imported: Some(quote_ident!(imported)),
+        type_only: false,
+    })
+}
+
+/// Value of an attribute. `<a b />` is `true`.
+fn attr_value(value: Option<JSXAttrValue>, span: Span) -> Box<Expr> {
+    value
         .map(|v| match v {
             JSXAttrValue::JSXExprContainer(JSXExprContainer {
                 expr: JSXExpr::Expr(e),
@@ -372,13 +775,7 @@ fn attr_to_prop(a: JSXAttr) -> Prop {
             }) => e,
             _ => unreachable!(),
         })
-        .unwrap_or_else(|| {
-            box Expr::Lit(Lit::Bool(Bool {
-                span: key.span(),
-                value: true,
-            }))
-        });
-    Prop::KeyValue(KeyValueProp { key, value })
+        .unwrap_or_else(|| box Expr::Lit(Lit::Bool(Bool { span, value: true })))
 }
 
 fn to_prop_name(n: JSXAttrName) -> PropName {
@@ -391,6 +788,7 @@ fn to_prop_name(n: JSXAttrName) -> PropName {
                     span,
                     value: i.sym,
                     has_escape: false,
+                    raw: None,
                 })
             } else {
                 PropName::Ident(i)
@@ -400,6 +798,7 @@ fn to_prop_name(n: JSXAttrName) -> PropName {
             span,
             value: format!("{}:{}", ns.sym, name.sym).into(),
             has_escape: false,
+            raw: None,
         }),
     }
 }
@@ -1123,6 +1123,221 @@ _react.default.createElement('div', {
 }, 'Hello World');"
 );
 
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Options {
+        runtime: Runtime::Automatic,
+        ..Default::default()
+    }),
+    automatic_runtime,
+    r#"
+var x = <div className="foo" key="k">{a}</div>;
+var y = <Foo><a /><b /></Foo>;
+var z = <><span /></>;
+"#,
+    r#"
+import { jsx as _jsx, jsxs as _jsxs, Fragment as _Fragment } from "react/jsx-runtime";
+var x = _jsx("div", {
+    className: "foo",
+    children: a
+}, "k");
+var y = _jsxs(Foo, {
+    children: [_jsx("a", {}), _jsx("b", {})]
+});
+var z = _jsx(_Fragment, {
+    children: _jsx("span", {})
+});
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Options {
+        runtime: Runtime::Automatic,
+        ..Default::default()
+    }),
+    automatic_runtime_spread,
+    r#"
+<div key="a" {...props} foo />;
+<div {...props} key="b" />;
+"#,
+    r#"
+import { jsx as _jsx } from "react/jsx-runtime";
+import { createElement as _createElement } from "react";
+_jsx("div", {
+    ...props,
+    foo: true
+}, "a");
+_createElement("div", _extends({}, props, {
+    key: "b"
+}));
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| crate::react::react(
+        tester.cm.clone(),
+        Options {
+            runtime: Runtime::Automatic,
+            development: true,
+            ..Default::default()
+        }
+    ),
+    automatic_runtime_development,
+    r#"<div key="a"><span /><span /></div>;"#,
+    r#"
+import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+_jsxDEV("div", {
+    children: [_jsxDEV("span", {}, void 0, false, {
+        fileName: "input.js",
+        lineNumber: 1
+    }, this), _jsxDEV("span", {}, void 0, false, {
+        fileName: "input.js",
+        lineNumber: 1
+    }, this)]
+}, "a", true, {
+    fileName: "input.js",
+    lineNumber: 1
+}, this);
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    automatic_runtime_pragma,
+    r#"/** @jsxRuntime automatic @jsxImportSource preact */
+<a />;
+"#,
+    r#"
+import { jsx as _jsx } from "preact/jsx-runtime";
+_jsx("a", {});
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    spread_children,
+    r#"<div>{...children}</div>;"#,
+    r#"React.createElement("div", null, ...children);"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Options {
+        runtime: Runtime::Automatic,
+        ..Default::default()
+    }),
+    automatic_runtime_spread_children,
+    r#"<div>{...children}</div>;"#,
+    r#"
+import { jsx as _jsx } from "react/jsx-runtime";
+_jsx("div", {
+    children: [...children]
+});
+"#
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    pragma_comments,
+    r#"/** @jsx h */
+/** @jsxFrag Fragment */
+<><div /></>;
+"#,
+    r#"h(Fragment, null, h("div", null));"#
+);
+
+#[test]
+fn automatic_runtime_script() {
+    crate::tests::Tester::run(|tester| {
+        let syntax = ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+            jsx: true,
+            ..Default::default()
+        });
+        let script = tester.with_parser("input.js", syntax, "var x = <div />;", |p| {
+            p.parse_script().map_err(|mut e| {
+                e.emit();
+            })
+        })?;
+
+        let script = match Program::Script(script).fold_with(&mut jsx(Options {
+            runtime: Runtime::Automatic,
+            ..Default::default()
+        })) {
+            Program::Script(script) => script,
+            Program::Module(..) => unreachable!(),
+        };
+        let actual = tester.print(&Module {
+            span: DUMMY_SP,
+            body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+            shebang: None,
+        });
+
+        let expected = tester.parse_module(
+            "expected.js",
+            r#"var _jsx = require("react/jsx-runtime").jsx;
+var x = _jsx("div", {});"#,
+        )?;
+        let expected = tester.print(&expected);
+
+        assert_eq!(actual, expected);
+        Ok(())
+    });
+}
+
+#[test]
+fn invalid_pragma_comment() {
+    crate::tests::Tester::run(|tester| {
+        let syntax = ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+            jsx: true,
+            ..Default::default()
+        });
+        let actual = tester.apply_transform(
+            tr(Default::default()),
+            "input.js",
+            syntax,
+            "/** @jsx 1+ */\n<div />;",
+        )?;
+        assert!(tester.handler.has_errors());
+
+        // The configured pragma is used instead.
+        let expected = tester.apply_transform(
+            ::testing::DropSpan,
+            "expected.js",
+            syntax,
+            r#"React.createElement("div", null);"#,
+        )?;
+
+        assert_eq!(tester.print(&actual), tester.print(&expected));
+        Ok(())
+    });
+}
+
 #[test]
 fn jsx_text() {
     assert_eq!(jsx_text_to_str(" ".into()), *" ");
@@ -24,7 +24,7 @@ impl swc_ecma_codegen::Handlers for MyHandlers {}
 pub(crate) struct Tester<'a> {
     pub cm: Arc<SourceMap>,
     pub handler: &'a Handler,
-    pub comments: Comments,
+    pub comments: &'a Comments,
 }
 
 impl<'a> Tester<'a> {
@@ -33,12 +33,16 @@ impl<'a> Tester<'a> {
         F: FnOnce(&mut Tester<'_>) -> Result<(), ()>,
     {
         let out = ::testing::run_test(false, |cm, handler| {
+            let comments = Comments::default();
+
             crate::util::HANDLER.set(handler, || {
-                HELPERS.set(&Default::default(), || {
-                    op(&mut Tester {
-                        cm,
-                        handler,
-                        comments: Comments::default(),
+                crate::util::COMMENTS.set(&comments, || {
+                    HELPERS.set(&Default::default(), || {
+                        op(&mut Tester {
+                            cm,
+                            handler,
+                            comments: &comments,
+                        })
                     })
                 })
             })
@@ -72,7 +76,7 @@ impl<'a> Tester<'a> {
             sess,
             syntax,
             SourceFileInput::from(&*fm),
-            Some(&self.comments),
+            Some(self.comments),
         );
         op(&mut p)
     }
@@ -120,7 +124,12 @@ impl<'a> Tester<'a> {
                 handler: &self.handler,
             };
 
-            let mut p = Parser::new(sess, syntax, SourceFileInput::from(&*fm), None);
+            let mut p = Parser::new(
+                sess,
+                syntax,
+                SourceFileInput::from(&*fm),
+                Some(self.comments),
+            );
             p.parse_module().map_err(|mut e| {
                 e.emit();
             })?
@@ -401,6 +410,8 @@ impl Write for Buf {
 struct Normalizer;
 impl Fold<PatOrExpr> for Normalizer {
     fn fold(&mut self, n: PatOrExpr) -> PatOrExpr {
+        let n = n.fold_children(self);
+
         match n {
             PatOrExpr::Pat(box Pat::Expr(e)) => PatOrExpr::Expr(e),
             _ => n,
@@ -408,6 +419,19 @@ impl Fold<PatOrExpr> for Normalizer {
     }
 }
 
+// Raw source text differs between the input and the expected output.
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
+
 pub(crate) struct HygieneVisualizer;
 impl Fold<Ident> for HygieneVisualizer {
     fn fold(&mut self, ident: Ident) -> Ident {
@@ -1,24 +1,119 @@
 use crate::{
+    optimization::SimplifyExpr,
     pass::Pass,
-    util::{prepend_stmts, var::VarCollector, ExprFactory},
+    util::{
+        constructor::inject_after_super, find_ids, id, undefined, var::VarCollector, ExprFactory,
+        Id, HANDLER,
+    },
 };
 use ast::*;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::mem;
 use swc_atoms::{js_word, JsWord};
 use swc_common::{
-    util::move_map::MoveMap, Fold, FoldWith, Spanned, SyntaxContext, Visit, VisitWith, DUMMY_SP,
+    util::move_map::MoveMap, BytePos, Fold, FoldWith, Span, Spanned, SyntaxContext, Visit,
+    VisitWith, DUMMY_SP,
 };
 
+pub use self::dts::dts;
+
+mod dts;
+
 /// Strips type annotations out.
 pub fn strip() -> impl Pass {
-    Strip::default()
+    strip_with_config(Default::default())
+}
+
+pub fn strip_with_config(config: Config) -> impl Pass {
+    Strip {
+        config,
+        ..Default::default()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Values of enums declared in other modules, which are used to inline
+    /// usages of the enums.
+    ///
+    /// Keys are module specifiers, names of enums and names of members.
+    #[serde(default)]
+    pub enums: HashMap<JsWord, HashMap<JsWord, HashMap<JsWord, EnumValue>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EnumValue {
+    Num(f64),
+    Str(JsWord),
+}
+
+impl EnumValue {
+    fn from_expr(e: &Expr) -> Option<Self> {
+        match *e {
+            Expr::Lit(Lit::Num(Number { value, .. })) => Some(EnumValue::Num(value)),
+            Expr::Lit(Lit::Str(Str { ref value, .. })) => Some(EnumValue::Str(value.clone())),
+            Expr::Unary(UnaryExpr {
+                op: op!(unary, "-"),
+                arg: box Expr::Lit(Lit::Num(Number { value, .. })),
+                ..
+            }) => Some(EnumValue::Num(-value)),
+            Expr::Paren(ParenExpr { ref expr, .. }) => EnumValue::from_expr(expr),
+            _ => None,
+        }
+    }
+
+    fn into_expr(self, span: Span) -> Expr {
+        match self {
+            EnumValue::Num(value) if value.is_sign_negative() => Expr::Unary(UnaryExpr {
+                span,
+                op: op!(unary, "-"),
+                arg: box Expr::Lit(Lit::Num(Number {
+                    span,
+                    value: -value,
+                    raw: None,
+                })),
+            }),
+            EnumValue::Num(value) => Expr::Lit(Lit::Num(Number {
+                span,
+                value,
+                raw: None,
+            })),
+            EnumValue::Str(value) => Expr::Lit(Lit::Str(Str {
+                span,
+                value,
+                has_escape: false,
+                raw: None,
+            })),
+        }
+    }
 }
 
 #[derive(Default)]
 struct Strip {
+    config: Config,
     non_top_level: bool,
     scope: Scope,
     phase: Phase,
+    /// Name of the namespace being processed.
+    ns: Option<Ident>,
+    enums: HashMap<(JsWord, SyntaxContext), EnumInfo>,
+}
+
+struct EnumInfo {
+    /// True for const enums and enums imported from other modules.
+    inline: bool,
+    members: HashMap<JsWord, EnumValue>,
+}
+
+struct EnumMember {
+    name: JsWord,
+    /// Value computed at compile time.
+    value: Option<EnumValue>,
+    /// Initializer to evaluate at runtime.
+    init: Option<Box<Expr>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -83,12 +178,19 @@ impl Strip {
                 }
             }
 
-            Decl::TsEnum(TsEnumDecl { ref id, .. })
-            | Decl::TsInterface(TsInterfaceDecl { ref id, .. })
-            | Decl::TsModule(TsModuleDecl {
+            Decl::TsModule(TsModuleDecl {
                 id: TsModuleName::Ident(ref id),
+                declare,
+                ref body,
                 ..
-            })
+            }) => store!(
+                id.sym,
+                id.span.ctxt(),
+                !declare && body.as_ref().map_or(false, is_instantiated)
+            ),
+
+            Decl::TsEnum(TsEnumDecl { ref id, .. })
+            | Decl::TsInterface(TsInterfaceDecl { ref id, .. })
             | Decl::TsTypeAlias(TsTypeAliasDecl { ref id, .. }) => {
                 store!(id.sym, id.span.ctxt(), false)
             }
@@ -104,59 +206,126 @@ impl Strip {
     }
 }
 
-impl Fold<Constructor> for Strip {
-    fn fold(&mut self, c: Constructor) -> Constructor {
-        let c = c.fold_children(self);
+impl Fold<Class> for Strip {
+    fn fold(&mut self, class: Class) -> Class {
+        let mut class = class.fold_children(self);
+
+        let has_param_props = class.body.iter().any(|m| match *m {
+            ClassMember::Constructor(ref c) => c.params.iter().any(|p| match *p {
+                PatOrTsParamProp::TsParamProp(..) => true,
+                _ => false,
+            }),
+            _ => false,
+        });
+        // Like `tsc`, instance fields are moved into the constructor so that
+        // they can access parameter properties. Computed keys and decorators
+        // are evaluated while defining the class, so such fields are kept.
+        let can_move_fields = class.body.iter().all(|m| match *m {
+            ClassMember::ClassProp(ref p) => {
+                p.is_static || (!p.computed && p.decorators.is_empty())
+            }
+            _ => true,
+        });
+
+        let mut field_inits = vec![];
+        if has_param_props && can_move_fields {
+            class.body = class.body.move_flat_map(|m| match m {
+                ClassMember::ClassProp(ClassProp {
+                    key,
+                    value: Some(value),
+                    is_static: false,
+                    ..
+                }) => {
+                    let this = ThisExpr { span: DUMMY_SP };
+                    let left = match *key {
+                        Expr::Ident(i) => this.member(i),
+                        key => this.computed_member(key),
+                    };
+                    field_inits.push(box Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        left: PatOrExpr::Expr(box left),
+                        op: op!("="),
+                        right: value,
+                    }));
+                    None
+                }
+                _ => Some(m),
+            });
+        }
+
+        Class {
+            body: class.body.move_map(|m| match m {
+                ClassMember::Constructor(c) => {
+                    let field_inits = mem::replace(&mut field_inits, vec![]);
+                    ClassMember::Constructor(inject_param_props(c, field_inits))
+                }
+                _ => m,
+            }),
+            ..class
+        }
+    }
+}
 
-        let mut stmts = vec![];
+/// Converts parameter properties of `c` to assignments and injects them after
+/// `super()`, followed by `field_inits`.
+#[allow(clippy::vec_box)]
+fn inject_param_props(c: Constructor, field_inits: Vec<Box<Expr>>) -> Constructor {
+    let mut exprs = vec![];
 
-        let params = c.params.move_map(|param| match param {
-            PatOrTsParamProp::Pat(..) => param,
-            PatOrTsParamProp::TsParamProp(param) => {
-                let (ident, param) = match param.param {
-                    TsParamPropParam::Ident(i) => (i.clone(), Pat::Ident(i)),
-                    TsParamPropParam::Assign(AssignPat {
+    let params = c.params.move_map(|param| match param {
+        // Decorators are applied by the decorators pass.
+        PatOrTsParamProp::Pat(..) | PatOrTsParamProp::Param(..) => param,
+        PatOrTsParamProp::TsParamProp(TsParamProp {
+            span,
+            decorators,
+            param,
+            ..
+        }) => {
+            let (ident, param) = match param {
+                TsParamPropParam::Ident(i) => (i.clone(), Pat::Ident(i)),
+                TsParamPropParam::Assign(AssignPat {
+                    span,
+                    left: box Pat::Ident(i),
+                    right,
+                    ..
+                }) => (
+                    i.clone(),
+                    Pat::Assign(AssignPat {
                         span,
                         left: box Pat::Ident(i),
                         right,
-                        ..
-                    }) => (
-                        i.clone(),
-                        Pat::Assign(AssignPat {
-                            span,
-                            left: box Pat::Ident(i),
-                            right,
-                            type_ann: None,
-                        }),
-                    ),
-                    _ => unreachable!("destructuring pattern inside TsParameterProperty"),
-                };
-                stmts.push(
-                    AssignExpr {
-                        span: DUMMY_SP,
-                        left: PatOrExpr::Expr(
-                            box ThisExpr { span: DUMMY_SP }.member(ident.clone()),
-                        ),
-                        op: op!("="),
-                        right: box Expr::Ident(ident),
-                    }
-                    .into_stmt(),
-                );
+                        type_ann: None,
+                    }),
+                ),
+                _ => unreachable!("destructuring pattern inside TsParameterProperty"),
+            };
+            exprs.push(box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Expr(box ThisExpr { span: DUMMY_SP }.member(ident.clone())),
+                op: op!("="),
+                right: box Expr::Ident(ident),
+            }));
 
+            if decorators.is_empty() {
                 PatOrTsParamProp::Pat(param)
+            } else {
+                PatOrTsParamProp::Param(Param {
+                    span,
+                    decorators,
+                    pat: param,
+                })
             }
-        });
-
-        let body = match c.body {
-            Some(mut body) => {
-                prepend_stmts(&mut body.stmts, stmts.into_iter());
-                Some(body)
-            }
-            None => None,
-        };
+        }
+    });
 
-        Constructor { params, body, ..c }
+    let c = Constructor { params, ..c };
+    if c.body.is_none() || exprs.is_empty() {
+        return c;
     }
+    exprs.extend(field_inits);
+
+    // `this` is not available before super() in derived classes.
+    inject_after_super(c, exprs)
 }
 
 impl Fold<Vec<ClassMember>> for Strip {
@@ -204,9 +373,96 @@ impl Fold<Vec<ModuleItem>> for Strip {
         let old = self.phase;
         self.phase = Phase::DropImports;
 
+        // Classes and functions can be merged with namespaces, and `var` should
+        // not be emitted for them.
+        let mut declared = HashSet::default();
+        for item in &items {
+            match *item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl { ref ident, .. })))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ref ident, .. })))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Class(ClassDecl { ref ident, .. }),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(FnDecl { ref ident, .. }),
+                    ..
+                })) => {
+                    declared.insert((ident.sym.clone(), ident.span.ctxt()));
+                }
+                _ => {}
+            }
+        }
+        // Exported variables of the current namespace.
+        let mut ns_vars = vec![];
+
         // Second pass
         let mut stmts = Vec::with_capacity(items.len());
         for item in items {
+            // Exported members of a namespace are stored in the namespace object.
+            let item = match self.ns {
+                Some(ref ns) => match item {
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        decl: Decl::Var(var),
+                        ..
+                    })) => {
+                        var.decls.visit_with(&mut VarCollector { to: &mut ns_vars });
+
+                        // References to the variables are replaced below.
+                        for d in var.decls {
+                            if let Some(init) = d.init {
+                                stmts.push(
+                                    AssignExpr {
+                                        span: d.span,
+                                        left: PatOrExpr::Pat(box d.name),
+                                        op: op!("="),
+                                        right: init,
+                                    }
+                                    .into_stmt()
+                                    .into(),
+                                );
+                            }
+                        }
+                        continue;
+                    }
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        decl: decl @ Decl::Class(..),
+                        ..
+                    }))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        decl:
+                            decl @ Decl::Fn(FnDecl {
+                                function: Function { body: Some(..), .. },
+                                ..
+                            }),
+                        ..
+                    })) => {
+                        let ident = match decl {
+                            Decl::Class(ClassDecl { ref ident, .. })
+                            | Decl::Fn(FnDecl { ref ident, .. }) => ident.clone(),
+                            _ => unreachable!(),
+                        };
+
+                        // function foo() {}
+                        // Foo.foo = foo;
+                        stmts.push(Stmt::Decl(decl).into());
+                        stmts.push(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Expr(box ns.clone().member(ident.clone())),
+                                op: op!("="),
+                                right: box Expr::Ident(ident),
+                            }
+                            .into_stmt()
+                            .into(),
+                        );
+                        continue;
+                    }
+                    _ => item,
+                },
+                None => item,
+            };
+
             match item {
                 ModuleItem::Stmt(Stmt::Empty(..)) => continue,
 
@@ -214,21 +470,7 @@ impl Fold<Vec<ModuleItem>> for Strip {
                     decl: Decl::TsEnum(e),
                     ..
                 })) => {
-                    stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
-                        span: e.span,
-                        decl: Decl::Var(VarDecl {
-                            span: DUMMY_SP,
-                            kind: VarDeclKind::Var,
-                            declare: false,
-                            decls: vec![VarDeclarator {
-                                span: e.span,
-                                name: Pat::Ident(e.id.clone()),
-                                definite: false,
-                                init: None,
-                            }],
-                        }),
-                    })));
-                    self.handle_enum(e, &mut stmts)
+                    self.handle_enum(e, true, &mut declared, &mut stmts)
                 }
                 ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(e))) => {
                     // var Foo;
@@ -236,21 +478,15 @@ impl Fold<Vec<ModuleItem>> for Strip {
                     //     Foo[Foo["a"] = 0] = "a";
                     // })(Foo || (Foo = {}));
 
-                    stmts.push(
-                        Stmt::Decl(Decl::Var(VarDecl {
-                            span: DUMMY_SP,
-                            kind: VarDeclKind::Var,
-                            declare: false,
-                            decls: vec![VarDeclarator {
-                                span: e.span,
-                                name: Pat::Ident(e.id.clone()),
-                                definite: false,
-                                init: None,
-                            }],
-                        }))
-                        .into(),
-                    );
-                    self.handle_enum(e, &mut stmts)
+                    self.handle_enum(e, false, &mut declared, &mut stmts)
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsModule(m),
+                    ..
+                })) => self.handle_ts_module(m, true, &mut declared, &mut stmts),
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(m))) => {
+                    self.handle_ts_module(m, false, &mut declared, &mut stmts)
                 }
 
                 ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
@@ -258,16 +494,11 @@ impl Fold<Vec<ModuleItem>> for Strip {
                     ..
                 })))
                 | ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(..)))
-                | ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(..)))
                 | ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(..)))
                 | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     decl: Decl::TsInterface(..),
                     ..
                 }))
-                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
-                    decl: Decl::TsModule(..),
-                    ..
-                }))
                 | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     decl: Decl::TsTypeAlias(..),
                     ..
@@ -292,13 +523,40 @@ impl Fold<Vec<ModuleItem>> for Strip {
                     decl: DefaultDecl::TsInterfaceDecl(..),
                     ..
                 }))
+                | ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    type_only: true, ..
+                }))
                 | ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(..)) => continue,
 
                 ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                    if let TsModuleRef::TsExternalModuleRef(..) = import.module_ref {
+                        // `import foo = require('foo')` is lowered by module transforms.
+                        if import.is_export || !self.is_type_only_import(&import.id) {
+                            stmts.push(ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(
+                                import,
+                            )));
+                        }
+                        continue;
+                    }
+
                     if !import.is_export {
                         continue;
                     }
 
+                    if let Some(ref ns) = self.ns {
+                        stmts.push(
+                            AssignExpr {
+                                span: import.span,
+                                left: PatOrExpr::Expr(box ns.clone().member(import.id)),
+                                op: op!("="),
+                                right: box module_ref_to_expr(import.module_ref),
+                            }
+                            .into_stmt()
+                            .into(),
+                        );
+                        continue;
+                    }
+
                     stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                         span: DUMMY_SP,
                         decl: Decl::Var(VarDecl {
@@ -315,15 +573,6 @@ impl Fold<Vec<ModuleItem>> for Strip {
                     })));
                 }
 
-                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(export)) => {
-                    stmts.push(ModuleItem::ModuleDecl(
-                        ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                            span: export.span(),
-                            expr: export.expr,
-                        })
-                        .fold_with(self),
-                    ))
-                }
                 ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(mut export)) => {
                     // if specifier become empty, we remove export statement.
 
@@ -353,13 +602,176 @@ impl Fold<Vec<ModuleItem>> for Strip {
         }
         self.phase = old;
 
-        stmts
+        match self.ns {
+            Some(ref ns) if !ns_vars.is_empty() => stmts.fold_with(&mut NsVarReplacer {
+                ns,
+                vars: &ns_vars,
+                shadowed: vec![],
+                in_assign_target: false,
+            }),
+            _ => stmts,
+        }
     }
 }
 
 impl Strip {
-    fn handle_enum(&mut self, e: TsEnumDecl, stmts: &mut Vec<ModuleItem>) {
-        let id = e.id;
+    /// Emits `var Foo;` (or `export var Foo;`) if `Foo` is not declared yet.
+    fn declare_var(
+        &self,
+        id: &Ident,
+        exported: bool,
+        span: Span,
+        declared: &mut HashSet<(JsWord, SyntaxContext)>,
+        stmts: &mut Vec<ModuleItem>,
+    ) {
+        if !declared.insert((id.sym.clone(), id.span.ctxt())) {
+            return;
+        }
+
+        let decl = Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span,
+                name: Pat::Ident(id.clone()),
+                definite: false,
+                init: None,
+            }],
+        });
+
+        // Exported members of a namespace are stored in the namespace object.
+        if exported && self.ns.is_none() {
+            stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl,
+            })))
+        } else {
+            stmts.push(Stmt::Decl(decl).into())
+        }
+    }
+
+    /// Creates the argument of the iife used for enums and namespaces.
+    ///
+    ///  - `Foo || (Foo = {})`
+    ///  - `Foo = Ns.Foo || (Ns.Foo = {})` for exported members of a namespace.
+    fn iife_arg(&self, id: &Ident, exported: bool) -> ExprOrSpread {
+        let (obj, target) = match self.ns {
+            Some(ref ns) if exported => {
+                let obj = ns.clone().member(id.clone());
+                (obj.clone(), PatOrExpr::Expr(box obj))
+            }
+            _ => (
+                Expr::Ident(id.clone()),
+                PatOrExpr::Pat(Pat::Ident(id.clone()).into()),
+            ),
+        };
+
+        let init = BinExpr {
+            span: DUMMY_SP,
+            left: box obj,
+            op: op!("||"),
+            right: box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: target,
+                op: op!("="),
+                right: box Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props: vec![],
+                }),
+            }),
+        };
+
+        match self.ns {
+            Some(..) if exported => AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Pat(Pat::Ident(id.clone()).into()),
+                op: op!("="),
+                right: box init.into(),
+            }
+            .as_arg(),
+            _ => init.as_arg(),
+        }
+    }
+
+    fn handle_ts_module(
+        &mut self,
+        m: TsModuleDecl,
+        exported: bool,
+        declared: &mut HashSet<(JsWord, SyntaxContext)>,
+        stmts: &mut Vec<ModuleItem>,
+    ) {
+        // Ambient modules and namespaces don't exist at runtime.
+        if m.declare {
+            return;
+        }
+        let id = match m.id {
+            TsModuleName::Ident(id) => id,
+            TsModuleName::Str(..) => return,
+        };
+
+        if let Some(body) = m.body {
+            self.handle_namespace(id, body, exported, declared, stmts)
+        }
+    }
+
+    /// ```ts
+    /// namespace Foo {
+    ///     export const a = 1;
+    /// }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// var Foo;
+    /// (function (Foo) {
+    ///     Foo.a = 1;
+    /// })(Foo || (Foo = {}));
+    /// ```
+    ///
+    /// Body of the namespace is already processed by [Fold<TsModuleDecl>].
+    fn handle_namespace(
+        &mut self,
+        id: Ident,
+        body: TsNamespaceBody,
+        exported: bool,
+        declared: &mut HashSet<(JsWord, SyntaxContext)>,
+        stmts: &mut Vec<ModuleItem>,
+    ) {
+        let body = match body {
+            TsNamespaceBody::TsModuleBlock(block) => block.body,
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                // `namespace Foo.Bar {}` is `namespace Foo { export namespace Bar {} }`
+                let old = mem::replace(&mut self.ns, Some(id.clone()));
+                let mut body = vec![];
+                self.handle_namespace(
+                    decl.id,
+                    *decl.body,
+                    true,
+                    &mut Default::default(),
+                    &mut body,
+                );
+                self.ns = old;
+                body
+            }
+        };
+        let body = body
+            .into_iter()
+            .filter_map(|item| match item {
+                ModuleItem::Stmt(stmt) => Some(stmt),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Namespaces which contain only types are not instantiated.
+        if body.is_empty() {
+            return;
+        }
+
+        self.declare_var(&id, exported, id.span, declared, stmts);
+        let arg = self.iife_arg(&id, exported);
+
         stmts.push(
             CallExpr {
                 span: DUMMY_SP,
@@ -368,90 +780,243 @@ impl Strip {
                     function: Function {
                         span: DUMMY_SP,
                         decorators: Default::default(),
+                        param_decorators: Default::default(),
                         is_async: false,
                         is_generator: false,
                         type_params: Default::default(),
-                        params: vec![Pat::Ident(id.clone())],
+                        params: vec![Pat::Ident(id)],
                         body: Some(BlockStmt {
                             span: DUMMY_SP,
-                            stmts: e
-                                .members
-                                .into_iter()
-                                .enumerate()
-                                .map(|(i, m)| {
-                                    let value = match m.id {
-                                        TsEnumMemberId::Str(s) => s,
-                                        TsEnumMemberId::Ident(i) => Str {
-                                            span: i.span,
-                                            value: i.sym,
-                                            has_escape: false,
-                                        },
-                                    };
-
-                                    // Foo[Foo["a"] = 0] = "a";
-                                    AssignExpr {
-                                        span: DUMMY_SP,
-                                        left: PatOrExpr::Expr(box Expr::Member(MemberExpr {
-                                            obj: id.clone().as_obj(),
-                                            span: DUMMY_SP,
-                                            computed: true,
-
-                                            // Foo["a"] = 0
-                                            prop: box Expr::Assign(AssignExpr {
-                                                span: DUMMY_SP,
-                                                left: PatOrExpr::Expr(box Expr::Member(
-                                                    MemberExpr {
-                                                        span: DUMMY_SP,
-                                                        obj: id.clone().as_obj(),
-                                                        prop: m.init.unwrap_or_else(|| {
-                                                            box Expr::Lit(Lit::Str(value.clone()))
-                                                        }),
-                                                        computed: true,
-                                                    },
-                                                )),
-                                                op: op!("="),
-                                                right: box Expr::Lit(Lit::Num(Number {
-                                                    span: DUMMY_SP,
-                                                    value: i as _,
-                                                })),
-                                            }),
-                                        })),
-                                        op: op!("="),
-                                        right: box Expr::Lit(Lit::Str(Str {
-                                            span: DUMMY_SP,
-                                            value: value.value,
-                                            has_escape: false,
-                                        })),
-                                    }
-                                    .into_stmt()
-                                })
-                                .collect(),
+                            stmts: body,
                         }),
                         return_type: Default::default(),
                     },
                 }
                 .as_callee(),
-                args: vec![BinExpr {
+                args: vec![arg],
+                type_args: Default::default(),
+            }
+            .into_stmt()
+            .into(),
+        )
+    }
+
+    fn handle_enum(
+        &mut self,
+        e: TsEnumDecl,
+        exported: bool,
+        declared: &mut HashSet<(JsWord, SyntaxContext)>,
+        stmts: &mut Vec<ModuleItem>,
+    ) {
+        if e.declare {
+            return;
+        }
+
+        let members = self.enum_values(&e);
+
+        // Usages of const enums are inlined. Const enums in a namespace are kept
+        // because they can be accessed like `Ns.Foo.a`.
+        if e.is_const && self.ns.is_none() && members.iter().all(|m| m.value.is_some()) {
+            return;
+        }
+
+        self.declare_var(&e.id, exported, e.span, declared, stmts);
+
+        let id = e.id;
+        let arg = self.iife_arg(&id, exported);
+        let body = e
+            .members
+            .into_iter()
+            .zip(members)
+            .map(|(m, member)| {
+                let key = Expr::Lit(Lit::Str(Str {
                     span: DUMMY_SP,
-                    left: box Expr::Ident(id.clone()),
-                    op: op!("||"),
-                    right: box Expr::Assign(AssignExpr {
+                    value: member.name.clone(),
+                    has_escape: false,
+                    raw: None,
+                }));
+
+                let (value, is_str) = match member.value {
+                    Some(value) => {
+                        let is_str = match value {
+                            EnumValue::Str(..) => true,
+                            EnumValue::Num(..) => false,
+                        };
+                        (box value.into_expr(DUMMY_SP), is_str)
+                    }
+                    None => match member.init {
+                        Some(init) => (init, false),
+                        None => {
+                            HANDLER.with(|handler| {
+                                handler
+                                    .struct_span_err(m.span, "enum member must have initializer")
+                                    .emit()
+                            });
+                            (undefined(DUMMY_SP), false)
+                        }
+                    },
+                };
+
+                // Foo["a"] = "a"
+                let assign = AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Expr(box id.clone().computed_member(key)),
+                    op: op!("="),
+                    right: value,
+                };
+
+                if is_str {
+                    return assign.into_stmt();
+                }
+
+                // Foo[Foo["a"] = 0] = "a";
+                AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Expr(box id.clone().computed_member(assign)),
+                    op: op!("="),
+                    right: box Expr::Lit(Lit::Str(Str {
                         span: DUMMY_SP,
-                        left: PatOrExpr::Pat(Pat::Ident(id.clone()).into()),
-                        op: op!("="),
-                        right: box Expr::Object(ObjectLit {
+                        value: member.name,
+                        has_escape: false,
+                        raw: None,
+                    })),
+                }
+                .into_stmt()
+            })
+            .collect();
+
+        stmts.push(
+            CallExpr {
+                span: DUMMY_SP,
+                callee: FnExpr {
+                    ident: None,
+                    function: Function {
+                        span: DUMMY_SP,
+                        decorators: Default::default(),
+                        param_decorators: Default::default(),
+                        is_async: false,
+                        is_generator: false,
+                        type_params: Default::default(),
+                        params: vec![Pat::Ident(id.clone())],
+                        body: Some(BlockStmt {
                             span: DUMMY_SP,
-                            props: vec![],
+                            stmts: body,
                         }),
-                    }),
+                        return_type: Default::default(),
+                    },
                 }
-                .as_arg()],
+                .as_callee(),
+                args: vec![arg],
                 type_args: Default::default(),
             }
             .into_stmt()
             .into(),
         )
     }
+
+    /// Computes values of members of an enum.
+    fn enum_values(&self, e: &TsEnumDecl) -> Vec<EnumMember> {
+        let mut members = Vec::with_capacity(e.members.len());
+        let mut next = Some(0.0);
+
+        for m in &e.members {
+            let name = match m.id {
+                TsEnumMemberId::Ident(ref i) => i.sym.clone(),
+                TsEnumMemberId::Str(ref s) => s.value.clone(),
+            };
+
+            let (value, init) = match m.init {
+                Some(ref init) => {
+                    let init = init.clone().fold_with(&mut EnumValueReplacer {
+                        id: &e.id,
+                        members: &members,
+                        enums: &self.enums,
+                        shadowed: vec![],
+                    });
+                    let value = EnumValue::from_expr(&init.clone().fold_with(&mut SimplifyExpr));
+
+                    match value {
+                        Some(value) => (Some(value), None),
+                        None => (None, Some(init)),
+                    }
+                }
+                None => (next.map(EnumValue::Num), None),
+            };
+
+            next = match value {
+                Some(EnumValue::Num(v)) => Some(v + 1.0),
+                _ => None,
+            };
+            members.push(EnumMember { name, value, init });
+        }
+
+        members
+    }
+
+    /// Collects values of enums so that usages of const enums can be inlined.
+    fn collect_enums(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ref import)) => {
+                    let enums = match self.config.enums.get(&import.src.value) {
+                        Some(enums) => enums,
+                        None => continue,
+                    };
+
+                    for s in &import.specifiers {
+                        if let ImportSpecifier::Specific(ref s) = *s {
+                            let name = s.imported.as_ref().unwrap_or(&s.local);
+                            if let Some(members) = enums.get(&name.sym) {
+                                self.enums.insert(
+                                    (s.local.sym.clone(), s.local.span.ctxt()),
+                                    EnumInfo {
+                                        inline: true,
+                                        members: members.clone(),
+                                    },
+                                );
+                            }
+                        }
+                    }
+                }
+
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ref e)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(ref e),
+                    ..
+                })) => {
+                    let members = self.enum_values(e);
+                    let info = self
+                        .enums
+                        .entry((e.id.sym.clone(), e.id.span.ctxt()))
+                        .or_insert_with(|| EnumInfo {
+                            inline: e.is_const,
+                            members: Default::default(),
+                        });
+                    info.members.extend(
+                        members
+                            .into_iter()
+                            .filter_map(|m| Some((m.name, m.value?))),
+                    );
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    fn is_type_only_import(&self, id: &Ident) -> bool {
+        match self
+            .scope
+            .imported_idents
+            .get(&(id.sym.clone(), id.span.ctxt()))
+        {
+            Some(&DeclInfo {
+                has_type: true,
+                has_concrete: false,
+            }) => true,
+            _ => false,
+        }
+    }
 }
 
 impl Fold<ImportDecl> for Strip {
@@ -500,6 +1065,23 @@ impl Fold<ImportDecl> for Strip {
     }
 }
 
+impl Fold<TsImportEqualsDecl> for Strip {
+    fn fold(&mut self, import: TsImportEqualsDecl) -> TsImportEqualsDecl {
+        if let Phase::Analysis = self.phase {
+            self.scope.imported_idents.insert(
+                (import.id.sym.clone(), import.id.span.ctxt()),
+                Default::default(),
+            );
+        }
+
+        // Folding `id` would mark the import as used.
+        TsImportEqualsDecl {
+            module_ref: import.module_ref.fold_with(self),
+            ..import
+        }
+    }
+}
+
 impl Fold<Ident> for Strip {
     fn fold(&mut self, i: Ident) -> Ident {
         self.scope
@@ -507,7 +1089,15 @@ impl Fold<Ident> for Strip {
             .entry((i.sym.clone(), i.span.ctxt()))
             .and_modify(|v| v.has_concrete = true);
 
+        // The span of `a` in `a?: T` covers the type annotation.
+        let span = if i.type_ann.is_some() && !i.span.is_dummy() {
+            i.span.with_hi(i.span.lo() + BytePos(i.sym.len() as u32))
+        } else {
+            i.span
+        };
+
         Ident {
+            span,
             optional: false,
             ..i.fold_children(self)
         }
@@ -546,16 +1136,52 @@ impl Fold<Decl> for Strip {
     }
 }
 
+impl Fold<TsModuleDecl> for Strip {
+    fn fold(&mut self, m: TsModuleDecl) -> TsModuleDecl {
+        // Ambient
+        if m.declare {
+            return m;
+        }
+
+        let old = self.ns.take();
+        self.ns = match m.id {
+            TsModuleName::Ident(ref id) => Some(id.clone()),
+            TsModuleName::Str(..) => None,
+        };
+        let m = m.fold_children(self);
+        self.ns = old;
+
+        m
+    }
+}
+
+impl Fold<TsNamespaceDecl> for Strip {
+    fn fold(&mut self, decl: TsNamespaceDecl) -> TsNamespaceDecl {
+        let old = mem::replace(&mut self.ns, Some(decl.id.clone()));
+        let decl = decl.fold_children(self);
+        self.ns = old;
+
+        decl
+    }
+}
+
 impl Fold<Stmt> for Strip {
     fn fold(&mut self, stmt: Stmt) -> Stmt {
         let stmt = stmt.fold_children(self);
 
         match stmt {
             Stmt::Decl(decl) => match decl {
-                Decl::TsInterface(..) | Decl::TsModule(..) | Decl::TsTypeAlias(..) => {
+                Decl::TsInterface(..)
+                | Decl::TsModule(TsModuleDecl { declare: true, .. })
+                | Decl::TsModule(TsModuleDecl {
+                    id: TsModuleName::Str(..),
+                    ..
+                })
+                | Decl::TsTypeAlias(..) => {
                     let span = decl.span();
                     Stmt::Empty(EmptyStmt { span })
                 }
+                // Namespaces are handled by Fold<Vec<ModuleItem>>
                 _ => Stmt::Decl(decl),
             },
             _ => stmt,
@@ -620,15 +1246,24 @@ impl Fold<Expr> for Strip {
 impl Fold<Module> for Strip {
     fn fold(&mut self, node: Module) -> Module {
         let node = validate!(node);
+        self.collect_enums(&node.body);
 
-        validate!(node.fold_children(self))
+        let node = node.fold_children(self);
+        // Types are stripped at this point, so parameters and variables are the
+        // only things which can shadow an enum.
+        let node = node.fold_with(&mut EnumInliner {
+            enums: &self.enums,
+            shadowed: vec![],
+        });
+
+        validate!(node)
     }
 }
 
 fn module_ref_to_expr(r: TsModuleRef) -> Expr {
     match r {
         TsModuleRef::TsEntityName(name) => ts_entity_name_to_expr(name),
-        _ => unimplemented!("export import A = B where B != TsEntityName\nB: {:?}", r),
+        _ => unreachable!("import foo = require('foo') should be handled by module transforms"),
     }
 }
 
@@ -644,3 +1279,454 @@ fn ts_entity_name_to_expr(n: TsEntityName) -> Expr {
         .into(),
     }
 }
+
+/// Returns true if the namespace contains values.
+fn is_instantiated(body: &TsNamespaceBody) -> bool {
+    match *body {
+        TsNamespaceBody::TsModuleBlock(ref block) => block.body.iter().any(|item| match *item {
+            ModuleItem::Stmt(Stmt::Decl(ref decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                match *decl {
+                    Decl::TsInterface(..) | Decl::TsTypeAlias(..) => false,
+                    Decl::TsModule(ref m) => {
+                        !m.declare && m.body.as_ref().map_or(false, is_instantiated)
+                    }
+                    Decl::Fn(FnDecl {
+                        function: Function { body: None, .. },
+                        ..
+                    }) => false,
+                    _ => true,
+                }
+            }
+            ModuleItem::Stmt(Stmt::Empty(..)) => false,
+            _ => true,
+        }),
+        TsNamespaceBody::TsNamespaceDecl(ref decl) => is_instantiated(&decl.body),
+    }
+}
+
+/// Implements scope tracking for a folder with a `shadowed: Vec<Id>` field.
+///
+/// Folders run before the resolver use this to detect shadowing, because it
+/// cannot be detected by comparing syntax contexts.
+macro_rules! track_scopes {
+    ($T:ty) => {
+        impl $T {
+            /// Folds children of `node` while `ids` are shadowed.
+            fn fold_in_scope<N>(&mut self, ids: Vec<Id>, node: N) -> N
+            where
+                N: FoldWith<Self>,
+            {
+                let len = self.shadowed.len();
+                self.shadowed.extend(ids);
+                let node = node.fold_children(self);
+                self.shadowed.truncate(len);
+
+                node
+            }
+        }
+
+        impl Fold<Function> for $T {
+            fn fold(&mut self, f: Function) -> Function {
+                let mut ids: Vec<Id> = find_ids(&f.params);
+                if let Some(ref body) = f.body {
+                    body.visit_with(&mut HoistedVarFinder { ids: &mut ids });
+                }
+
+                self.fold_in_scope(ids, f)
+            }
+        }
+
+        impl Fold<Constructor> for $T {
+            fn fold(&mut self, c: Constructor) -> Constructor {
+                let mut ids: Vec<Id> = find_ids(&c.params);
+                if let Some(ref body) = c.body {
+                    body.visit_with(&mut HoistedVarFinder { ids: &mut ids });
+                }
+
+                self.fold_in_scope(ids, c)
+            }
+        }
+
+        impl Fold<ArrowExpr> for $T {
+            fn fold(&mut self, f: ArrowExpr) -> ArrowExpr {
+                let mut ids: Vec<Id> = find_ids(&f.params);
+                if let BlockStmtOrExpr::BlockStmt(ref body) = f.body {
+                    body.visit_with(&mut HoistedVarFinder { ids: &mut ids });
+                }
+
+                self.fold_in_scope(ids, f)
+            }
+        }
+
+        impl Fold<FnExpr> for $T {
+            fn fold(&mut self, f: FnExpr) -> FnExpr {
+                let ids = f.ident.iter().map(id).collect();
+
+                self.fold_in_scope(ids, f)
+            }
+        }
+
+        impl Fold<ClassExpr> for $T {
+            fn fold(&mut self, c: ClassExpr) -> ClassExpr {
+                let ids = c.ident.iter().map(id).collect();
+
+                self.fold_in_scope(ids, c)
+            }
+        }
+
+        impl Fold<BlockStmt> for $T {
+            fn fold(&mut self, b: BlockStmt) -> BlockStmt {
+                let mut ids = vec![];
+                for stmt in &b.stmts {
+                    match *stmt {
+                        Stmt::Decl(Decl::Fn(ref f)) => ids.push(id(&f.ident)),
+                        Stmt::Decl(Decl::Class(ref c)) => ids.push(id(&c.ident)),
+                        Stmt::Decl(Decl::Var(ref var)) => ids.extend(block_scoped_ids(var)),
+                        _ => {}
+                    }
+                }
+
+                self.fold_in_scope(ids, b)
+            }
+        }
+
+        impl Fold<CatchClause> for $T {
+            fn fold(&mut self, c: CatchClause) -> CatchClause {
+                let ids = match c.param {
+                    Some(ref param) => find_ids(param),
+                    None => vec![],
+                };
+
+                self.fold_in_scope(ids, c)
+            }
+        }
+
+        impl Fold<ForStmt> for $T {
+            fn fold(&mut self, s: ForStmt) -> ForStmt {
+                let ids = match s.init {
+                    Some(VarDeclOrExpr::VarDecl(ref var)) => block_scoped_ids(var),
+                    _ => vec![],
+                };
+
+                self.fold_in_scope(ids, s)
+            }
+        }
+
+        impl Fold<ForInStmt> for $T {
+            fn fold(&mut self, s: ForInStmt) -> ForInStmt {
+                let ids = match s.left {
+                    VarDeclOrPat::VarDecl(ref var) => block_scoped_ids(var),
+                    _ => vec![],
+                };
+
+                self.fold_in_scope(ids, s)
+            }
+        }
+
+        impl Fold<ForOfStmt> for $T {
+            fn fold(&mut self, s: ForOfStmt) -> ForOfStmt {
+                let ids = match s.left {
+                    VarDeclOrPat::VarDecl(ref var) => block_scoped_ids(var),
+                    _ => vec![],
+                };
+
+                self.fold_in_scope(ids, s)
+            }
+        }
+    };
+}
+
+/// Replaces references to exported variables of a namespace with member
+/// expressions like `Foo.a`.
+struct NsVarReplacer<'a> {
+    ns: &'a Ident,
+    vars: &'a [Id],
+    /// Variables declared in the current scope or its parents, which shadow
+    /// the exported variables.
+    shadowed: Vec<Id>,
+    in_assign_target: bool,
+}
+
+impl NsVarReplacer<'_> {
+    fn replace(&self, i: &Ident) -> Option<Expr> {
+        let i_id = id(i);
+        if self.vars.contains(&i_id) && !self.shadowed.contains(&i_id) {
+            Some(self.ns.clone().member(Ident::new(i.sym.clone(), i.span)))
+        } else {
+            None
+        }
+    }
+}
+
+track_scopes!(NsVarReplacer<'_>);
+
+/// Returns names declared by `let` or `const`.
+fn block_scoped_ids(var: &VarDecl) -> Vec<Id> {
+    match var.kind {
+        VarDeclKind::Var => vec![],
+        _ => find_ids(&var.decls),
+    }
+}
+
+/// Collects names declared by `var` in a function body.
+struct HoistedVarFinder<'a> {
+    ids: &'a mut Vec<Id>,
+}
+
+impl Visit<VarDecl> for HoistedVarFinder<'_> {
+    fn visit(&mut self, var: &VarDecl) {
+        if var.kind == VarDeclKind::Var {
+            self.ids.extend(find_ids::<_, Id>(&var.decls));
+        }
+    }
+}
+
+impl Visit<Function> for HoistedVarFinder<'_> {
+    fn visit(&mut self, _: &Function) {}
+}
+
+impl Visit<Expr> for HoistedVarFinder<'_> {
+    fn visit(&mut self, _: &Expr) {}
+}
+
+impl Fold<Expr> for NsVarReplacer<'_> {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let old = self.in_assign_target;
+        self.in_assign_target = false;
+        let e = match e {
+            Expr::Ident(i) => self.replace(&i).unwrap_or(Expr::Ident(i)),
+            Expr::Member(MemberExpr {
+                span,
+                obj,
+                prop,
+                computed,
+            }) => Expr::Member(MemberExpr {
+                span,
+                obj: obj.fold_with(self),
+                prop: if computed { prop.fold_with(self) } else { prop },
+                computed,
+            }),
+            _ => e.fold_children(self),
+        };
+        self.in_assign_target = old;
+
+        e
+    }
+}
+
+impl Fold<PatOrExpr> for NsVarReplacer<'_> {
+    fn fold(&mut self, n: PatOrExpr) -> PatOrExpr {
+        match n {
+            PatOrExpr::Pat(box Pat::Ident(i)) => match self.replace(&i) {
+                Some(e) => PatOrExpr::Expr(box e),
+                None => PatOrExpr::Pat(box Pat::Ident(i)),
+            },
+            PatOrExpr::Pat(pat) => {
+                let old = self.in_assign_target;
+                self.in_assign_target = true;
+                let pat = pat.fold_with(self);
+                self.in_assign_target = old;
+
+                PatOrExpr::Pat(pat)
+            }
+            PatOrExpr::Expr(e) => PatOrExpr::Expr(e.fold_with(self)),
+        }
+    }
+}
+
+impl Fold<Pat> for NsVarReplacer<'_> {
+    fn fold(&mut self, pat: Pat) -> Pat {
+        match pat {
+            Pat::Ident(i) => {
+                if self.in_assign_target {
+                    if let Some(e) = self.replace(&i) {
+                        return Pat::Expr(box e);
+                    }
+                }
+
+                Pat::Ident(i)
+            }
+            _ => pat.fold_children(self),
+        }
+    }
+}
+
+impl Fold<ObjectPatProp> for NsVarReplacer<'_> {
+    fn fold(&mut self, p: ObjectPatProp) -> ObjectPatProp {
+        match p {
+            // ({ a } = foo) => ({ a: Foo.a } = foo)
+            ObjectPatProp::Assign(AssignPatProp { span, key, value }) => {
+                let replaced = if self.in_assign_target {
+                    self.replace(&key)
+                } else {
+                    None
+                };
+
+                match replaced {
+                    Some(e) => {
+                        let target = box Pat::Expr(box e);
+                        ObjectPatProp::KeyValue(KeyValuePatProp {
+                            key: PropName::Ident(key),
+                            value: match value {
+                                Some(default) => box Pat::Assign(AssignPat {
+                                    span,
+                                    left: target,
+                                    right: default.fold_with(self),
+                                    type_ann: None,
+                                }),
+                                None => target,
+                            },
+                        })
+                    }
+                    None => ObjectPatProp::Assign(AssignPatProp {
+                        span,
+                        key,
+                        value: value.fold_with(self),
+                    }),
+                }
+            }
+            _ => p.fold_children(self),
+        }
+    }
+}
+
+impl Fold<Prop> for NsVarReplacer<'_> {
+    fn fold(&mut self, p: Prop) -> Prop {
+        match p {
+            // { a } => { a: Foo.a }
+            Prop::Shorthand(i) => match self.replace(&i) {
+                Some(e) => Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i),
+                    value: box e,
+                }),
+                None => Prop::Shorthand(i),
+            },
+            _ => p.fold_children(self),
+        }
+    }
+}
+
+fn member_prop_name(e: &MemberExpr) -> Option<JsWord> {
+    match *e.prop {
+        Expr::Ident(ref i) if !e.computed => Some(i.sym.clone()),
+        Expr::Lit(Lit::Str(ref s)) if e.computed => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+/// Replaces references to enum members in an initializer of an enum member.
+struct EnumValueReplacer<'a> {
+    /// The enum being declared.
+    id: &'a Ident,
+    /// Members declared before the current member.
+    members: &'a [EnumMember],
+    enums: &'a HashMap<(JsWord, SyntaxContext), EnumInfo>,
+    /// Variables declared in the initializer, which shadow enums and members.
+    shadowed: Vec<Id>,
+}
+
+impl EnumValueReplacer<'_> {
+    fn member(&self, name: &JsWord, span: Span) -> Option<Expr> {
+        let m = self.members.iter().rev().find(|m| m.name == *name)?;
+
+        Some(match m.value {
+            Some(ref value) => value.clone().into_expr(span),
+            None => self.id.clone().member(Ident::new(name.clone(), span)),
+        })
+    }
+}
+
+impl Fold<Expr> for EnumValueReplacer<'_> {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            // enum Foo { a = 1, b = a << 1 }
+            Expr::Ident(i) => {
+                if self.shadowed.contains(&id(&i)) {
+                    return Expr::Ident(i);
+                }
+                self.member(&i.sym, i.span).unwrap_or(Expr::Ident(i))
+            }
+
+            Expr::Member(e) => {
+                let value = match e.obj {
+                    ExprOrSuper::Expr(box Expr::Ident(ref obj))
+                        if !self.shadowed.contains(&id(obj)) =>
+                    {
+                        member_prop_name(&e).and_then(|name| {
+                            if obj.sym == self.id.sym && obj.span.ctxt() == self.id.span.ctxt() {
+                                self.member(&name, e.span)
+                            } else {
+                                self.enums
+                                    .get(&(obj.sym.clone(), obj.span.ctxt()))
+                                    .and_then(|info| info.members.get(&name))
+                                    .map(|value| value.clone().into_expr(e.span))
+                            }
+                        })
+                    }
+                    _ => None,
+                };
+
+                match value {
+                    Some(value) => value,
+                    None => Expr::Member(MemberExpr {
+                        obj: e.obj.fold_with(self),
+                        prop: if e.computed {
+                            e.prop.fold_with(self)
+                        } else {
+                            e.prop
+                        },
+                        ..e
+                    }),
+                }
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+track_scopes!(EnumValueReplacer<'_>);
+
+/// Replaces `Foo.a` with the value of the member if `Foo` is a const enum.
+struct EnumInliner<'a> {
+    enums: &'a HashMap<(JsWord, SyntaxContext), EnumInfo>,
+    /// Variables declared in the current scope or its parents, which shadow
+    /// the enums.
+    shadowed: Vec<Id>,
+}
+
+impl EnumInliner<'_> {
+    fn inline(&self, e: &MemberExpr) -> Option<Expr> {
+        let obj = match e.obj {
+            ExprOrSuper::Expr(box Expr::Ident(ref obj)) => obj,
+            _ => return None,
+        };
+        if self.shadowed.contains(&id(obj)) {
+            return None;
+        }
+        let info = self.enums.get(&(obj.sym.clone(), obj.span.ctxt()))?;
+        if !info.inline {
+            return None;
+        }
+
+        let value = info.members.get(&member_prop_name(e)?)?;
+        Some(value.clone().into_expr(e.span))
+    }
+}
+
+impl Fold<Expr> for EnumInliner<'_> {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        if let Expr::Member(ref m) = e {
+            if let Some(value) = self.inline(m) {
+                return value;
+            }
+        }
+
+        e
+    }
+}
+
+track_scopes!(EnumInliner<'_>);
@@ -0,0 +1,855 @@
+use crate::{pass::Pass, util::HANDLER};
+use ast::*;
+use hashbrown::HashSet;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Fold, FoldWith, Span, Spanned, Visit, VisitWith, DUMMY_SP};
+
+/// Creates the declaration surface (`.d.ts`) of a typescript module.
+///
+/// This pass must run before [strip](super::strip) because it relies on type
+/// annotations.
+///
+///  - Bodies of functions and methods are removed.
+///  - Private members of classes are removed.
+///  - Initializers are replaced with their annotated types.
+///  - Values which are not exported are removed, unless the exported
+///    declarations refer to them.
+///
+/// If a type cannot be known without type inference, an error is reported
+/// using [HANDLER] instead of guessing the type.
+pub fn dts() -> impl Pass {
+    Dts::default()
+}
+
+#[derive(Default)]
+struct Dts {
+    /// True while processing the body of a namespace. `declare` is not allowed
+    /// there, as the context is already ambient.
+    in_ambient: bool,
+}
+
+enum Item {
+    /// Part of the declaration surface.
+    Keep(ModuleItem),
+    /// A declaration which is not exported. It's kept only if another
+    /// declaration refers to it.
+    Local(Vec<JsWord>, Decl),
+}
+
+impl Fold<Module> for Dts {
+    fn fold(&mut self, m: Module) -> Module {
+        Module {
+            body: self.handle_items(m.body),
+            ..m
+        }
+    }
+}
+
+impl Dts {
+    fn handle_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        // A file without import / export is a global script, and all
+        // declarations in it are visible from outside.
+        let is_module = self.in_ambient
+            || items.iter().any(|item| match *item {
+                ModuleItem::ModuleDecl(..) => true,
+                _ => false,
+            });
+
+        let mut exported_names = HashSet::default();
+        if is_module {
+            for item in &items {
+                if let ModuleItem::ModuleDecl(ref decl) = *item {
+                    collect_exported_names(decl, &mut exported_names);
+                }
+            }
+        }
+
+        let mut buf = Vec::with_capacity(items.len());
+        let mut last_overload: Option<JsWord> = None;
+
+        for item in items {
+            // Implementation signature of an overloaded function should be
+            // removed.
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                    ref ident,
+                    ref function,
+                    ..
+                })))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl:
+                        Decl::Fn(FnDecl {
+                            ref ident,
+                            ref function,
+                            ..
+                        }),
+                    ..
+                })) => {
+                    let is_impl = function.body.is_some()
+                        && last_overload.as_ref().map_or(false, |name| *name == ident.sym);
+
+                    last_overload = if function.body.is_none() {
+                        Some(ident.sym.clone())
+                    } else {
+                        None
+                    };
+
+                    if is_impl {
+                        continue;
+                    }
+                }
+                _ => last_overload = None,
+            }
+
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => {
+                    buf.push(Item::Keep(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(
+                        ExportDecl {
+                            span,
+                            decl: self.handle_decl(decl),
+                        },
+                    ))))
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl,
+                })) => {
+                    let decl = match decl {
+                        DefaultDecl::Class(c) => DefaultDecl::Class(ClassExpr {
+                            class: self.handle_class(c.class),
+                            ..c
+                        }),
+                        DefaultDecl::Fn(f) => DefaultDecl::Fn(FnExpr {
+                            function: self.handle_fn(f.function, true),
+                            ..f
+                        }),
+                        DefaultDecl::TsInterfaceDecl(..) => decl,
+                    };
+
+                    buf.push(Item::Keep(ModuleItem::ModuleDecl(
+                        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, decl }),
+                    )))
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    match *export.expr {
+                        Expr::Ident(..) => buf.push(Item::Keep(ModuleItem::ModuleDecl(
+                            ModuleDecl::ExportDefaultExpr(export),
+                        ))),
+                        _ => {
+                            // declare const _default: T;
+                            // export default _default;
+                            let ty = match type_of_expr(&export.expr, true) {
+                                Some(ty) => ty,
+                                None => {
+                                    report(
+                                        export.expr.span(),
+                                        "cannot determine the type of the default export; \
+                                         declare it as a variable with a type annotation",
+                                    );
+                                    continue;
+                                }
+                            };
+                            let id = Ident::new("_default".into(), DUMMY_SP);
+                            buf.push(Item::Keep(ModuleItem::Stmt(Stmt::Decl(Decl::Var(
+                                VarDecl {
+                                    span: export.span,
+                                    kind: VarDeclKind::Const,
+                                    declare: !self.in_ambient,
+                                    decls: vec![VarDeclarator {
+                                        span: export.span,
+                                        name: Pat::Ident(Ident {
+                                            type_ann: Some(TsTypeAnn {
+                                                span: DUMMY_SP,
+                                                type_ann: ty,
+                                            }),
+                                            ..id.clone()
+                                        }),
+                                        init: None,
+                                        definite: false,
+                                    }],
+                                },
+                            )))));
+                            buf.push(Item::Keep(ModuleItem::ModuleDecl(
+                                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                                    span: export.span,
+                                    expr: box Expr::Ident(id),
+                                }),
+                            )));
+                        }
+                    }
+                }
+
+                ModuleItem::ModuleDecl(decl) => buf.push(Item::Keep(ModuleItem::ModuleDecl(decl))),
+
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    let names = declared_names(&decl);
+                    if !is_module || names.iter().any(|name| exported_names.contains(name)) {
+                        buf.push(Item::Keep(ModuleItem::Stmt(Stmt::Decl(
+                            self.handle_decl(decl),
+                        ))))
+                    } else {
+                        buf.push(Item::Local(names, decl))
+                    }
+                }
+
+                // Other statements are not a part of the declaration surface.
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        // Include local declarations referenced from the declaration surface.
+        let mut refs = HashSet::default();
+        for item in &buf {
+            if let Item::Keep(ref item) = *item {
+                item.visit_with(&mut RefCollector { refs: &mut refs });
+            }
+        }
+
+        loop {
+            let mut changed = false;
+
+            buf = buf
+                .into_iter()
+                .map(|item| match item {
+                    Item::Local(names, decl) => {
+                        if names.iter().any(|name| refs.contains(name)) {
+                            changed = true;
+                            let decl = self.handle_decl(decl);
+                            decl.visit_with(&mut RefCollector { refs: &mut refs });
+                            Item::Keep(ModuleItem::Stmt(Stmt::Decl(decl)))
+                        } else {
+                            Item::Local(names, decl)
+                        }
+                    }
+                    _ => item,
+                })
+                .collect();
+
+            if !changed {
+                break;
+            }
+        }
+
+        buf.into_iter()
+            .filter_map(|item| match item {
+                Item::Keep(item) => Some(item),
+                Item::Local(..) => None,
+            })
+            .collect()
+    }
+
+    fn handle_decl(&mut self, decl: Decl) -> Decl {
+        let declare = !self.in_ambient;
+
+        match decl {
+            // Already ambient.
+            Decl::Class(ClassDecl { declare: true, .. })
+            | Decl::Fn(FnDecl { declare: true, .. })
+            | Decl::Var(VarDecl { declare: true, .. })
+            | Decl::TsEnum(TsEnumDecl { declare: true, .. })
+            | Decl::TsModule(TsModuleDecl { declare: true, .. })
+            | Decl::TsInterface(..)
+            | Decl::TsTypeAlias(..) => decl,
+
+            Decl::Class(c) => Decl::Class(ClassDecl {
+                declare,
+                class: self.handle_class(c.class),
+                ..c
+            }),
+            Decl::Fn(f) => Decl::Fn(FnDecl {
+                declare,
+                function: self.handle_fn(f.function, true),
+                ..f
+            }),
+            Decl::Var(v) => {
+                let kind = v.kind;
+
+                Decl::Var(VarDecl {
+                    declare,
+                    decls: v
+                        .decls
+                        .into_iter()
+                        .map(|d| handle_var_declarator(kind, d))
+                        .collect(),
+                    ..v
+                })
+            }
+            Decl::TsEnum(e) => Decl::TsEnum(TsEnumDecl { declare, ..e }),
+            Decl::TsModule(m) => {
+                let old = self.in_ambient;
+                self.in_ambient = true;
+                let body = m.body.map(|body| self.handle_ns_body(body));
+                self.in_ambient = old;
+
+                Decl::TsModule(TsModuleDecl { declare, body, ..m })
+            }
+        }
+    }
+
+    fn handle_ns_body(&mut self, body: TsNamespaceBody) -> TsNamespaceBody {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                body: self.handle_items(block.body),
+                ..block
+            }),
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                    declare: false,
+                    body: box self.handle_ns_body(*decl.body),
+                    ..decl
+                })
+            }
+        }
+    }
+
+    fn handle_class(&mut self, class: Class) -> Class {
+        if let Some(ref super_class) = class.super_class {
+            match **super_class {
+                Expr::Ident(..) | Expr::Member(..) => {}
+                _ => report(
+                    super_class.span(),
+                    "cannot emit declaration of a class extending an expression which is not \
+                     an entity name",
+                ),
+            }
+        }
+
+        let mut body = Vec::with_capacity(class.body.len());
+        let mut last_overload: Option<PropName> = None;
+
+        for member in class.body {
+            match member {
+                ClassMember::Constructor(c) => {
+                    if c.accessibility == Some(Accessibility::Private) {
+                        body.push(ClassMember::Constructor(Constructor {
+                            params: vec![],
+                            body: None,
+                            ..c
+                        }));
+                        continue;
+                    }
+
+                    let mut props = vec![];
+                    let params = c
+                        .params
+                        .into_iter()
+                        .map(|param| match param {
+                            PatOrTsParamProp::Pat(pat) => PatOrTsParamProp::Pat(handle_param(pat)),
+                            PatOrTsParamProp::Param(p) => {
+                                PatOrTsParamProp::Pat(handle_param(p.pat))
+                            }
+                            PatOrTsParamProp::TsParamProp(p) => {
+                                let pat = match p.param {
+                                    TsParamPropParam::Ident(i) => Pat::Ident(i),
+                                    TsParamPropParam::Assign(a) => Pat::Assign(a),
+                                };
+                                let pat = handle_param(pat);
+
+                                if p.accessibility != Some(Accessibility::Private) {
+                                    if let Pat::Ident(ref i) = pat {
+                                        props.push(ClassMember::ClassProp(ClassProp {
+                                            span: p.span,
+                                            key: box Expr::Ident(Ident {
+                                                type_ann: None,
+                                                optional: false,
+                                                ..i.clone()
+                                            }),
+                                            value: None,
+                                            type_ann: i.type_ann.clone(),
+                                            is_static: false,
+                                            decorators: vec![],
+                                            computed: false,
+                                            accessibility: p.accessibility,
+                                            is_abstract: false,
+                                            is_optional: i.optional,
+                                            readonly: p.readonly,
+                                            definite: false,
+                                        }))
+                                    }
+                                }
+
+                                PatOrTsParamProp::Pat(pat)
+                            }
+                        })
+                        .collect();
+
+                    body.extend(props);
+                    body.push(ClassMember::Constructor(Constructor {
+                        params,
+                        body: None,
+                        ..c
+                    }));
+                }
+
+                ClassMember::Method(m) => {
+                    let is_impl = m.function.body.is_some()
+                        && last_overload
+                            .as_ref()
+                            .map_or(false, |key| prop_name_eq(key, &m.key));
+                    last_overload = if m.function.body.is_none() {
+                        Some(m.key.clone())
+                    } else {
+                        None
+                    };
+
+                    if is_impl || m.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    let needs_return_type =
+                        m.kind == MethodKind::Method || m.kind == MethodKind::Getter;
+                    body.push(ClassMember::Method(ClassMethod {
+                        function: self.handle_fn(m.function, needs_return_type),
+                        ..m
+                    }))
+                }
+
+                ClassMember::ClassProp(p) => {
+                    if p.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    let type_ann = match p.type_ann {
+                        Some(ty) => Some(ty),
+                        None => match p.value {
+                            Some(ref value) => match type_of_expr(value, p.readonly) {
+                                Some(ty) => Some(TsTypeAnn {
+                                    span: DUMMY_SP,
+                                    type_ann: ty,
+                                }),
+                                None => {
+                                    report(
+                                        p.key.span(),
+                                        "cannot determine the type of the property; add a type \
+                                         annotation",
+                                    );
+                                    None
+                                }
+                            },
+                            None => None,
+                        },
+                    };
+
+                    body.push(ClassMember::ClassProp(ClassProp {
+                        value: None,
+                        type_ann,
+                        decorators: vec![],
+                        definite: false,
+                        ..p
+                    }))
+                }
+
+                // Private names and static blocks are not a part of the declaration
+                // surface.
+                ClassMember::PrivateMethod(..)
+                | ClassMember::PrivateProp(..)
+                | ClassMember::StaticBlock(..) => {}
+
+                ClassMember::TsIndexSignature(..) => body.push(member),
+            }
+        }
+
+        Class {
+            decorators: vec![],
+            body,
+            ..class
+        }
+    }
+
+    fn handle_fn(&mut self, f: Function, needs_return_type: bool) -> Function {
+        if needs_return_type && f.return_type.is_none() && f.body.is_some() {
+            report(
+                f.span,
+                "cannot determine the return type of the function; add a return type annotation",
+            );
+        }
+
+        Function {
+            params: f.params.into_iter().map(handle_param).collect(),
+            decorators: vec![],
+            param_decorators: vec![],
+            body: None,
+            // Not allowed in an ambient context.
+            is_async: false,
+            is_generator: false,
+            ..f
+        }
+    }
+}
+
+fn handle_var_declarator(kind: VarDeclKind, d: VarDeclarator) -> VarDeclarator {
+    let name = match d.name {
+        Pat::Ident(i) => {
+            if i.type_ann.is_some() {
+                Pat::Ident(i)
+            } else {
+                let ty = d
+                    .init
+                    .as_ref()
+                    .and_then(|init| type_of_expr(init, kind == VarDeclKind::Const));
+                if ty.is_none() {
+                    report(
+                        i.span,
+                        "cannot determine the type of the variable; add a type annotation",
+                    );
+                }
+
+                Pat::Ident(Ident {
+                    type_ann: ty.map(|type_ann| TsTypeAnn {
+                        span: DUMMY_SP,
+                        type_ann,
+                    }),
+                    ..i
+                })
+            }
+        }
+        name => {
+            match name {
+                Pat::Array(ArrayPat { type_ann: None, .. })
+                | Pat::Object(ObjectPat { type_ann: None, .. }) => report(
+                    name.span(),
+                    "cannot determine the types of destructured variables; add a type annotation",
+                ),
+                _ => {}
+            }
+
+            name.fold_with(&mut StripInitializers)
+        }
+    };
+
+    VarDeclarator {
+        name,
+        init: None,
+        definite: false,
+        ..d
+    }
+}
+
+/// Removes default values and makes parameters with a default value optional.
+fn handle_param(pat: Pat) -> Pat {
+    match pat {
+        Pat::Assign(AssignPat { left, right, .. }) => match *left {
+            Pat::Ident(i) => {
+                let type_ann = match i.type_ann {
+                    Some(ty) => Some(ty),
+                    None => match type_of_expr(&right, false) {
+                        Some(type_ann) => Some(TsTypeAnn {
+                            span: DUMMY_SP,
+                            type_ann,
+                        }),
+                        None => {
+                            report(
+                                i.span,
+                                "cannot determine the type of the parameter; add a type \
+                                 annotation",
+                            );
+                            None
+                        }
+                    },
+                };
+
+                Pat::Ident(Ident {
+                    optional: true,
+                    type_ann,
+                    ..i
+                })
+            }
+            left => left.fold_with(&mut StripInitializers),
+        },
+        _ => pat.fold_with(&mut StripInitializers),
+    }
+}
+
+/// Removes initializers in binding patterns, which are not allowed in an
+/// ambient context.
+struct StripInitializers;
+
+impl Fold<Pat> for StripInitializers {
+    fn fold(&mut self, pat: Pat) -> Pat {
+        let pat = pat.fold_children(self);
+
+        match pat {
+            Pat::Assign(AssignPat { left, .. }) => *left,
+            _ => pat,
+        }
+    }
+}
+
+impl Fold<AssignPatProp> for StripInitializers {
+    fn fold(&mut self, p: AssignPatProp) -> AssignPatProp {
+        AssignPatProp { value: None, ..p }
+    }
+}
+
+impl Fold<Expr> for StripInitializers {
+    fn fold(&mut self, e: Expr) -> Expr {
+        e
+    }
+}
+
+/// Returns the type of `e` if it can be known without type inference.
+///
+/// If `is_const` is true, literal types are not widened.
+fn type_of_expr(e: &Expr, is_const: bool) -> Option<Box<TsType>> {
+    let span = e.span();
+
+    let kwd = |kind| {
+        Some(box TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        }))
+    };
+    let lit = |lit| {
+        Some(box TsType::TsLitType(TsLitType {
+            span: DUMMY_SP,
+            lit,
+        }))
+    };
+
+    match *e {
+        Expr::Lit(Lit::Str(ref s)) => {
+            if is_const {
+                lit(TsLit::Str(s.clone()))
+            } else {
+                kwd(TsKeywordTypeKind::TsStringKeyword)
+            }
+        }
+        Expr::Lit(Lit::Num(ref n)) => {
+            if is_const {
+                lit(TsLit::Number(n.clone()))
+            } else {
+                kwd(TsKeywordTypeKind::TsNumberKeyword)
+            }
+        }
+        Expr::Lit(Lit::Bool(b)) => {
+            if is_const {
+                lit(TsLit::Bool(b))
+            } else {
+                kwd(TsKeywordTypeKind::TsBooleanKeyword)
+            }
+        }
+        Expr::Lit(Lit::BigInt(..)) => kwd(TsKeywordTypeKind::TsBigIntKeyword),
+        Expr::Lit(Lit::Null(..)) if is_const => kwd(TsKeywordTypeKind::TsNullKeyword),
+        Expr::Lit(Lit::Regex(..)) => Some(box TsType::TsTypeRef(TsTypeRef {
+            span: DUMMY_SP,
+            type_name: TsEntityName::Ident(Ident::new("RegExp".into(), DUMMY_SP)),
+            type_params: None,
+        })),
+        Expr::Tpl(ref t) if t.exprs.is_empty() => kwd(TsKeywordTypeKind::TsStringKeyword),
+
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg: box Expr::Lit(Lit::Num(ref n)),
+            ..
+        }) => {
+            if is_const {
+                lit(TsLit::Number(Number {
+                    span,
+                    value: -n.value,
+                    raw: None,
+                }))
+            } else {
+                kwd(TsKeywordTypeKind::TsNumberKeyword)
+            }
+        }
+        Expr::Unary(UnaryExpr { op: op!("!"), .. }) => kwd(TsKeywordTypeKind::TsBooleanKeyword),
+        Expr::Unary(UnaryExpr {
+            op: op!("typeof"), ..
+        }) => kwd(TsKeywordTypeKind::TsStringKeyword),
+        Expr::Unary(UnaryExpr { op: op!("void"), .. }) => {
+            kwd(TsKeywordTypeKind::TsUndefinedKeyword)
+        }
+
+        Expr::TsAs(TsAsExpr { ref type_ann, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { ref type_ann, .. }) => Some(type_ann.clone()),
+
+        Expr::Paren(ParenExpr { ref expr, .. }) => type_of_expr(expr, is_const),
+
+        Expr::Arrow(ArrowExpr {
+            ref params,
+            return_type: Some(ref return_type),
+            ref type_params,
+            ..
+        })
+        | Expr::Fn(FnExpr {
+            function:
+                Function {
+                    ref params,
+                    return_type: Some(ref return_type),
+                    ref type_params,
+                    ..
+                },
+            ..
+        }) => {
+            let params = params
+                .iter()
+                .cloned()
+                .map(|p| match handle_param(p) {
+                    Pat::Ident(i) => Some(TsFnParam::Ident(i)),
+                    Pat::Array(a) => Some(TsFnParam::Array(a)),
+                    Pat::Rest(r) => Some(TsFnParam::Rest(r)),
+                    Pat::Object(o) => Some(TsFnParam::Object(o)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(box TsType::from(TsFnType {
+                span: DUMMY_SP,
+                params,
+                type_params: type_params.clone(),
+                type_ann: return_type.clone(),
+            }))
+        }
+
+        _ => None,
+    }
+}
+
+fn report(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
+
+fn prop_name_eq(l: &PropName, r: &PropName) -> bool {
+    match (l, r) {
+        (PropName::Ident(l), PropName::Ident(r)) => l.sym == r.sym,
+        (PropName::Str(l), PropName::Str(r)) => l.value == r.value,
+        (PropName::Num(l), PropName::Num(r)) => l.value == r.value,
+        _ => false,
+    }
+}
+
+fn declared_names(decl: &Decl) -> Vec<JsWord> {
+    match *decl {
+        Decl::Class(ClassDecl { ref ident, .. }) | Decl::Fn(FnDecl { ref ident, .. }) => {
+            vec![ident.sym.clone()]
+        }
+        Decl::Var(ref v) => {
+            let mut names = vec![];
+            for d in &v.decls {
+                pat_names(&d.name, &mut names);
+            }
+            names
+        }
+        Decl::TsInterface(TsInterfaceDecl { ref id, .. })
+        | Decl::TsTypeAlias(TsTypeAliasDecl { ref id, .. })
+        | Decl::TsEnum(TsEnumDecl { ref id, .. })
+        | Decl::TsModule(TsModuleDecl {
+            id: TsModuleName::Ident(ref id),
+            ..
+        }) => vec![id.sym.clone()],
+        Decl::TsModule(TsModuleDecl {
+            id: TsModuleName::Str(..),
+            ..
+        }) => vec![],
+    }
+}
+
+fn pat_names(pat: &Pat, names: &mut Vec<JsWord>) {
+    match *pat {
+        Pat::Ident(ref i) => names.push(i.sym.clone()),
+        Pat::Array(ref a) => {
+            for elem in a.elems.iter().filter_map(|e| e.as_ref()) {
+                pat_names(elem, names)
+            }
+        }
+        Pat::Object(ref o) => {
+            for prop in &o.props {
+                match *prop {
+                    ObjectPatProp::KeyValue(ref p) => pat_names(&p.value, names),
+                    ObjectPatProp::Assign(ref p) => names.push(p.key.sym.clone()),
+                    ObjectPatProp::Rest(ref p) => pat_names(&p.arg, names),
+                }
+            }
+        }
+        Pat::Rest(ref r) => pat_names(&r.arg, names),
+        Pat::Assign(ref a) => pat_names(&a.left, names),
+        Pat::Invalid(..) | Pat::Expr(..) => {}
+    }
+}
+
+/// Collects names exported using `export { a }`, `export default a` or
+/// `export = a`.
+fn collect_exported_names(decl: &ModuleDecl, names: &mut HashSet<JsWord>) {
+    match *decl {
+        ModuleDecl::ExportNamed(NamedExport {
+            ref specifiers,
+            src: None,
+            ..
+        }) => {
+            for s in specifiers {
+                if let ExportSpecifier::Named(ref s) = *s {
+                    names.insert(s.orig.sym.clone());
+                }
+            }
+        }
+        ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+            expr: box Expr::Ident(ref i),
+            ..
+        })
+        | ModuleDecl::TsExportAssignment(TsExportAssignment {
+            expr: box Expr::Ident(ref i),
+            ..
+        }) => {
+            names.insert(i.sym.clone());
+        }
+        _ => {}
+    }
+}
+
+/// Collects names referenced from types and heritage clauses.
+struct RefCollector<'a> {
+    refs: &'a mut HashSet<JsWord>,
+}
+
+impl Visit<TsEntityName> for RefCollector<'_> {
+    fn visit(&mut self, n: &TsEntityName) {
+        match *n {
+            TsEntityName::Ident(ref i) => {
+                self.refs.insert(i.sym.clone());
+            }
+            TsEntityName::TsQualifiedName(ref q) => q.left.visit_with(self),
+        }
+    }
+}
+
+impl Visit<Class> for RefCollector<'_> {
+    fn visit(&mut self, c: &Class) {
+        c.visit_children(self);
+
+        let mut super_class = c.super_class.as_ref().map(|e| &**e);
+        while let Some(e) = super_class {
+            match *e {
+                Expr::Ident(ref i) => {
+                    self.refs.insert(i.sym.clone());
+                    super_class = None;
+                }
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(ref obj),
+                    ..
+                }) => super_class = Some(&**obj),
+                _ => super_class = None,
+            }
+        }
+    }
+}
+
+impl Visit<ModuleDecl> for RefCollector<'_> {
+    fn visit(&mut self, decl: &ModuleDecl) {
+        decl.visit_children(self);
+
+        collect_exported_names(decl, self.refs);
+    }
+}
+
+impl Visit<Ident> for RefCollector<'_> {
+    fn visit(&mut self, i: &Ident) {
+        // `this` is not a reference to a declaration.
+        if i.sym == js_word!("this") {
+            return;
+        }
+
+        i.type_ann.visit_with(self);
+    }
+}
@@ -391,6 +391,54 @@ macro_rules! test_exec {
     };
 }
 
+/// Asserts that the transform reports an error containing `$msg`.
+macro_rules! test_errors {
+    ($syntax:expr, $tr:expr, $test_name:ident, $input:expr, $msg:expr) => {
+        #[test]
+        fn $test_name() {
+            let stderr = common::transform_errors(stringify!($test_name), $syntax, $tr, $input);
+            assert!(
+                stderr.contains($msg),
+                "expected an error containing `{}`, got:\n{}",
+                $msg,
+                stderr
+            );
+        }
+    };
+}
+
+/// Applies the transform and returns the errors it reported.
+pub fn transform_errors<F, P>(test_name: &'static str, syntax: Syntax, tr: F, input: &str) -> String
+where
+    F: FnOnce(&mut Tester<'_>) -> P,
+    P: Pass,
+{
+    let out = ::testing::run_test(false, |cm, handler| {
+        swc_ecma_transforms::util::HANDLER.set(handler, || {
+            HELPERS.set(&Default::default(), || {
+                let mut tester = Tester {
+                    cm,
+                    handler,
+                    comments: Comments::default(),
+                };
+                let tr = make_tr(test_name, tr, &mut tester);
+                tester.apply_transform(tr, "input.js", syntax, input)?;
+
+                if handler.has_errors() {
+                    Err(())
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    });
+
+    match out {
+        Ok(()) => panic!("expected an error"),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -406,6 +454,8 @@ impl Write for Buf {
 struct Normalizer;
 impl Fold<PatOrExpr> for Normalizer {
     fn fold(&mut self, n: PatOrExpr) -> PatOrExpr {
+        let n = n.fold_children(self);
+
         match n {
             PatOrExpr::Pat(box Pat::Expr(e)) => PatOrExpr::Expr(e),
             _ => n,
@@ -413,6 +463,19 @@ impl Fold<PatOrExpr> for Normalizer {
     }
 }
 
+// Raw source text differs between the input and the expected output.
+impl Fold<Str> for Normalizer {
+    fn fold(&mut self, s: Str) -> Str {
+        Str { raw: None, ..s }
+    }
+}
+
+impl Fold<Number> for Normalizer {
+    fn fold(&mut self, n: Number) -> Number {
+        Number { raw: None, ..n }
+    }
+}
+
 struct HygieneVisualizer;
 impl Fold<Ident> for HygieneVisualizer {
     fn fold(&mut self, ident: Ident) -> Ident {
@@ -4,7 +4,7 @@
 #![feature(specialization)]
 
 use swc_common::chain;
-use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
 use swc_ecma_transforms::{
     compat::es2015::{arrow, block_scoping, spread, Classes},
     pass::Pass,
@@ -6109,3 +6109,67 @@ with (env) {
 
 "#
 );
+
+test_exec!(
+    Syntax::Es(EsConfig {
+        logical_assignment: true,
+        ..Default::default()
+    }),
+    |_| tr(),
+    super_logical_assignment_exec,
+    r#"
+let sets = 0;
+class Base {
+  get a() {
+    return this._a;
+  }
+  set a(v) {
+    sets++;
+    this._a = v;
+  }
+}
+
+class Foo extends Base {
+  test() {
+    super.a ??= 0;
+    super.a ||= 1;
+    super.a ||= 2;
+    super.a &&= 3;
+    super.a ??= 4;
+    return super.a;
+  }
+}
+
+expect(new Foo().test()).toBe(3);
+expect(sets).toBe(3);
+"#
+);
+
+test!(
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| tr(),
+    constructor_param_decorator,
+    r#"
+class Foo {
+  constructor(@Inject() a) {}
+}
+"#,
+    r#"
+var Foo = function Foo(a) {
+  'use strict';
+
+  _classCallCheck(this, Foo);
+};
+"#
+);
+
+test_errors!(
+    Syntax::Typescript(Default::default()),
+    |_| tr(),
+    constructor_param_prop,
+    "class Foo { constructor(private a) {} }",
+    "Parameter properties should be removed by typescript::strip"
+);
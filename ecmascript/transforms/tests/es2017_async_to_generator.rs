@@ -5,7 +5,7 @@
 
 use ast::*;
 use swc_common::{chain, fold::FoldWith, Fold, Spanned};
-use swc_ecma_parser::Syntax;
+use swc_ecma_parser::{EsConfig, Syntax};
 use swc_ecma_transforms::{
     compat::{
         es2015::{arrow, destructuring, es2015, function_name, parameters},
@@ -2128,3 +2128,20 @@ function foo() {
     return _foo.apply(this, arguments);
 }"
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| async_to_generator(),
+    top_level_await,
+    r#"
+const a = await foo();
+for await (const b of c) {}
+"#,
+    r#"
+const a = await foo();
+for await (const b of c) {}
+"#
+);
@@ -0,0 +1,92 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{FlowConfig, Syntax};
+use swc_ecma_transforms::flow::strip;
+
+#[macro_use]
+mod common;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            Syntax::Flow(FlowConfig {
+                all: true,
+                ..Default::default()
+            }),
+            |_| strip(),
+            $name,
+            $from,
+            $to
+        );
+    };
+}
+
+to!(
+    annotations,
+    "function foo(a: number, b?: ?string): Array<string> {
+    const c: { a: number } = a;
+}",
+    "function foo(a, b) {
+    const c = a;
+}"
+);
+
+to!(
+    type_alias,
+    "type A = {| a: number |};
+export type B = A | string;
+opaque type C: string = string;
+export opaque type D = number;
+foo();",
+    "foo();"
+);
+
+to!(
+    object_type_variance_and_spread,
+    "type A = { +a: string, -b: number };
+type B = {| ...A, c: boolean |};
+const d: { ...B, e: string } = f;",
+    "const d = f;"
+);
+
+to!(
+    import_type,
+    "import type { A } from './a';
+import typeof B from './b';
+import type, { type C, D } from './c';
+const d: A | B | C = D;",
+    "import type, { D } from './c';
+const d = D;"
+);
+
+to!(
+    generics,
+    "function foo<T: Object, +U = string>(a: T): U {}
+class Foo<T> extends Bar<T> {}",
+    "function foo(a) {}
+class Foo extends Bar {}"
+);
+
+to!(
+    type_cast,
+    "const a = ((b: any): string);
+const c = (d: number) => d;
+const e = (f: number = 1) => f;",
+    "const a = b;
+const c = (d) => d;
+const e = (f = 1) => f;"
+);
+
+to!(
+    import_type_specifier,
+    "import { type A, typeof B, C } from './a';
+import { type D } from './d';
+import './e';
+const f: A | B | D = C;",
+    "import { C } from './a';
+import './e';
+const f = C;"
+);
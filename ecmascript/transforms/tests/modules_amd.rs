@@ -11,7 +11,7 @@ use swc_ecma_transforms::{
         amd::{amd, Config},
         util,
     },
-    resolver,
+    resolver, typescript,
 };
 
 #[macro_use]
@@ -1176,3 +1176,43 @@ export const foo = function () {
 });
 "
 );
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), tr(Default::default())),
+    ts_import_equals,
+    "import foo = require('foo');
+export import bar = require('bar');
+foo.baz();",
+    "define(['exports', 'foo', 'bar'], function(_exports, foo, bar) {
+    'use strict';
+    Object.defineProperty(_exports, '__esModule', {
+        value: true
+    });
+    _exports.bar = bar;
+    foo.baz();
+});"
+);
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), tr(Default::default())),
+    ts_export_assignment,
+    "import foo = require('foo');
+export = foo.bar;",
+    "define(['foo'], function(foo) {
+    'use strict';
+    return foo.bar;
+});"
+);
+
+test_errors!(
+    Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    top_level_await,
+    "export const a = await foo();",
+    "Top-level await cannot be used when targeting AMD modules"
+);
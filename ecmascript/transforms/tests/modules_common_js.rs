@@ -4076,3 +4076,66 @@ Object.keys(_c).forEach(function(key) {
 });
 "
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), tr(Default::default())),
+    ts_import_equals,
+    "import foo = require('foo');
+import bar = require('bar');
+let a: bar.Bar;
+foo.baz();",
+    "'use strict';
+var foo = require('foo');
+let a;
+foo.baz();"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), tr(Default::default())),
+    ts_export_import_equals,
+    "export import foo = require('foo');",
+    "'use strict';
+Object.defineProperty(exports, '__esModule', {
+    value: true
+});
+var foo = require('foo');
+exports.foo = foo;"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), tr(Default::default())),
+    ts_export_assignment,
+    "import foo = require('foo');
+export = foo.bar;",
+    "'use strict';
+var foo = require('foo');
+module.exports = foo.bar;"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        import_assertions: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    import_assertions,
+    "import data from './data.json' assert { type: 'json' };
+data",
+    "'use strict';
+var _dataJson = _interopRequireDefault(require('./data.json'));
+_dataJson.default"
+);
+
+test_errors!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    top_level_await,
+    "export const a = await foo();",
+    "Top-level await cannot be used when targeting CommonJS modules"
+);
@@ -0,0 +1,28 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_common::chain;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{modules::es::es, typescript};
+
+#[macro_use]
+mod common;
+
+test_errors!(
+    Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), es()),
+    ts_import_equals,
+    "import foo = require('foo');
+foo.baz();",
+    "Import assignment cannot be used when targeting ECMAScript modules"
+);
+
+test_errors!(
+    Syntax::Typescript(Default::default()),
+    |_| chain!(typescript::strip(), es()),
+    ts_export_assignment,
+    "export = foo.bar;",
+    "Export assignment cannot be used when targeting ECMAScript modules"
+);
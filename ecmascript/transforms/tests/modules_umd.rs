@@ -11,7 +11,7 @@ use swc_ecma_transforms::{
         util,
     },
     pass::Pass,
-    resolver,
+    resolver, typescript,
 };
 
 #[macro_use]
@@ -1874,3 +1874,34 @@ export const foo = function () {
     _exports.foo = foo;
 });"
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |tester| chain!(typescript::strip(), tr(tester, Default::default())),
+    ts_export_assignment,
+    "import foo = require('foo');
+export = foo.bar;",
+    "(function(global, factory) {
+    if (typeof define === 'function' && define.amd) {
+        define(['foo'], factory);
+    } else if (typeof exports !== 'undefined') {
+        module.exports = factory(require('foo'));
+    } else {
+        global.input = factory(global.foo);
+    }
+})(this, function(foo) {
+    'use strict';
+    return foo.bar;
+});"
+);
+
+test_errors!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        top_level_await: true,
+        ..Default::default()
+    }),
+    |tester| tr(tester, Default::default()),
+    top_level_await,
+    "export const a = await foo();",
+    "Top-level await cannot be used when targeting UMD modules"
+);
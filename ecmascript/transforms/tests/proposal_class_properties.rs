@@ -4856,3 +4856,156 @@ expect(foo.y).toBe('bar');
 
 "#
 );
+
+fn static_blocks() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        class_props: true,
+        static_blocks: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    static_blocks(),
+    |_| tr(),
+    static_block,
+    r#"
+class Foo {
+  static a = 1;
+  static {
+    this.b = this.a + 1;
+  }
+  static c = 3;
+}
+"#,
+    r#"
+var Foo = function Foo() {
+    'use strict';
+    _classCallCheck(this, Foo);
+};
+_defineProperty(Foo, 'a', 1);
+(function() {
+    Foo.b = Foo.a + 1;
+})();
+_defineProperty(Foo, 'c', 3);
+"#
+);
+
+test_exec!(
+    static_blocks(),
+    |_| tr(),
+    static_block_exec,
+    r#"
+var order = [];
+
+class Base {
+  static getName() {
+    return "base";
+  }
+}
+
+class Foo extends Base {
+  static #secret = 1;
+  static a = order.push("a");
+  static {
+    var self = this;
+    order.push("block");
+    this.b = this.#secret + 1;
+    this.name2 = super.getName();
+    this.isSelf = (() => this === self)();
+  }
+  static c = order.push("c");
+}
+
+expect(order).toEqual(["a", "block", "c"]);
+expect(Foo.b).toBe(2);
+expect(Foo.name2).toBe("base");
+expect(Foo.isSelf).toBe(true);
+"#
+);
+
+fn logical_assignment_syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        class_props: true,
+        logical_assignment: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    logical_assignment_syntax(),
+    |_| tr(),
+    private_logical_assignment,
+    "
+class Foo {
+    #x = 0;
+
+    test(other) {
+        this.#x ||= 1;
+        other.obj.#x ??= 2;
+    }
+}
+",
+    "
+var Foo = function () {
+  'use strict';
+  function Foo() {
+    _classCallCheck(this, Foo);
+
+    _x.set(this, {
+      writable: true,
+      value: 0
+    });
+  }
+
+  _createClass(Foo, [{
+    key: 'test',
+    value: function test(other) {
+      var _obj, _ref;
+
+      _classPrivateFieldGet(this, _x) || _classPrivateFieldSet(this, _x, 1);
+      (_ref = _classPrivateFieldGet(_obj = other.obj, _x)) !== null && _ref !== void 0
+        ? _ref
+        : _classPrivateFieldSet(_obj, _x, 2);
+    }
+  }]);
+
+  return Foo;
+}();
+
+var _x = new WeakMap();
+"
+);
+
+test_exec!(
+    logical_assignment_syntax(),
+    |_| tr(),
+    private_logical_assignment_exec,
+    r#"
+let rhs = 0;
+function value(v) {
+  rhs++;
+  return v;
+}
+
+class Foo {
+  #x = 0;
+  static #y = null;
+
+  test() {
+    this.#x &&= value(1);
+    this.#x ||= value(2);
+    this.#x ||= value(3);
+    this.#x ??= value(4);
+    Foo.#y ??= value(5);
+    Foo.#y ??= value(6);
+    return [this.#x, Foo.#y];
+  }
+}
+
+expect(new Foo().test()).toEqual([2, 5]);
+expect(rhs).toBe(2);
+"#
+);
@@ -6,6 +6,7 @@
 use swc_common::chain;
 use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
 use swc_ecma_transforms::{
+    compat::es2015::Classes,
     pass::Pass,
     proposals::{class_properties, decorators, decorators::Config},
     resolver, typescript,
@@ -2054,6 +2055,235 @@ expect(typeof Parent.prototype.child).toBe("function");
 "#
 );
 
+test_exec!(
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| chain!(
+        decorators::metadata(),
+        typescript::strip(),
+        decorators(decorators::Config { legacy: true }),
+    ),
+    legacy_metadata,
+    r#"
+const metadata = [];
+Reflect.metadata = function (key: string, value: any) {
+    return function (target: any, prop?: string) {
+        metadata.push([prop, key, value]);
+        if (prop === undefined) {
+            return target;
+        }
+    };
+};
+
+function dec(target: any, prop?: string, desc?: any) {
+    return prop === undefined ? target : desc;
+}
+
+class Bar {}
+
+@dec
+class Foo {
+    constructor(a: string, b: Bar, c?: number | undefined) {}
+
+    @dec
+    method(a: number, b: Bar[]): boolean {
+        return true;
+    }
+
+    @dec
+    get getter(): string {
+        return '';
+    }
+
+    @dec
+    prop: string | null = null;
+}
+
+expect(metadata).toEqual([
+    ['method', 'design:returntype', Boolean],
+    ['method', 'design:paramtypes', [Number, Array]],
+    ['method', 'design:type', Function],
+    ['getter', 'design:type', String],
+    ['prop', 'design:type', String],
+    [undefined, 'design:paramtypes', [String, Bar, Number]],
+]);
+"#
+);
+
+test_exec!(
+    syntax(true),
+    |_| chain!(
+        decorators(decorators::Config { legacy: true }),
+        class_properties(),
+    ),
+    legacy_computed_method_key,
+    r#"
+let count = 0;
+function getKey() {
+    count++;
+    return 'foo';
+}
+
+function dec(target, name, desc) {
+    desc.value = function () {
+        return 'decorated';
+    };
+    return desc;
+}
+
+class A {
+    @dec
+    [getKey()]() {
+        return 'original';
+    }
+}
+
+expect(new A().foo()).toBe('decorated');
+expect(count).toBe(1);
+"#
+);
+
+test_exec!(
+    syntax(true),
+    |_| chain!(
+        decorators(decorators::Config { legacy: true }),
+        class_properties(),
+    ),
+    legacy_computed_prop_key,
+    r#"
+let count = 0;
+function getKey() {
+    count++;
+    return 'foo';
+}
+
+function dec(target, name, desc) {
+    desc.initializer = function () {
+        return 'decorated';
+    };
+    return desc;
+}
+
+class A {
+    @dec
+    [getKey()] = 'original';
+}
+
+expect(new A().foo).toBe('decorated');
+expect(new A().foo).toBe('decorated');
+expect(count).toBe(1);
+"#
+);
+
+test_exec!(
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| chain!(
+        typescript::strip(),
+        decorators(decorators::Config { legacy: true }),
+    ),
+    legacy_param_decorator,
+    r#"
+const calls = [];
+function inject(name: string) {
+    return function (target: any, key: any, index: number) {
+        calls.push([target.name, key, index, name]);
+    };
+}
+
+class Foo {
+    constructor(@inject('a') a: string, @inject('b') private b: number) {}
+}
+
+expect(calls).toEqual([['Foo', undefined, 1, 'b'], ['Foo', undefined, 0, 'a']]);
+expect(new Foo('a', 1).b).toBe(1);
+"#
+);
+
+test_exec!(
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| chain!(
+        typescript::strip(),
+        decorators(decorators::Config { legacy: true }),
+        Classes::default(),
+    ),
+    legacy_param_decorator_classes,
+    r#"
+const calls = [];
+function inject(name: string) {
+    return function (target: any, key: any, index: number) {
+        calls.push([index, name]);
+    };
+}
+
+class Foo {
+    constructor(@inject('a') a: string, @inject('b') readonly b: number) {}
+}
+
+const foo = new Foo('a', 1);
+expect(calls).toEqual([[1, 'b'], [0, 'a']]);
+expect(Object.keys(foo)).toEqual(['b']);
+"#
+);
+
+test_exec!(
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| chain!(
+        typescript::strip(),
+        decorators(decorators::Config { legacy: true }),
+    ),
+    legacy_method_param_decorator,
+    r#"
+const calls = [];
+function inject(name: string) {
+    return function (target: any, key: any, index: number) {
+        calls.push([typeof target, key, index, name]);
+    };
+}
+
+class Foo {
+    m(a: string, @inject('b') b: number) {
+        return b;
+    }
+
+    static s(@inject('c') c: number) {}
+}
+
+expect(calls).toEqual([['object', 'm', 1, 'b'], ['function', 's', 0, 'c']]);
+expect(new Foo().m('a', 1)).toBe(1);
+"#
+);
+
+test_errors!(
+    syntax(false),
+    |_| decorators(Default::default()),
+    method_param_decorator,
+    "class Foo {
+    m(@inject a) {}
+}",
+    "Parameter decorators are only supported by legacy decorators"
+);
+
+test_errors!(
+    syntax(false),
+    |_| chain!(decorators(Default::default()), Classes::default()),
+    param_decorator_classes,
+    "class Foo {
+    constructor(@inject a) {}
+}",
+    "Parameter decorators are only supported by legacy decorators"
+);
+
 // legacy_regression_10264
 test!(
     syntax(true),
@@ -2464,7 +2694,10 @@ export { _class1 as default }
 // legacy_decl_to_expression_class_decorators
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_decl_to_expression_class_decorators,
     r#"
 export default @dec class A {}
@@ -2835,7 +3068,10 @@ const inst = {
 // legacy_decl_to_expression_method_decorators
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_decl_to_expression_method_decorators,
     r#"
 export default class A {
@@ -4122,7 +4358,10 @@ expect(Example._()).toBe("__8__");
 // legacy_regression_8512
 test_exec!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_regression_8512_exec,
     r#"
 function dec(Class, key, desc) {
@@ -4139,7 +4378,10 @@ class Foo {
 
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     issue_591_1,
     "
 export class Example {
@@ -4173,7 +4415,10 @@ export let Example = ((_class = class Example{
 
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     issue_591_2,
     "class Example {
   @foo() bar = '1';
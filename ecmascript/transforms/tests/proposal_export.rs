@@ -6,7 +6,7 @@
 use ast::*;
 use swc_common::Fold;
 use swc_ecma_parser::{EsConfig, Syntax};
-use swc_ecma_transforms::proposals::export;
+use swc_ecma_transforms::proposals::export::{self, export};
 
 #[macro_use]
 mod common;
@@ -79,3 +79,20 @@ test!(
     "import * as _foo from 'bar';
 export { _foo as foo };"
 );
+
+test!(
+    syntax_namespace(),
+    |_| export::export_with_config(export::Config { keep_namespace: true }),
+    namespace_keep,
+    "export * as foo from 'bar';",
+    "export * as foo from 'bar';"
+);
+
+test!(
+    syntax_namespace(),
+    |_| export::export_with_config(export::Config { keep_namespace: true }),
+    namespace_keep_compound,
+    "export * as foo, { bar } from 'bar';",
+    "export * as foo from 'bar';
+export { bar } from 'bar';"
+);
@@ -0,0 +1,188 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_transforms::typescript::dts;
+
+#[macro_use]
+mod common;
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+            |_| dts(),
+            $name,
+            $from,
+            $to
+        );
+    };
+}
+
+to!(
+    fn_body,
+    "export function foo(a: number, b = 'b'): string {
+    return a + b;
+}",
+    "export declare function foo(a: number, b?: string): string;"
+);
+
+to!(
+    fn_overload,
+    "export function foo(a: string): string;
+export function foo(a: number): number;
+export function foo(a: any): any {
+    return a;
+}",
+    "export declare function foo(a: string): string;
+export declare function foo(a: number): number;"
+);
+
+to!(
+    var_const_literal,
+    "export const a = 1, b = 'foo', c: boolean = true;",
+    "export declare const a: 1, b: 'foo', c: boolean;"
+);
+
+to!(
+    var_let_widened,
+    "export let a = 1, b = 'foo';",
+    "export declare let a: number, b: string;"
+);
+
+to!(
+    class_members,
+    "export class Foo {
+    private a: number = 1;
+    protected b = 'b';
+    readonly c = 2;
+    static d: string;
+    #e = 1;
+    constructor(public f: number, private g: string) {}
+    foo(): void {
+        this.a = 2;
+    }
+    private bar() {}
+}",
+    "export declare class Foo {
+    protected b: string;
+    readonly c: 2;
+    static d: string;
+    f: number;
+    constructor(f: number, g: string);
+    foo(): void;
+}"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(::swc_ecma_parser::TsConfig {
+        decorators: true,
+        ..Default::default()
+    }),
+    |_| dts(),
+    decorated_constructor_param,
+    "export class Foo {
+    constructor(@inject() a: number, @inject() private b: string) {}
+}",
+    "export declare class Foo {
+    constructor(a: number, b: string);
+}"
+);
+
+to!(
+    local_decl_dropped,
+    "const a = 1;
+function foo() {}
+console.log(a);
+export const b: number = 2;",
+    "export declare const b: number;"
+);
+
+to!(
+    local_decl_referenced,
+    "interface Bar {}
+type Baz = Bar[];
+interface Unused {}
+class Base {
+    a: number;
+}
+export class Foo extends Base {
+    bar: Baz;
+}",
+    "interface Bar {}
+type Baz = Bar[];
+declare class Base {
+    a: number;
+}
+export declare class Foo extends Base {
+    bar: Baz;
+}"
+);
+
+to!(
+    local_decl_exported_by_name,
+    "function foo(): void {}
+const bar = 1;
+export { foo };
+export default bar;",
+    "declare function foo(): void;
+declare const bar: 1;
+export { foo };
+export default bar;"
+);
+
+to!(
+    export_default_expr,
+    "export default 'foo' as string;",
+    "declare const _default: string;
+export default _default;"
+);
+
+to!(
+    namespace,
+    "export namespace Foo {
+    export const a = 1;
+    const b = 2;
+    export function foo(): void {}
+}",
+    "export declare namespace Foo {
+    export const a: 1;
+    export function foo(): void;
+}"
+);
+
+to!(
+    script,
+    "const a = 1;
+function foo(): void {}
+foo();",
+    "declare const a: 1;
+declare function foo(): void;"
+);
+
+to!(
+    imports_kept,
+    "import { Foo } from './foo';
+export * from './bar';
+export const foo: Foo = new Foo();",
+    "import { Foo } from './foo';
+export * from './bar';
+export declare const foo: Foo;"
+);
+
+test_errors!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| dts(),
+    non_inferable_var,
+    "export const a = foo();",
+    "cannot determine the type of the variable; add a type annotation"
+);
+
+test_errors!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| dts(),
+    non_inferable_return_type,
+    "export function foo() { return bar(); }",
+    "cannot determine the return type of the function; add a return type annotation"
+);
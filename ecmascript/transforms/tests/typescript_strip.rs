@@ -3,8 +3,14 @@
 #![feature(box_patterns)]
 #![feature(specialization)]
 
+use hashbrown::HashMap;
 use swc_common::chain;
-use swc_ecma_transforms::{resolver, typescript::strip};
+use swc_ecma_transforms::{
+    compat::es2015::Classes,
+    proposals::class_properties,
+    resolver,
+    typescript::{strip, strip_with_config, Config, EnumValue},
+};
 
 #[macro_use]
 mod common;
@@ -284,10 +290,10 @@ test!(
 }",
     "var State;
 (function(State) {
-    State[State['closed'] = 0] = 'closed';
-    State[State['opened'] = 1] = 'opened';
-    State[State['mounted'] = 2] = 'mounted';
-    State[State['unmounted'] = 3] = 'unmounted';
+    State['closed'] = 'closed';
+    State['opened'] = 'opened';
+    State['mounted'] = 'mounted';
+    State['unmounted'] = 'unmounted';
 })(State || (State = {
 }));
 ",
@@ -306,11 +312,394 @@ test!(
 }",
     "export var State;
 (function(State) {
-    State[State['closed'] = 0] = 'closed';
-    State[State['opened'] = 1] = 'opened';
-    State[State['mounted'] = 2] = 'mounted';
-    State[State['unmounted'] = 3] = 'unmounted';
+    State['closed'] = 'closed';
+    State['opened'] = 'opened';
+    State['mounted'] = 'mounted';
+    State['unmounted'] = 'unmounted';
 })(State || (State = {
 }));",
     ok_if_code_eq
 );
+
+to!(
+    namespace_001,
+    "namespace Foo {
+    export const a = 1;
+    const b = 2;
+    export function foo() {
+        return a + b;
+    }
+}",
+    "var Foo;
+(function(Foo) {
+    Foo.a = 1;
+    const b = 2;
+    function foo() {
+        return Foo.a + b;
+    }
+    Foo.foo = foo;
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_nested,
+    "namespace Foo.Bar {
+    export class A {}
+}",
+    "var Foo;
+(function(Foo) {
+    var Bar;
+    (function(Bar) {
+        class A {
+        }
+        Bar.A = A;
+    })(Bar = Foo.Bar || (Foo.Bar = {}));
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_nested_exported,
+    "namespace Foo {
+    export namespace Bar {
+        export let a = 1;
+    }
+    export enum E {
+        A
+    }
+}",
+    "var Foo;
+(function(Foo) {
+    var Bar;
+    (function(Bar) {
+        Bar.a = 1;
+    })(Bar = Foo.Bar || (Foo.Bar = {}));
+    var E;
+    (function(E) {
+        E[E['A'] = 0] = 'A';
+    })(E = Foo.E || (Foo.E = {}));
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_merged,
+    "function Foo() {}
+namespace Foo {
+    export const a = 1;
+}
+namespace Foo {
+    export const b = 2;
+}",
+    "function Foo() {
+}
+(function(Foo) {
+    Foo.a = 1;
+})(Foo || (Foo = {}));
+(function(Foo) {
+    Foo.b = 2;
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_export,
+    "export namespace Foo {
+    export let a = 1;
+    a = 2;
+    [a] = [3];
+}",
+    "export var Foo;
+(function(Foo) {
+    Foo.a = 1;
+    Foo.a = 2;
+    [Foo.a] = [3];
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_shadowed_var,
+    "namespace Foo {
+    export const a = 1;
+    function f(a) {
+        return a;
+    }
+    function g() {
+        var a = 2;
+        return a;
+    }
+    function h() {
+        {
+            let a = 3;
+        }
+        return a;
+    }
+    try {} catch (a) {
+        a;
+    }
+    for (const a of []) a;
+    [1].map((a) => a);
+}",
+    "var Foo;
+(function(Foo) {
+    Foo.a = 1;
+    function f(a) {
+        return a;
+    }
+    function g() {
+        var a = 2;
+        return a;
+    }
+    function h() {
+        {
+            let a = 3;
+        }
+        return Foo.a;
+    }
+    try {} catch (a) {
+        a;
+    }
+    for (const a of []) a;
+    [1].map((a) => a);
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    namespace_type_only,
+    "namespace Foo {
+    export interface A {}
+    export type B = A;
+}
+declare namespace Bar {
+    const a: number;
+}
+export const c = 1;",
+    "export const c = 1;"
+);
+
+to!(
+    enum_initializer,
+    "enum Foo {
+    a = 2,
+    b,
+    c = 1 << 3,
+    d = a | c,
+    e = 'x' + 'y',
+    f = Foo.c * 2,
+    g = -1,
+    h,
+    i = Math.random(),
+    j = i + 1,
+}",
+    "var Foo;
+(function(Foo) {
+    Foo[Foo['a'] = 2] = 'a';
+    Foo[Foo['b'] = 3] = 'b';
+    Foo[Foo['c'] = 8] = 'c';
+    Foo[Foo['d'] = 10] = 'd';
+    Foo['e'] = 'xy';
+    Foo[Foo['f'] = 16] = 'f';
+    Foo[Foo['g'] = -1] = 'g';
+    Foo[Foo['h'] = 0] = 'h';
+    Foo[Foo['i'] = Math.random()] = 'i';
+    Foo[Foo['j'] = Foo.i + 1] = 'j';
+})(Foo || (Foo = {}));"
+);
+
+to!(
+    const_enum_inline,
+    "const enum Direction {
+    Up = 1,
+    Down,
+    Name = 'dir',
+}
+const a = Direction.Up, b = Direction['Down'], c = Direction.Name;",
+    "const a = 1, b = 2, c = 'dir';"
+);
+
+to!(
+    const_enum_export,
+    "export const enum Foo {
+    A = 1 << 2,
+}
+export const a = Foo.A;",
+    "export const a = 4;"
+);
+
+to!(
+    const_enum_in_other_enum,
+    "const enum Foo {
+    A = 2,
+}
+enum Bar {
+    B = Foo.A * 2,
+}",
+    "var Bar;
+(function(Bar) {
+    Bar[Bar['B'] = 4] = 'B';
+})(Bar || (Bar = {}));"
+);
+
+to!(
+    const_enum_shadowed,
+    "const enum E {
+    A = 1,
+}
+function f(E) {
+    return E.A;
+}
+const g = () => {
+    let E = { A: 2 };
+    return E.A;
+};
+E.A;",
+    "function f(E) {
+    return E.A;
+}
+const g = () => {
+    let E = {
+        A: 2
+    };
+    return E.A;
+};
+1;"
+);
+
+test!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| {
+        let mut members = HashMap::default();
+        members.insert("Up".into(), EnumValue::Num(0.0));
+        members.insert("Name".into(), EnumValue::Str("dir".into()));
+        let mut enums = HashMap::default();
+        enums.insert("Direction".into(), members);
+        let mut config = Config::default();
+        config.enums.insert("./direction".into(), enums);
+
+        strip_with_config(config)
+    },
+    enum_from_other_module,
+    "import { Direction as Dir } from './direction';
+console.log(Dir.Up, Dir.Name, Dir.Down);",
+    "import { Direction as Dir } from './direction';
+console.log(0, 'dir', Dir.Down);"
+);
+
+to!(
+    constructor_super,
+    "class Foo extends Bar {
+    constructor(private a: number, public b = 1) {
+        console.log('before');
+        super(a);
+        console.log(this.a);
+    }
+}",
+    "class Foo extends Bar {
+    constructor(a, b = 1) {
+        console.log('before');
+        super(a);
+        this.a = a;
+        this.b = b;
+        console.log(this.a);
+    }
+}"
+);
+
+to!(
+    constructor_super_conditional,
+    "class Foo extends Bar {
+    constructor(readonly a: number) {
+        if (a) {
+            super(a);
+        } else {
+            super();
+        }
+    }
+}",
+    "class Foo extends Bar {
+    constructor(a) {
+        if (a) {
+            super(a);
+            this.a = a;
+        } else {
+            super();
+            this.a = a;
+        }
+    }
+}"
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(strip(), Classes::default()),
+    constructor_super_classes_exec,
+    "class Base {
+    constructor(public a: number) {}
+}
+class Foo extends Base {
+    constructor(a: number, private b: number) {
+        super(a);
+    }
+    sum() {
+        return this.a + this.b;
+    }
+}
+expect(new Foo(1, 2).sum()).toBe(3);"
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(strip(), resolver(), class_properties(), Classes::default()),
+    constructor_super_class_properties_exec,
+    "class Base {
+    constructor(public a: number) {}
+}
+class Foo extends Base {
+    c: number = 3;
+    constructor(a: number, private b: number) {
+        super(a);
+    }
+    sum() {
+        return this.a + this.b + this.c;
+    }
+}
+expect(new Foo(1, 2).sum()).toBe(6);"
+);
+
+to!(
+    constructor_field_after_param_prop,
+    "class A extends B {
+    c = this.b * 2;
+    static d = 1;
+    constructor(private b: number) {
+        super();
+    }
+}",
+    "class A extends B {
+    static d = 1;
+    constructor(b) {
+        super();
+        this.b = b;
+        this.c = this.b * 2;
+    }
+}"
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| strip(),
+    constructor_field_after_param_prop_exec,
+    "class A {
+    c = this.b * 2;
+    constructor(private b: number) {}
+}
+expect(new A(2).c).toBe(4);"
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |_| chain!(strip(), resolver(), class_properties(), Classes::default()),
+    constructor_field_after_param_prop_class_properties_exec,
+    "class A {
+    c = this.b * 2;
+    constructor(private b: number) {}
+}
+expect(new A(2).c).toBe(4);"
+);
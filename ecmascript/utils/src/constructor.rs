@@ -1,8 +1,34 @@
-use crate::{prepend_stmts, ExprFactory};
+use crate::{prepend_stmts, ExprFactory, HANDLER};
 use ast::*;
 use std::iter;
 use swc_common::{Fold, FoldWith, DUMMY_SP};
 
+/// Returns the pattern of a constructor parameter.
+///
+/// Decorators of the parameter are dropped. Parameter properties should be
+/// removed by the typescript strip pass, so they are reported as an error.
+pub fn param_pat(param: PatOrTsParamProp) -> Pat {
+    match param {
+        PatOrTsParamProp::Pat(pat) => pat,
+        PatOrTsParamProp::Param(param) => param.pat,
+        PatOrTsParamProp::TsParamProp(param) => {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        param.span,
+                        "Parameter properties should be removed by typescript::strip",
+                    )
+                    .emit()
+            });
+
+            match param.param {
+                TsParamPropParam::Ident(i) => Pat::Ident(i),
+                TsParamPropParam::Assign(a) => Pat::Assign(a),
+            }
+        }
+    }
+}
+
 #[allow(clippy::vec_box)]
 pub fn inject_after_super(mut c: Constructor, exprs: Vec<Box<Expr>>) -> Constructor {
     // Allow using super multiple time
@@ -21,6 +21,7 @@ use scoped_tls::scoped_thread_local;
 use std::{
     borrow::Cow,
     f64::{INFINITY, NAN},
+    mem::replace,
     num::FpCategory,
     ops::Add,
 };
@@ -928,6 +929,18 @@ pub trait ExprExt {
             | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
             | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => expr.may_have_side_effects(),
             Expr::OptChain(ref e) => e.expr.may_have_side_effects(),
+            Expr::Pipeline(..) => true,
+            Expr::Bind(BindExpr {
+                ref object,
+                ref callee,
+                ..
+            }) => {
+                object
+                    .as_ref()
+                    .map(|object| object.may_have_side_effects())
+                    .unwrap_or(false)
+                    || callee.may_have_side_effects()
+            }
 
             Expr::Invalid(..) => unreachable!(),
         }
@@ -1267,6 +1280,78 @@ pub fn alias_if_required(expr: &Expr, default: &str) -> (Ident, bool) {
     (alias_ident_for(expr, default), true)
 }
 
+/// Lowers a logical assignment to the target, where `get` reads the target
+/// and `set` assigns the right operand to it. `set` is evaluated only if
+/// required.
+///
+/// `a ||= b` => `get || set`
+///
+/// `a ??= b` => `(_ref = get) !== null && _ref !== void 0 ? _ref : set`
+pub fn short_circuit_assign(
+    span: Span,
+    get: Box<Expr>,
+    op: AssignOp,
+    set: Box<Expr>,
+    vars: &mut Vec<VarDeclarator>,
+) -> Expr {
+    let op = match op {
+        op!("&&=") => op!("&&"),
+        op!("||=") => op!("||"),
+        op!("??=") => op!("??"),
+        _ => unreachable!("{:?} is not a logical assignment operator", op),
+    };
+
+    if op != op!("??") {
+        return Expr::Bin(BinExpr {
+            span,
+            left: get,
+            op,
+            right: set,
+        });
+    }
+
+    let (alias, aliased) = alias_if_required(&get, "_ref");
+    let init = if aliased {
+        vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        box Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(box Pat::Ident(alias.clone())),
+            right: get,
+        })
+    } else {
+        get
+    };
+
+    Expr::Cond(CondExpr {
+        span,
+        test: box Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            left: box Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: init,
+                op: op!("!=="),
+                right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            }),
+            op: op!("&&"),
+            right: box Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: box Expr::Ident(alias.clone()),
+                op: op!("!=="),
+                right: undefined(DUMMY_SP),
+            }),
+        }),
+        cons: box Expr::Ident(alias),
+        alt: set,
+    })
+}
+
 pub fn prop_name_to_expr(p: PropName) -> Expr {
     match p {
         PropName::Ident(i) => Expr::Ident(i),
@@ -1284,6 +1369,7 @@ pub fn prop_name_to_expr_value(p: PropName) -> Expr {
             span: i.span,
             value: i.sym,
             has_escape: false,
+            raw: None,
         })),
         PropName::Str(s) => Expr::Lit(Lit::Str(s)),
         PropName::Num(n) => Expr::Lit(Lit::Num(n)),
@@ -1349,7 +1435,11 @@ pub fn undefined(span: Span) -> Box<Expr> {
     box Expr::Unary(UnaryExpr {
         span,
         op: op!("void"),
-        arg: box Expr::Lit(Lit::Num(Number { value: 0.0, span })),
+        arg: box Expr::Lit(Lit::Num(Number {
+            value: 0.0,
+            span,
+            raw: None,
+        })),
     })
 }
 
@@ -1397,6 +1487,38 @@ pub fn prepend_stmts<T: StmtLike>(
     *to = buf
 }
 
+/// Folds `stmts` and declares the variables pushed to `vars(folder)` while
+/// folding a statement with a `var` right before that statement.
+pub fn fold_stmts_hoisting_vars<F, T>(
+    folder: &mut F,
+    stmts: Vec<T>,
+    vars: fn(&mut F) -> &mut Vec<VarDeclarator>,
+) -> Vec<T>
+where
+    F: Fold<T>,
+    T: StmtLike + FoldWith<F>,
+{
+    let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+    for stmt in stmts {
+        let stmt = stmt.fold_with(folder);
+
+        let vars = vars(folder);
+        if !vars.is_empty() {
+            buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                decls: replace(vars, Default::default()),
+                declare: false,
+            }))));
+        }
+
+        buf.push(stmt);
+    }
+
+    buf
+}
+
 pub trait IsDirective {
     fn as_ref(&self) -> Option<&Stmt>;
     fn is_use_strict(&self) -> bool {
@@ -1670,6 +1792,7 @@ where
             | Expr::TsAs(TsAsExpr { expr, .. })
             | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => add_effects(v, expr),
             Expr::OptChain(e) => add_effects(v, e.expr),
+            Expr::Pipeline(..) | Expr::Bind(..) => v.push(box expr),
 
             Expr::Invalid(..) => unreachable!(),
         }
@@ -6,13 +6,17 @@ use common::{errors::Handler, FileName, SourceMap};
 pub use ecmascript::parser::JscTarget;
 use ecmascript::{
     ast::{Expr, ExprStmt, ModuleItem, Stmt},
+    codegen::{self, QuoteStyle},
     parser::{lexer::Lexer, Parser, Session as ParseSess, SourceFileInput, Syntax},
     preset_env,
     transforms::{
-        const_modules, modules,
+        const_modules, flow, modules,
         optimization::{simplifier, InlineGlobals, JsonParse},
-        pass::{noop, Optional, Pass},
-        proposals::{class_properties, decorators, export, nullish_coalescing, optional_chaining},
+        pass::{Optional, Pass},
+        proposals::{
+            class_properties, decorators, export, function_bind, logical_assignment,
+            nullish_coalescing, optional_chaining, pipeline,
+        },
         react, resolver, typescript,
     },
 };
@@ -78,15 +82,29 @@ pub struct Options {
     #[serde(default = "default_env_name")]
     pub env_name: String,
 
+    /// Source map of the input file, used to map the output back to the
+    /// original source.
+    ///
+    /// If `true`, the map is loaded from a `//# sourceMappingURL=` comment or
+    /// from `<filename>.map`.
     #[serde(default)]
     pub input_source_map: Option<InputSourceMap>,
 
+    /// `true`, `false`, `"inline"` or `"both"`.
+    ///
+    /// `"inline"` appends the source map to the code as a data url instead of
+    /// returning it, and `"both"` does both.
     #[serde(default)]
     pub source_maps: Option<SourceMapsConfig>,
 
+    /// Name of the input file in the generated source map.
+    ///
+    /// This is ignored if the input is not read from a file, as the generated
+    /// source map has no source for such an input.
     #[serde(default)]
     pub source_file_name: Option<String>,
 
+    /// `sourceRoot` of the generated source map.
     #[serde(default)]
     pub source_root: Option<String>,
 
@@ -105,6 +123,32 @@ pub enum SourceMapsConfig {
     Str(String),
 }
 
+impl SourceMapsConfig {
+    /// Returns true if a source map should be generated.
+    pub fn enabled(&self) -> bool {
+        match *self {
+            SourceMapsConfig::Bool(b) => b,
+            SourceMapsConfig::Str(_) => true,
+        }
+    }
+
+    /// Returns true if the source map should be appended to the code.
+    pub fn inline(&self) -> bool {
+        match *self {
+            SourceMapsConfig::Str(ref s) => s == "inline" || s == "both",
+            _ => false,
+        }
+    }
+
+    /// Returns true if the source map should be returned separately.
+    pub fn separate(&self) -> bool {
+        match *self {
+            SourceMapsConfig::Bool(b) => b,
+            SourceMapsConfig::Str(ref s) => s != "inline",
+        }
+    }
+}
+
 impl Default for SourceMapsConfig {
     fn default() -> Self {
         SourceMapsConfig::Bool(true)
@@ -115,6 +159,7 @@ impl Default for SourceMapsConfig {
 #[serde(untagged)]
 pub enum InputSourceMap {
     Bool(bool),
+    /// Content of the source map.
     Str(String),
 }
 
@@ -143,12 +188,13 @@ impl Options {
             external_helpers,
             target,
             loose,
+            output,
         } = config.jsc;
 
         let syntax = syntax.unwrap_or_default();
         let mut transform = transform.unwrap_or_default();
 
-        if syntax.typescript() {
+        if syntax.typescript_only() {
             transform.legacy_decorator = true;
         }
         let optimizer = transform.optimizer;
@@ -189,9 +235,25 @@ impl Options {
         let pass = chain!(
             // handle jsx
             Optional::new(react::react(cm.clone(), transform.react), syntax.jsx()),
-            Optional::new(typescript::strip(), syntax.typescript()),
+            // Decorator metadata is emitted from type annotations.
+            Optional::new(
+                decorators::metadata(),
+                syntax.decorators() && transform.decorator_metadata
+            ),
+            Optional::new(
+                typescript::strip_with_config(transform.typescript),
+                syntax.typescript() && !syntax.flow()
+            ),
+            Optional::new(flow::strip(), syntax.flow()),
+            Optional::new(pipeline(), syntax.pipeline_operator().is_some()),
+            Optional::new(function_bind(), syntax.fn_bind()),
+            // preset_env lowers it only if targets require it.
+            Optional::new(
+                logical_assignment(),
+                syntax.logical_assignment() && config.env.is_none()
+            ),
             Optional::new(nullish_coalescing(), syntax.nullish_coalescing()),
-            Optional::new(optional_chaining(), syntax.typescript()),
+            Optional::new(optional_chaining(), syntax.typescript_only()),
             resolver(),
             const_modules,
             optimization,
@@ -201,9 +263,17 @@ impl Options {
                 }),
                 syntax.decorators()
             ),
-            Optional::new(class_properties(), syntax.class_props()),
             Optional::new(
-                export(),
+                class_properties(),
+                syntax.class_props() || syntax.static_blocks()
+            ),
+            Optional::new(
+                export::export_with_config(export::Config {
+                    // Module transforms only understand named specifiers.
+                    keep_namespace: target >= JscTarget::Es2020
+                        && config.env.is_none()
+                        && config.module.is_none(),
+                }),
                 syntax.export_default_from() || syntax.export_namespace_from()
             ),
             Optional::new(simplifier(), enable_optimizer),
@@ -216,7 +286,7 @@ impl Options {
             .finalize(syntax, config.module);
 
         BuiltConfig {
-            minify: config.minify.unwrap_or(false),
+            output: output.into_codegen_config(config.minify.unwrap_or(false), target),
             pass,
             external_helpers,
             syntax,
@@ -224,13 +294,11 @@ impl Options {
             is_module,
             source_maps: self
                 .source_maps
-                .as_ref()
-                .map(|s| match s {
-                    SourceMapsConfig::Bool(v) => *v,
-                    // TODO: Handle source map
-                    SourceMapsConfig::Str(_) => true,
-                })
-                .unwrap_or(false),
+                .clone()
+                .unwrap_or(SourceMapsConfig::Bool(false)),
+            input_source_map: self.input_source_map.clone().unwrap_or_default(),
+            source_file_name: self.source_file_name.clone(),
+            source_root: self.source_root.clone(),
         }
     }
 }
@@ -429,9 +497,12 @@ pub struct BuiltConfig<P: Pass> {
     pub pass: P,
     pub syntax: Syntax,
     pub target: JscTarget,
-    pub minify: bool,
+    pub output: codegen::Config,
     pub external_helpers: bool,
-    pub source_maps: bool,
+    pub source_maps: SourceMapsConfig,
+    pub input_source_map: InputSourceMap,
+    pub source_file_name: Option<String>,
+    pub source_root: Option<String>,
     pub is_module: bool,
 }
 
@@ -452,6 +523,47 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub loose: bool,
+
+    #[serde(default)]
+    pub output: OutputConfig,
+}
+
+/// `jsc.output`
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OutputConfig {
+    #[serde(default)]
+    pub indent_width: Option<usize>,
+
+    #[serde(default)]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// Add trailing commas to multiline array and object literals.
+    #[serde(default)]
+    pub trailing_comma: bool,
+
+    /// Only used for minified output.
+    #[serde(default)]
+    pub max_line_len: Option<usize>,
+
+    #[serde(default)]
+    pub ascii_only: bool,
+}
+
+impl OutputConfig {
+    fn into_codegen_config(self, minify: bool, target: JscTarget) -> codegen::Config {
+        let default = codegen::Config::default();
+
+        codegen::Config {
+            minify,
+            indent_width: self.indent_width.unwrap_or(default.indent_width),
+            quote_style: self.quote_style.unwrap_or(default.quote_style),
+            trailing_comma: self.trailing_comma,
+            max_line_len: self.max_line_len,
+            ascii_only: self.ascii_only,
+            target,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -469,7 +581,7 @@ pub enum ModuleConfig {
 impl ModuleConfig {
     pub fn build(cm: Arc<SourceMap>, config: Option<ModuleConfig>) -> Box<dyn Pass> {
         match config {
-            None => box noop(),
+            None => box modules::es::es(),
             Some(ModuleConfig::CommonJs(config)) => box modules::common_js::common_js(config),
             Some(ModuleConfig::Umd(config)) => box modules::umd::umd(cm, config),
             Some(ModuleConfig::Amd(config)) => box modules::amd::amd(config),
@@ -491,6 +603,13 @@ pub struct TransformConfig {
 
     #[serde(default)]
     pub legacy_decorator: bool,
+
+    /// `emitDecoratorMetadata` of typescript.
+    #[serde(default)]
+    pub decorator_metadata: bool,
+
+    #[serde(default)]
+    pub typescript: typescript::Config,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -647,6 +766,23 @@ impl Merge for JscConfig {
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.output.merge(&from.output);
+    }
+}
+
+impl Merge for OutputConfig {
+    fn merge(&mut self, from: &Self) {
+        if from.indent_width.is_some() {
+            self.indent_width = from.indent_width;
+        }
+        if from.quote_style.is_some() {
+            self.quote_style = from.quote_style;
+        }
+        self.trailing_comma.merge(&from.trailing_comma);
+        if from.max_line_len.is_some() {
+            self.max_line_len = from.max_line_len;
+        }
+        self.ascii_only.merge(&from.ascii_only);
     }
 }
 
@@ -684,6 +820,8 @@ impl Merge for TransformConfig {
         self.optimizer.merge(&from.optimizer);
         self.const_modules.merge(&from.const_modules);
         self.react.merge(&from.react);
+        self.typescript.merge(&from.typescript);
+        self.decorator_metadata.merge(&from.decorator_metadata);
     }
 }
 
@@ -710,3 +848,9 @@ impl Merge for ConstModulesConfig {
         *self = from.clone()
     }
 }
+
+impl Merge for typescript::Config {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone()
+    }
+}
@@ -22,17 +22,27 @@ pub enum Error {
     #[fail(display = "failed to emit module: {}", err)]
     FailedToEmitModule { err: io::Error },
 
+    #[fail(display = "failed to create declaration file")]
+    FailedToEmitDts {},
+
     #[fail(display = "failed to write sourcemap: {}", err)]
     FailedToWriteSourceMap { err: sourcemap::Error },
 
     #[fail(display = "sourcemap is not utf8: {}", err)]
     SourceMapNotUtf8 { err: FromUtf8Error },
 
+    #[fail(display = "failed to read input sourcemap: {}", err)]
+    FailedToReadInputSourceMap { err: io::Error },
+
+    #[fail(display = "failed to parse input sourcemap: {}", err)]
+    FailedToParseInputSourceMap { err: sourcemap::Error },
+
     #[fail(display = "invalid regexp: {}: {}", regex, err)]
     InvalidRegex { regex: String, err: regex::Error },
 
-    /* #[fail(display = "generated code is not utf8: {}", err)]
-     * GeneratedCodeNotUtf8 { err: FromUtf8Error }, */
+    #[fail(display = "generated code is not utf8: {}", err)]
+    GeneratedCodeNotUtf8 { err: FromUtf8Error },
+
     /// This means `test` field in .swcrc file did not matched the compiling
     /// file.
     #[fail(display = "unmatched")]
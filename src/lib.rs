@@ -11,20 +11,23 @@ pub mod error;
 
 pub use crate::builder::PassBuilder;
 use crate::{
-    config::{BuiltConfig, ConfigFile, JscTarget, Merge, Options, Rc, RootMode},
+    config::{
+        BuiltConfig, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
+        SourceMapsConfig,
+    },
     error::Error,
 };
 use common::{
     comments::Comments, errors::Handler, FileName, FoldWith, Globals, SourceFile, SourceMap,
-    GLOBALS,
+    Spanned, GLOBALS,
 };
 use ecmascript::{
     ast::Program,
     codegen::{self, Emitter},
-    parser::{lexer::Lexer, Parser, Session as ParseSess, Syntax},
+    parser::{lexer::Lexer, EsConfig, Parser, Session as ParseSess, Syntax},
     transforms::{
         helpers::{self, Helpers},
-        util,
+        typescript, util,
         util::COMMENTS,
     },
 };
@@ -33,8 +36,12 @@ pub use ecmascript::{
     transforms::{chain_at, pass::Pass},
 };
 use serde::Serialize;
-use sourcemap::SourceMapBuilder;
-use std::{fs::File, path::Path, sync::Arc};
+use sourcemap::{DecodedMap, SourceMapBuilder};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub struct Compiler {
     /// swc uses rustc's span interning.
@@ -47,6 +54,31 @@ pub struct Compiler {
     comments: Comments,
 }
 
+/// Options for [Compiler::print].
+pub struct PrintOptions<'a> {
+    pub source_maps: SourceMapsConfig,
+    /// If given, the generated source map points to the sources of `orig`
+    /// instead of the printed file.
+    pub orig: Option<&'a sourcemap::SourceMap>,
+    /// Overrides the name of the printed file in the source map.
+    pub source_file_name: Option<&'a str>,
+    /// `sourceRoot` of the source map.
+    pub source_root: Option<&'a str>,
+    pub codegen: codegen::Config,
+}
+
+impl Default for PrintOptions<'_> {
+    fn default() -> Self {
+        PrintOptions {
+            source_maps: SourceMapsConfig::Bool(false),
+            orig: None,
+            source_file_name: None,
+            source_root: None,
+            codegen: Default::default(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct TransformOutput {
     pub code: String,
@@ -89,6 +121,18 @@ impl Compiler {
             let session = ParseSess {
                 handler: &self.handler,
             };
+            let syntax = match syntax {
+                // Files without `@flow` are parsed as ecmascript.
+                Syntax::Flow(cfg) if !cfg.all && !self.has_flow_pragma(&fm, target) => {
+                    Syntax::Es(EsConfig {
+                        jsx: cfg.jsx,
+                        decorators: cfg.decorators,
+                        dynamic_import: cfg.dynamic_import,
+                        ..Default::default()
+                    })
+                }
+                _ => syntax,
+            };
             let lexer = Lexer::new(
                 session,
                 syntax,
@@ -123,14 +167,113 @@ impl Compiler {
         })
     }
 
+    /// Returns true if a comment before the first token of `fm` contains
+    /// `@flow`.
+    fn has_flow_pragma(&self, fm: &SourceFile, target: JscTarget) -> bool {
+        let comments = Comments::default();
+        let mut lexer = Lexer::new(
+            ParseSess {
+                handler: &self.handler,
+            },
+            Syntax::Es(Default::default()),
+            target,
+            SourceFileInput::from(fm),
+            Some(&comments),
+        );
+        let first = match lexer.next() {
+            Some(token) => token.span.lo(),
+            None => return false,
+        };
+
+        match comments.leading_comments(first) {
+            Some(cmts) => cmts.iter().any(|c| c.text.contains("@flow")),
+            None => false,
+        }
+    }
+
+    /// Loads the source map of the input file.
+    ///
+    /// If `input_src_map` is `true`, the map is loaded from a
+    /// `//# sourceMappingURL=` comment of `fm` or from `<filename>.map`.
+    pub fn get_orig_src_map(
+        &self,
+        fm: &SourceFile,
+        input_src_map: &InputSourceMap,
+    ) -> Result<Option<sourcemap::SourceMap>, Error> {
+        let decoded = match *input_src_map {
+            InputSourceMap::Bool(false) => return Ok(None),
+            InputSourceMap::Str(ref s) => sourcemap::decode_slice(s.as_bytes()),
+            InputSourceMap::Bool(true) => {
+                let reference = sourcemap::locate_sourcemap_reference_slice(fm.src.as_bytes())
+                    .map_err(|err| Error::FailedToParseInputSourceMap { err })?;
+
+                match reference.get_url() {
+                    Some(url) if url.starts_with("data:") => sourcemap::decode_data_url(url),
+                    url => {
+                        let path = match fm.name {
+                            FileName::Real(ref path) => match url {
+                                Some(url) => path.with_file_name(url),
+                                None => {
+                                    let mut path = path.clone().into_os_string();
+                                    path.push(".map");
+                                    PathBuf::from(path)
+                                }
+                            },
+                            _ => return Ok(None),
+                        };
+                        // Published packages often reference maps they don't ship.
+                        if !path.is_file() {
+                            return Ok(None);
+                        }
+
+                        let file = File::open(&path)
+                            .map_err(|err| Error::FailedToReadInputSourceMap { err })?;
+                        sourcemap::decode(file)
+                    }
+                }
+            }
+        };
+
+        let map = match decoded.map_err(|err| Error::FailedToParseInputSourceMap { err })? {
+            DecodedMap::Regular(map) => map,
+            DecodedMap::Index(index) => index
+                .flatten()
+                .map_err(|err| Error::FailedToParseInputSourceMap { err })?,
+        };
+
+        Ok(Some(map))
+    }
+
+    /// Removes the `//# sourceMappingURL=` comment at the end of `program`, as
+    /// it points to the source map of the input.
+    fn remove_src_map_url_comment(&self, program: &Program) {
+        let pos = program.span().hi();
+        if let Some(cmts) = self.comments.take_trailing_comments(pos) {
+            for cmt in cmts {
+                if !cmt.text.starts_with("# sourceMappingURL=")
+                    && !cmt.text.starts_with("@ sourceMappingURL=")
+                {
+                    self.comments.add_trailing(pos, cmt);
+                }
+            }
+        }
+    }
+
     pub fn print(
         &self,
         program: &Program,
         fm: Arc<SourceFile>,
         comments: &Comments,
-        source_map: bool,
-        minify: bool,
+        opts: PrintOptions<'_>,
     ) -> Result<TransformOutput, Error> {
+        let PrintOptions {
+            source_maps: source_map,
+            orig,
+            source_file_name,
+            source_root,
+            codegen: cfg,
+        } = opts;
+
         self.run(|| {
             let mut src_map_builder = SourceMapBuilder::new(None);
 
@@ -138,28 +281,32 @@ impl Compiler {
                 FileName::Real(ref p) => {
                     let id = src_map_builder.add_source(&p.display().to_string());
                     src_map_builder.set_source_contents(id, Some(&fm.src));
+                    if let Some(name) = source_file_name {
+                        src_map_builder.set_source(id, name);
+                    }
                 }
                 _ => {}
             }
 
-            let src = {
+            let mut src = {
                 let mut buf = vec![];
                 {
                     let handlers = box MyHandlers;
                     let mut emitter = Emitter {
-                        cfg: codegen::Config { minify },
-                        comments: if minify { None } else { Some(&comments) },
+                        cfg,
+                        comments: if cfg.minify { None } else { Some(&comments) },
                         cm: self.cm.clone(),
                         wr: box codegen::text_writer::JsWriter::new(
                             self.cm.clone(),
                             "\n",
                             &mut buf,
-                            if source_map {
+                            if source_map.enabled() {
                                 Some(&mut src_map_builder)
                             } else {
                                 None
                             },
-                        ),
+                        )
+                        .with_config(&cfg),
                         handlers,
                     };
 
@@ -167,20 +314,41 @@ impl Compiler {
                         .emit_program(&program)
                         .map_err(|err| Error::FailedToEmitModule { err })?;
                 }
-                // Invalid utf8 is valid in javascript world.
-                unsafe { String::from_utf8_unchecked(buf) }
+                // Lone surrogates are escaped by the emitter.
+                String::from_utf8(buf).map_err(|err| Error::GeneratedCodeNotUtf8 { err })?
+            };
+
+            if !source_map.enabled() {
+                return Ok(TransformOutput {
+                    code: src,
+                    map: None,
+                });
+            }
+
+            let map = match orig {
+                Some(orig) => compose_src_maps(orig, &src_map_builder.into_sourcemap()),
+                None => src_map_builder.into_sourcemap(),
             };
+            let mut buf = vec![];
+            map.to_writer(&mut buf).map_err(|err| Error::FailedToWriteSourceMap { err })?;
+            if let Some(root) = source_root {
+                // `sourcemap` does not write `sourceRoot`.
+                let mut json: serde_json::Value = serde_json::from_slice(&buf)
+                    .map_err(|err| Error::FailedToWriteSourceMap { err: err.into() })?;
+                json["sourceRoot"] = root.into();
+                buf = serde_json::to_vec(&json)
+                    .map_err(|err| Error::FailedToWriteSourceMap { err: err.into() })?;
+            }
+
+            if source_map.inline() {
+                src.push_str("\n//# sourceMappingURL=data:application/json;base64,");
+                src.push_str(&base64::encode(&buf));
+            }
+
             Ok(TransformOutput {
                 code: src,
-                map: if source_map {
-                    let mut buf = vec![];
-                    src_map_builder
-                        .into_sourcemap()
-                        .to_writer(&mut buf)
-                        .map_err(|err| Error::FailedToWriteSourceMap { err })?;
-                    let map =
-                        String::from_utf8(buf).map_err(|err| Error::SourceMapNotUtf8 { err })?;
-                    Some(map)
+                map: if source_map.separate() {
+                    Some(String::from_utf8(buf).map_err(|err| Error::SourceMapNotUtf8 { err })?)
                 } else {
                     None
                 },
@@ -189,6 +357,43 @@ impl Compiler {
     }
 }
 
+/// Maps the tokens of `map` back to the sources of `orig`.
+///
+/// `map` is the source map from the output of swc to its input, and `orig` is
+/// the source map of the input.
+fn compose_src_maps(
+    orig: &sourcemap::SourceMap,
+    map: &sourcemap::SourceMap,
+) -> sourcemap::SourceMap {
+    let mut builder = SourceMapBuilder::new(None);
+
+    for token in map.tokens() {
+        if !token.has_source() {
+            continue;
+        }
+        let orig_token = match orig.lookup_token(token.get_src_line(), token.get_src_col()) {
+            // `lookup_token` may return a token of a previous line.
+            Some(t) if t.has_source() && t.get_dst_line() == token.get_src_line() => t,
+            _ => continue,
+        };
+
+        let raw = builder.add(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            orig_token.get_src_line(),
+            orig_token.get_src_col(),
+            orig_token.get_source(),
+            orig_token.get_name().or_else(|| token.get_name()),
+        );
+        if !builder.has_source_contents(raw.src_id) {
+            let contents = orig.get_source_contents(orig_token.get_src_id());
+            builder.set_source_contents(raw.src_id, contents);
+        }
+    }
+
+    builder.into_sourcemap()
+}
+
 /// High-level apis.
 impl Compiler {
     pub fn new(cm: Arc<SourceMap>, handler: Handler) -> Self {
@@ -311,8 +516,9 @@ impl Compiler {
                 config.target,
                 config.syntax,
                 config.is_module,
-                !config.minify,
+                !config.output.minify,
             )?;
+            self.remove_src_map_url_comment(&module);
             let mut pass = config.pass;
             let module = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 util::HANDLER.set(&self.handler, || {
@@ -321,15 +527,47 @@ impl Compiler {
                 })
             });
 
+            let orig = if config.source_maps.enabled() {
+                self.get_orig_src_map(&fm, &config.input_source_map)?
+            } else {
+                None
+            };
+
             self.print(
                 &module,
                 fm,
                 &self.comments,
-                config.source_maps,
-                config.minify,
+                PrintOptions {
+                    source_maps: config.source_maps,
+                    orig: orig.as_ref(),
+                    source_file_name: config.source_file_name.as_ref().map(|s| &**s),
+                    source_root: config.source_root.as_ref().map(|s| &**s),
+                    codegen: config.output,
+                },
             )
         })
     }
+
+    /// Creates a typescript declaration file (`.d.ts`) from `fm`.
+    ///
+    /// Errors are reported to the handler if a type cannot be determined
+    /// without type inference.
+    pub fn emit_dts(&self, fm: Arc<SourceFile>, syntax: Syntax) -> Result<TransformOutput, Error> {
+        self.run(|| {
+            let errors = self.handler.err_count();
+
+            let module = self.parse_js(fm.clone(), JscTarget::Es2019, syntax, true, true)?;
+            let module = util::HANDLER.set(&self.handler, || {
+                module.fold_with(&mut typescript::dts())
+            });
+
+            if self.handler.err_count() != errors {
+                return Err(Error::FailedToEmitDts {});
+            }
+
+            self.print(&module, fm, &self.comments, Default::default())
+        })
+    }
 }
 
 struct MyHandlers;
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use swc::{
+    common::FileName,
+    ecmascript::{
+        ast::*,
+        parser::{JscTarget, Syntax},
+    },
+    Compiler,
+};
+use testing::Tester;
+
+/// Parses `src` with the default flow config and returns the expression of the
+/// first statement.
+fn parse_first_expr(src: &str) -> Expr {
+    let program = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+
+            let fm = cm.new_source_file(FileName::Real(PathBuf::from("input.js")), src.into());
+            match c.parse_js(fm, JscTarget::Es5, Syntax::Flow(Default::default()), true, true) {
+                Ok(v) => Ok(v),
+                Err(err) => panic!("Error: {}", err),
+            }
+        })
+        .unwrap();
+
+    match program {
+        Program::Module(Module { mut body, .. }) => match body.remove(0) {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => *expr,
+            item => panic!("expected an expression statement, got {:?}", item),
+        },
+        Program::Script(..) => unreachable!(),
+    }
+}
+
+#[test]
+fn flow_pragma() {
+    match parse_first_expr("// @flow\nf<T>(x);") {
+        Expr::Call(..) => {}
+        expr => panic!("expected a call with type arguments, got {:?}", expr),
+    }
+}
+
+#[test]
+fn flow_pragma_in_block_comment() {
+    match parse_first_expr("/**\n * @flow\n */\nf<T>(x);") {
+        Expr::Call(..) => {}
+        expr => panic!("expected a call with type arguments, got {:?}", expr),
+    }
+}
+
+#[test]
+fn no_flow_pragma() {
+    match parse_first_expr("// foo\nf<T>(x);") {
+        Expr::Bin(..) => {}
+        expr => panic!("expected a binary expression, got {:?}", expr),
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+use swc::{
+    common::FileName,
+    config::{Config, JscConfig, JscTarget, Options},
+    Compiler,
+};
+use testing::Tester;
+
+fn compile(src: &str, target: JscTarget) -> String {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+
+            let fm = cm.new_source_file(FileName::Real(PathBuf::from("input.js")), src.into());
+            let opts = Options {
+                config: Some(Config {
+                    jsc: JscConfig {
+                        target,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            match c.process_js_file(fm, &opts) {
+                Ok(v) => Ok(v.code),
+                Err(err) => panic!("Error: {}", err),
+            }
+        })
+        .unwrap()
+}
+
+#[test]
+fn raw_lit_es5() {
+    let code = compile("a(0b101, 0o17, 0xFF, 1_000, '\\u{1F600}');", JscTarget::Es5);
+
+    assert_eq!(code.trim(), "a(5, 15, 0xFF, 1000, '\u{1F600}');");
+}
+
+#[test]
+fn raw_lit_es2015() {
+    let code = compile("a(0b101, 0o17, 0xFF, 1_000, '\\u{1F600}');", JscTarget::Es2015);
+
+    assert_eq!(code.trim(), "a(0b101, 0o17, 0xFF, 1000, '\\u{1F600}');");
+}
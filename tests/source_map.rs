@@ -0,0 +1,161 @@
+#![feature(specialization)]
+
+use std::path::PathBuf;
+use swc::{
+    common::{FileName, Fold, FoldWith},
+    config::{InputSourceMap, Options, SourceMapsConfig},
+    ecmascript::{
+        ast::Ident,
+        parser::{JscTarget, Syntax},
+        transforms::typescript,
+    },
+    sourcemap::{self, DecodedMap, SourceMap, SourceMapBuilder},
+    Compiler, PrintOptions, TransformOutput,
+};
+use testing::Tester;
+
+/// Creates a source map which maps each line of `input.js` to the same line of
+/// `orig.ts`.
+fn input_map(lines: u32) -> String {
+    let mut builder = SourceMapBuilder::new(Some("input.js"));
+    for line in 0..lines {
+        builder.add(line, 0, line, 0, Some("orig.ts"), None);
+    }
+
+    let mut buf = vec![];
+    builder.into_sourcemap().to_writer(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn compile(src: &str, opts: &Options) -> TransformOutput {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+
+            let fm = cm.new_source_file(FileName::Real(PathBuf::from("input.js")), src.into());
+            match c.process_js_file(fm, opts) {
+                Ok(v) => Ok(v),
+                Err(err) => panic!("Error: {}", err),
+            }
+        })
+        .unwrap()
+}
+
+#[test]
+fn input_source_map_option() {
+    let out = compile(
+        "var a = 1;\nvar b = 2;\n",
+        &Options {
+            input_source_map: Some(InputSourceMap::Str(input_map(2))),
+            source_maps: Some(SourceMapsConfig::Bool(true)),
+            ..Default::default()
+        },
+    );
+
+    let map = SourceMap::from_slice(out.map.unwrap().as_bytes()).unwrap();
+    assert_eq!(map.sources().collect::<Vec<_>>(), vec!["orig.ts"]);
+
+    let token = map.lookup_token(1, 0).unwrap();
+    assert_eq!(token.get_source(), Some("orig.ts"));
+    assert_eq!(token.get_src_line(), 1);
+}
+
+#[test]
+fn source_mapping_url_comment() {
+    let src = format!(
+        "var a = 1;\n//# sourceMappingURL=data:application/json;base64,{}\n",
+        base64::encode(&input_map(1))
+    );
+    let out = compile(
+        &src,
+        &Options {
+            source_maps: Some(SourceMapsConfig::Bool(true)),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(out.code.trim(), "var a = 1;");
+
+    let map = SourceMap::from_slice(out.map.unwrap().as_bytes()).unwrap();
+    assert_eq!(map.sources().collect::<Vec<_>>(), vec!["orig.ts"]);
+
+    // The comment is stale even if no source map is generated.
+    let out = compile(&src, &Default::default());
+    assert_eq!(out.code.trim(), "var a = 1;");
+}
+
+#[test]
+fn inline_source_map() {
+    let out = compile(
+        "var a = 1;\n",
+        &Options {
+            source_maps: Some(SourceMapsConfig::Str("inline".into())),
+            source_file_name: Some("renamed.js".into()),
+            source_root: Some("/src/".into()),
+            ..Default::default()
+        },
+    );
+    assert!(out.map.is_none());
+    assert!(out.code.starts_with("var a = 1;\n"));
+    assert_eq!(out.code.matches("sourceMappingURL").count(), 1);
+
+    let url = out.code.rsplit("//# sourceMappingURL=").next().unwrap();
+    let map = match sourcemap::decode_data_url(url.trim()).unwrap() {
+        DecodedMap::Regular(map) => map,
+        DecodedMap::Index(..) => unreachable!(),
+    };
+    // `sourceRoot` is prepended to sources while decoding.
+    assert_eq!(map.sources().collect::<Vec<_>>(), vec!["/src/renamed.js"]);
+}
+
+/// Renames `a` to `a1` like the hygiene pass does.
+struct Renamer;
+
+impl Fold<Ident> for Renamer {
+    fn fold(&mut self, i: Ident) -> Ident {
+        if &*i.sym == "a" {
+            Ident {
+                sym: "a1".into(),
+                ..i
+            }
+        } else {
+            i
+        }
+    }
+}
+
+#[test]
+fn renamed_typed_param() {
+    let out = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+
+            let fm = cm.new_source_file(
+                FileName::Real(PathBuf::from("input.ts")),
+                "function foo(a?: number) {}\n".into(),
+            );
+            let syntax = Syntax::Typescript(Default::default());
+            let program = match c.parse_js(fm.clone(), JscTarget::Es2019, syntax, true, true) {
+                Ok(v) => v,
+                Err(err) => panic!("Error: {}", err),
+            };
+            let program = program
+                .fold_with(&mut typescript::strip())
+                .fold_with(&mut Renamer);
+
+            let opts = PrintOptions {
+                source_maps: SourceMapsConfig::Bool(true),
+                ..Default::default()
+            };
+            match c.print(&program, fm, &Default::default(), opts) {
+                Ok(v) => Ok(v),
+                Err(err) => panic!("Error: {}", err),
+            }
+        })
+        .unwrap();
+    assert_eq!(out.code.trim(), "function foo(a1) {}");
+
+    // The span of `a` covered `?: number`.
+    let map = SourceMap::from_slice(out.map.unwrap().as_bytes()).unwrap();
+    assert_eq!(map.names().collect::<Vec<_>>(), vec!["a"]);
+}